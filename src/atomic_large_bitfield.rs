@@ -0,0 +1,586 @@
+use crate::{find_highest_set_bit, find_lowest_set_bit};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Defines the number of bitfield groups in an atomic large bitfield
+const ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT: usize = core::mem::size_of::<usize>() * 8;
+
+/// Defines the maximum number of bits in an atomic large bitfield.
+const ATOMIC_LARGE_BIT_FIELD_BIT_SIZE: usize =
+    ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT;
+
+/// Defines the structure for Atomic Large Bitfields.
+/// An Atomic Large Bitfield is a structure that holds an array of `sizeof(usize) * 8`
+/// `AtomicUsize` values as well as an `AtomicUsize` "layer_cache" summarizing which groups have
+/// any bits set. Every operation is lock-free.
+///
+/// # Summary Protocol
+/// The group words are the source of truth and the layer cache is a summary used to skip empty
+/// groups. After every set, and after every clear that empties a group, the modifying thread
+/// brings the group's layer cache bit into agreement with the group word using a compare and
+/// exchange loop. The loop only finishes once a re-read of the group word agrees with the layer
+/// cache bit the thread observed or wrote, so a thread acting on a stale read of the group word
+/// always corrects its own update. Once no operation is in progress the layer cache exactly
+/// describes which groups have any bits set.
+///
+/// While a group is being emptied and refilled concurrently, a thread acting on a stale read may
+/// briefly clear the layer cache bit of a non-empty group. Every compare and exchange that clears
+/// a layer cache bit is counted in "clears_started" before it is attempted and in
+/// "clears_finished" once the thread's layer cache bit agrees with the group word again. A query
+/// that sees the counts differ around its read of the layer cache may have read such a bit, so it
+/// checks every group word instead.
+///
+/// # Guarantees
+/// - A query never misses a bit that was set before the query started and is not cleared while
+///   it runs. In particular, a bit set by a completed `set_bit` is always found.
+/// - A bit returned by a query was set when its group word was read.
+/// - The layer cache matches the group words whenever no operation is in progress.
+///
+/// # Memory Ordering
+/// Every operation on the group words, the layer cache and the clear counts uses
+/// `Ordering::SeqCst`, as the protocol relies on a single order of the group word and layer cache
+/// updates made by different threads. Setting a bit publishes any writes made before it to any
+/// thread that observes the bit as set.
+pub struct AtomicLargeBitField {
+    /// Holds a bitfield describing which sub bitfields currently have any set bits.
+    layer_cache: AtomicUsize,
+
+    /// Holds the number of layer cache clears that have been attempted.
+    clears_started: AtomicUsize,
+
+    /// Holds the number of layer cache clears whose group has been brought back into agreement
+    /// with the layer cache.
+    clears_finished: AtomicUsize,
+
+    /// Holds the bitfield state.
+    bitfield: [AtomicUsize; ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT],
+}
+
+/// Defines functionality for AtomicLargeBitField.
+impl AtomicLargeBitField {
+    /// Creates a new, empty AtomicLargeBitField
+    ///
+    /// # Returns
+    /// An AtomicLargeBitField.
+    pub const fn new() -> Self {
+        //
+        // A constant is required to repeat a non-Copy value in an array expression. Each element
+        // is a distinct atomic.
+        //
+
+        #[allow(clippy::declare_interior_mutable_const)]
+        const EMPTY_GROUP: AtomicUsize = AtomicUsize::new(0);
+
+        AtomicLargeBitField {
+            layer_cache: AtomicUsize::new(0),
+            clears_started: AtomicUsize::new(0),
+            clears_finished: AtomicUsize::new(0),
+            bitfield: [EMPTY_GROUP; ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT],
+        }
+    }
+
+    /// Gets the number of bits available in the bitfield type.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    pub fn get_number_of_bits() -> usize {
+        ATOMIC_LARGE_BIT_FIELD_BIT_SIZE
+    }
+
+    /// Sets a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    pub fn set_bit(&self, index: usize) {
+        self.test_and_set_bit(index);
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    pub fn clear_bit(&self, index: usize) {
+        self.test_and_clear_bit(index);
+    }
+
+    /// Sets a bit in the bit field and gets its previous value.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit was already set.
+    /// `Some(false)` if bit was cleared.
+    /// `None` if index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::AtomicLargeBitField;
+    ///
+    /// let large = AtomicLargeBitField::new();
+    ///
+    /// assert_eq!(large.test_and_set_bit(300), Some(false));
+    /// assert_eq!(large.test_and_set_bit(300), Some(true));
+    /// assert_eq!(large.test_and_set_bit(core::usize::MAX), None);
+    /// ```
+    pub fn test_and_set_bit(&self, index: usize) -> Option<bool> {
+        if index >= ATOMIC_LARGE_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        let top_layer = index / ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT;
+        let bottom_mask = 1 << (index % ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT);
+
+        //
+        // The group word must be updated before the layer cache so that the group is never
+        // advertised without its bit being visible. The layer cache is synchronized even if the
+        // group already had bits set, as the thread that filled the group may not have
+        // advertised it yet.
+        //
+
+        let previous = self.bitfield[top_layer].fetch_or(bottom_mask, Ordering::SeqCst);
+        self.sync_layer_cache(top_layer);
+        Some((previous & bottom_mask) != 0)
+    }
+
+    /// Clears a bit in the bit field and gets its previous value.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit was set.
+    /// `Some(false)` if bit was already cleared.
+    /// `None` if index is invalid.
+    pub fn test_and_clear_bit(&self, index: usize) -> Option<bool> {
+        if index >= ATOMIC_LARGE_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        let top_layer = index / ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT;
+        let bottom_mask = 1 << (index % ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT);
+        let sub_field = &self.bitfield[top_layer];
+
+        let previous = sub_field.fetch_and(!bottom_mask, Ordering::SeqCst);
+
+        //
+        // Only the thread that clears the last bit of a group updates the layer cache. Clears
+        // that leave other bits set do not change whether the group has any bits set.
+        //
+
+        if previous == bottom_mask {
+            self.sync_layer_cache(top_layer);
+        }
+
+        Some((previous & bottom_mask) != 0)
+    }
+
+    /// Brings the layer cache bit of a group into agreement with the group word.
+    ///
+    /// # Arguments
+    /// group_index - Provides the group whose layer cache bit to update.
+    fn sync_layer_cache(&self, group_index: usize) {
+        let group_mask = 1 << group_index;
+        let sub_field = &self.bitfield[group_index];
+        let mut clears = 0;
+
+        loop {
+            let has_bits = sub_field.load(Ordering::SeqCst) != 0;
+            let layer_cache = self.layer_cache.load(Ordering::SeqCst);
+            if ((layer_cache & group_mask) != 0) != has_bits {
+                let update = if has_bits {
+                    layer_cache | group_mask
+                } else {
+                    //
+                    // Readers must be told about the clear before it happens, as the group word
+                    // read may already be stale.
+                    //
+
+                    clears += 1;
+                    self.clears_started.fetch_add(1, Ordering::SeqCst);
+                    layer_cache & !group_mask
+                };
+
+                if self
+                    .layer_cache
+                    .compare_exchange(layer_cache, update, Ordering::SeqCst, Ordering::SeqCst)
+                    .is_err()
+                {
+                    continue;
+                }
+            }
+
+            //
+            // The layer cache bit agreed with the group word read above. If the group word still
+            // agrees, any later change to it is synchronized by the thread that made it.
+            //
+
+            if (sub_field.load(Ordering::SeqCst) != 0) == has_bits {
+                break;
+            }
+        }
+
+        if clears != 0 {
+            self.clears_finished.fetch_add(clears, Ordering::SeqCst);
+        }
+    }
+
+    /// Gets the groups that may have any bits set.
+    ///
+    /// # Returns
+    /// The layer cache, or every group if a layer cache clear may have been in progress while it
+    /// was read.
+    fn get_candidate_groups(&self) -> usize {
+        let finished = self.clears_finished.load(Ordering::SeqCst);
+        let groups = self.layer_cache.load(Ordering::SeqCst);
+        if self.clears_started.load(Ordering::SeqCst) != finished {
+            return core::usize::MAX;
+        }
+
+        groups
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    pub fn test_bit(&self, index: usize) -> Option<bool> {
+        if index >= ATOMIC_LARGE_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        let top_layer = index / ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT;
+        let bottom_mask = 1 << (index % ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT);
+        Some((self.bitfield[top_layer].load(Ordering::SeqCst) & bottom_mask) != 0)
+    }
+
+    /// Gets the lowest set bit.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::AtomicLargeBitField;
+    ///
+    /// let large = AtomicLargeBitField::new();
+    /// assert_eq!(large.get_lowest_set_bit(), None);
+    ///
+    /// large.set_bit(900);
+    /// large.set_bit(70);
+    /// assert_eq!(large.get_lowest_set_bit(), Some(70));
+    /// ```
+    pub fn get_lowest_set_bit(&self) -> Option<usize> {
+        let mut groups = self.get_candidate_groups();
+        while groups != 0 {
+            let level = find_lowest_set_bit(groups);
+            let sub_field = self.bitfield[level].load(Ordering::SeqCst);
+            if sub_field != 0 {
+                return Some(
+                    (level * ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT) + find_lowest_set_bit(sub_field),
+                );
+            }
+
+            //
+            // The group was emptied after the layer cache was read, or the layer cache could not
+            // be trusted. Move on to the next group.
+            //
+
+            groups &= !(1 << level);
+        }
+
+        None
+    }
+
+    /// Gets the highest set bit.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    pub fn get_highest_set_bit(&self) -> Option<usize> {
+        let mut groups = self.get_candidate_groups();
+        while groups != 0 {
+            let level = find_highest_set_bit(groups);
+            let sub_field = self.bitfield[level].load(Ordering::SeqCst);
+            if sub_field != 0 {
+                return Some(
+                    (level * ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT) + find_highest_set_bit(sub_field),
+                );
+            }
+
+            //
+            // The group was emptied after the layer cache was read, or the layer cache could not
+            // be trusted. Move on to the next group.
+            //
+
+            groups &= !(1 << level);
+        }
+
+        None
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.get_lowest_set_bit().is_none()
+    }
+}
+
+/// Defines the default AtomicLargeBitField.
+impl Default for AtomicLargeBitField {
+    /// Creates a new, empty AtomicLargeBitField
+    ///
+    /// # Returns
+    /// An AtomicLargeBitField.
+    fn default() -> Self {
+        AtomicLargeBitField::new()
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that the layer cache agrees with the group words and that no layer cache clear is in
+    /// progress once all threads are quiescent.
+    fn assert_layer_cache_consistent(large: &AtomicLargeBitField) {
+        let layer_cache = large.layer_cache.load(Ordering::Relaxed);
+        for index in 0..ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT {
+            let group_has_bits = large.bitfield[index].load(Ordering::Relaxed) != 0;
+            assert_eq!((layer_cache & (1 << index)) != 0, group_has_bits);
+        }
+
+        assert_eq!(
+            large.clears_started.load(Ordering::Relaxed),
+            large.clears_finished.load(Ordering::Relaxed)
+        );
+    }
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let large = AtomicLargeBitField::new();
+        assert_layer_cache_consistent(&large);
+        assert!(large.is_empty());
+        assert!(AtomicLargeBitField::default().is_empty());
+    }
+
+    #[test]
+    fn validate_set_and_clear_bit() {
+        let large = AtomicLargeBitField::new();
+
+        for i in 0..ATOMIC_LARGE_BIT_FIELD_BIT_SIZE {
+            //
+            // Out of bounds set should do nothing.
+            //
+
+            large.set_bit(ATOMIC_LARGE_BIT_FIELD_BIT_SIZE);
+            assert_eq!(large.test_bit(ATOMIC_LARGE_BIT_FIELD_BIT_SIZE), None);
+
+            large.set_bit(i);
+            assert_eq!(large.test_bit(i), Some(true));
+            assert_eq!(large.get_lowest_set_bit(), Some(0));
+            assert_eq!(large.get_highest_set_bit(), Some(i));
+        }
+
+        assert_layer_cache_consistent(&large);
+
+        for i in 0..ATOMIC_LARGE_BIT_FIELD_BIT_SIZE {
+            assert_eq!(large.get_lowest_set_bit(), Some(i));
+            large.clear_bit(i);
+            assert_eq!(large.test_bit(i), Some(false));
+            assert_layer_cache_consistent(&large);
+        }
+
+        assert!(large.is_empty());
+    }
+
+    #[test]
+    fn validate_test_and_modify_bit() {
+        let large = AtomicLargeBitField::new();
+        let bit = ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT + 3;
+
+        assert_eq!(
+            large.test_and_set_bit(ATOMIC_LARGE_BIT_FIELD_BIT_SIZE),
+            None
+        );
+        assert_eq!(
+            large.test_and_clear_bit(ATOMIC_LARGE_BIT_FIELD_BIT_SIZE),
+            None
+        );
+
+        assert_eq!(large.test_and_set_bit(bit), Some(false));
+        assert_eq!(large.test_and_set_bit(bit), Some(true));
+        assert_layer_cache_consistent(&large);
+
+        assert_eq!(large.test_and_clear_bit(bit), Some(true));
+        assert_eq!(large.test_and_clear_bit(bit), Some(false));
+        assert_layer_cache_consistent(&large);
+        assert!(large.is_empty());
+    }
+
+    #[test]
+    fn validate_stale_layer_cache_is_skipped() {
+        let large = AtomicLargeBitField::new();
+
+        //
+        // Simulate a reader racing with a clear that has emptied a group but not yet updated the
+        // layer cache.
+        //
+
+        large.set_bit(ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 2);
+        large.layer_cache.fetch_or(1 << 1, Ordering::Relaxed);
+        large.layer_cache.fetch_or(1 << 3, Ordering::Relaxed);
+
+        assert_eq!(
+            large.get_lowest_set_bit(),
+            Some(ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 2)
+        );
+
+        assert_eq!(
+            large.get_highest_set_bit(),
+            Some(ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 2)
+        );
+
+        large.clear_bit(ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 2);
+        assert_eq!(large.get_lowest_set_bit(), None);
+        assert!(large.is_empty());
+    }
+
+    #[test]
+    fn validate_in_progress_clear_is_not_trusted() {
+        let large = AtomicLargeBitField::new();
+        let bit = (ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 2) + 5;
+
+        //
+        // Simulate a clearing thread that acted on a stale read of the group word and has cleared
+        // the layer cache bit of a non-empty group, but has not yet corrected it.
+        //
+
+        large.set_bit(bit);
+        large.clears_started.fetch_add(1, Ordering::Relaxed);
+        large.layer_cache.fetch_and(!(1 << 2), Ordering::Relaxed);
+
+        assert_eq!(large.get_lowest_set_bit(), Some(bit));
+        assert_eq!(large.get_highest_set_bit(), Some(bit));
+        assert!(!large.is_empty());
+
+        //
+        // Once the clearing thread corrects the layer cache, the layer cache is trusted again.
+        //
+
+        large.layer_cache.fetch_or(1 << 2, Ordering::Relaxed);
+        large.clears_finished.fetch_add(1, Ordering::Relaxed);
+        assert_layer_cache_consistent(&large);
+        assert_eq!(large.get_lowest_set_bit(), Some(bit));
+    }
+
+    #[test]
+    fn validate_sync_corrects_stale_layer_cache() {
+        let large = AtomicLargeBitField::new();
+
+        //
+        // Simulate a thread that set the layer cache bit of a group that has since been emptied,
+        // and a group whose bit was never advertised.
+        //
+
+        large.layer_cache.fetch_or(1 << 1, Ordering::Relaxed);
+        large.bitfield[3].fetch_or(1, Ordering::Relaxed);
+
+        large.sync_layer_cache(1);
+        large.sync_layer_cache(3);
+        assert_layer_cache_consistent(&large);
+        assert_eq!(
+            large.get_lowest_set_bit(),
+            Some(ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 3)
+        );
+    }
+
+    #[test]
+    fn validate_concurrent_set_is_visible() {
+        let large = AtomicLargeBitField::new();
+        let thread_count = 4;
+
+        //
+        // Every thread shares its groups with every other thread, so groups are constantly
+        // emptied and refilled. A bit set by a completed set_bit must be found by every query made
+        // before it is cleared.
+        //
+
+        std::thread::scope(|scope| {
+            for thread in 0..thread_count {
+                let large = &large;
+                scope.spawn(move || {
+                    for iteration in 0..2000 {
+                        let group = iteration % 4;
+                        let index = (group * ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT) + thread;
+                        large.set_bit(index);
+                        assert!(large.get_lowest_set_bit().unwrap() <= index);
+                        assert!(large.get_highest_set_bit().unwrap() >= index);
+                        assert!(!large.is_empty());
+                        assert_eq!(large.test_and_clear_bit(index), Some(true));
+                    }
+                });
+            }
+        });
+
+        assert_layer_cache_consistent(&large);
+        assert!(large.is_empty());
+    }
+
+    #[test]
+    fn validate_concurrent_set_and_clear() {
+        let large = AtomicLargeBitField::new();
+        let pinned = (ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT * 4) + 1;
+        let thread_count = 4;
+
+        //
+        // The pinned bit is never cleared, so its group must be visible to readers throughout
+        // while other threads repeatedly fill and empty the groups around it.
+        //
+
+        large.set_bit(pinned);
+
+        std::thread::scope(|scope| {
+            for thread in 0..thread_count {
+                let large = &large;
+                scope.spawn(move || {
+                    for _ in 0..200 {
+                        for group in 0..8 {
+                            let index = (group * ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT) + 2 + thread;
+                            large.set_bit(index);
+                            assert_eq!(large.test_bit(index), Some(true));
+                        }
+
+                        for group in 0..8 {
+                            let index = (group * ATOMIC_LARGE_BIT_FIELD_GROUP_COUNT) + 2 + thread;
+                            assert_eq!(large.test_and_clear_bit(index), Some(true));
+                        }
+                    }
+                });
+            }
+
+            let large = &large;
+            scope.spawn(move || {
+                for _ in 0..2000 {
+                    let lowest = large.get_lowest_set_bit().unwrap();
+                    assert!(lowest <= pinned);
+                    let highest = large.get_highest_set_bit().unwrap();
+                    assert!(highest >= pinned);
+                }
+            });
+        });
+
+        assert_layer_cache_consistent(&large);
+        assert_eq!(large.get_lowest_set_bit(), Some(pinned));
+        assert_eq!(large.get_highest_set_bit(), Some(pinned));
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{find_highest_set_bit, find_lowest_set_bit};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Defines the maximum number of bits in an atomic small bitfield.
+const ATOMIC_SMALL_BIT_FIELD_BIT_SIZE: usize = core::mem::size_of::<usize>() * 8;
+
+/// Defines the structure for Atomic Small Bitfields.
+/// An Atomic Small Bitfield is a wrapper type that holds an `AtomicUsize` bitfield that can be
+/// shared and modified concurrently without locking.
+///
+/// # Memory Ordering
+/// Every modification is a single read-modify-write operation using `Ordering::AcqRel` and every
+/// query is a single load using `Ordering::Acquire`. Setting a bit therefore publishes any writes
+/// made before it to any thread that observes the bit as set.
+pub struct AtomicSmallBitField {
+    /// Holds the bitfield state.
+    bitfield: AtomicUsize,
+}
+
+/// Defines functionality for AtomicSmallBitField.
+impl AtomicSmallBitField {
+    /// Creates a new, empty AtomicSmallBitField
+    ///
+    /// # Returns
+    /// An AtomicSmallBitField.
+    pub const fn new() -> Self {
+        AtomicSmallBitField {
+            bitfield: AtomicUsize::new(0),
+        }
+    }
+
+    /// Gets the number of bits available in the bitfield type.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    pub fn get_number_of_bits() -> usize {
+        ATOMIC_SMALL_BIT_FIELD_BIT_SIZE
+    }
+
+    /// Sets a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    pub fn set_bit(&self, index: usize) {
+        if index < ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            self.bitfield.fetch_or(1 << index, Ordering::AcqRel);
+        }
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    pub fn clear_bit(&self, index: usize) {
+        if index < ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            self.bitfield.fetch_and(!(1 << index), Ordering::AcqRel);
+        }
+    }
+
+    /// Sets a bit in the bit field and gets its previous value.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit was already set.
+    /// `Some(false)` if bit was cleared.
+    /// `None` if index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::AtomicSmallBitField;
+    ///
+    /// let small = AtomicSmallBitField::new();
+    ///
+    /// assert_eq!(small.test_and_set_bit(3), Some(false));
+    /// assert_eq!(small.test_and_set_bit(3), Some(true));
+    /// assert_eq!(small.test_and_set_bit(core::usize::MAX), None);
+    /// ```
+    pub fn test_and_set_bit(&self, index: usize) -> Option<bool> {
+        if index >= ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        let previous = self.bitfield.fetch_or(1 << index, Ordering::AcqRel);
+        Some((previous & (1 << index)) != 0)
+    }
+
+    /// Clears a bit in the bit field and gets its previous value.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit was set.
+    /// `Some(false)` if bit was already cleared.
+    /// `None` if index is invalid.
+    pub fn test_and_clear_bit(&self, index: usize) -> Option<bool> {
+        if index >= ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        let previous = self.bitfield.fetch_and(!(1 << index), Ordering::AcqRel);
+        Some((previous & (1 << index)) != 0)
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    pub fn test_bit(&self, index: usize) -> Option<bool> {
+        if index >= ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        Some((self.bitfield.load(Ordering::Acquire) & (1 << index)) != 0)
+    }
+
+    /// Gets the lowest set bit.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::AtomicSmallBitField;
+    ///
+    /// let small = AtomicSmallBitField::new();
+    /// assert_eq!(small.get_lowest_set_bit(), None);
+    ///
+    /// small.set_bit(9);
+    /// small.set_bit(4);
+    /// assert_eq!(small.get_lowest_set_bit(), Some(4));
+    /// ```
+    pub fn get_lowest_set_bit(&self) -> Option<usize> {
+        let bitfield = self.bitfield.load(Ordering::Acquire);
+        if bitfield == 0 {
+            return None;
+        }
+
+        Some(find_lowest_set_bit(bitfield))
+    }
+
+    /// Gets the highest set bit.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    pub fn get_highest_set_bit(&self) -> Option<usize> {
+        let bitfield = self.bitfield.load(Ordering::Acquire);
+        if bitfield == 0 {
+            return None;
+        }
+
+        Some(find_highest_set_bit(bitfield))
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.bitfield.load(Ordering::Acquire) == 0
+    }
+}
+
+/// Defines the default AtomicSmallBitField.
+impl Default for AtomicSmallBitField {
+    /// Creates a new, empty AtomicSmallBitField
+    ///
+    /// # Returns
+    /// An AtomicSmallBitField.
+    fn default() -> Self {
+        AtomicSmallBitField::new()
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let small = AtomicSmallBitField::new();
+        assert_eq!(small.bitfield.load(Ordering::Relaxed), 0);
+        assert!(small.is_empty());
+        assert!(AtomicSmallBitField::default().is_empty());
+    }
+
+    #[test]
+    fn validate_set_and_clear_bit() {
+        let small = AtomicSmallBitField::new();
+        let mut expected_value: usize = 0;
+
+        for i in 0..ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            //
+            // Out of bounds set should do nothing.
+            //
+
+            small.set_bit(ATOMIC_SMALL_BIT_FIELD_BIT_SIZE);
+            assert_eq!(small.bitfield.load(Ordering::Relaxed), expected_value);
+
+            expected_value |= 1 << i;
+            small.set_bit(i);
+            assert_eq!(small.bitfield.load(Ordering::Relaxed), expected_value);
+            assert_eq!(small.test_bit(i), Some(true));
+        }
+
+        for i in 0..ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            small.clear_bit(ATOMIC_SMALL_BIT_FIELD_BIT_SIZE);
+            assert_eq!(small.bitfield.load(Ordering::Relaxed), expected_value);
+
+            expected_value &= !(1 << i);
+            small.clear_bit(i);
+            assert_eq!(small.bitfield.load(Ordering::Relaxed), expected_value);
+            assert_eq!(small.test_bit(i), Some(false));
+        }
+
+        assert_eq!(small.test_bit(ATOMIC_SMALL_BIT_FIELD_BIT_SIZE), None);
+    }
+
+    #[test]
+    fn validate_test_and_modify_bit() {
+        let small = AtomicSmallBitField::new();
+
+        assert_eq!(
+            small.test_and_set_bit(ATOMIC_SMALL_BIT_FIELD_BIT_SIZE),
+            None
+        );
+        assert_eq!(
+            small.test_and_clear_bit(ATOMIC_SMALL_BIT_FIELD_BIT_SIZE),
+            None
+        );
+
+        assert_eq!(small.test_and_set_bit(5), Some(false));
+        assert_eq!(small.test_and_set_bit(5), Some(true));
+        assert_eq!(small.test_and_clear_bit(5), Some(true));
+        assert_eq!(small.test_and_clear_bit(5), Some(false));
+        assert!(small.is_empty());
+    }
+
+    #[test]
+    fn validate_get_lowest_and_highest_set_bit() {
+        let small = AtomicSmallBitField::new();
+
+        //
+        // Empty should result in None
+        //
+
+        assert_eq!(small.get_lowest_set_bit(), None);
+        assert_eq!(small.get_highest_set_bit(), None);
+
+        for i in 0..ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            small.set_bit(i);
+            assert_eq!(small.get_lowest_set_bit(), Some(0));
+            assert_eq!(small.get_highest_set_bit(), Some(i));
+        }
+
+        for i in 0..ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(small.get_lowest_set_bit(), Some(i));
+            assert_eq!(
+                small.get_highest_set_bit(),
+                Some(ATOMIC_SMALL_BIT_FIELD_BIT_SIZE - 1)
+            );
+
+            small.clear_bit(i);
+        }
+    }
+
+    #[test]
+    fn validate_concurrent_test_and_set_claims_once() {
+        let small = AtomicSmallBitField::new();
+        let claimed = AtomicUsize::new(0);
+
+        //
+        // Every bit should be claimed by exactly one of the competing threads.
+        //
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for i in 0..ATOMIC_SMALL_BIT_FIELD_BIT_SIZE {
+                        if small.test_and_set_bit(i) == Some(false) {
+                            claimed.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            claimed.load(Ordering::Relaxed),
+            ATOMIC_SMALL_BIT_FIELD_BIT_SIZE
+        );
+
+        assert_eq!(small.bitfield.load(Ordering::Relaxed), core::usize::MAX);
+    }
+}
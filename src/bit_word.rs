@@ -0,0 +1,286 @@
+use crate::{find_highest_set_bit, find_lowest_set_bit, find_set_bit_with_rank};
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, IndexMut, Not, Shl, Shr,
+};
+
+/// Defines the number of bits in a `usize`. Words that fit in a `usize` use its bit scan helpers.
+const USIZE_BITS: usize = core::mem::size_of::<usize>() * 8;
+
+/// Prevents BitWord from being implemented outside of this crate, since LargeBitField relies on
+/// `Groups` holding exactly `BITS` words.
+mod private {
+    pub trait Sealed {}
+}
+
+/// Defines the primitive unsigned integers that can hold the bits of a SmallBitField or the groups
+/// of a LargeBitField.
+///
+/// A `SmallBitField<u8>` holds 8 bits and a `LargeBitField<u8>` holds 8 groups of 8 bits, while a
+/// `LargeBitField<u128>` holds 128 groups of 128 bits. Both bitfields default to `usize` words.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::{BitWord, FastBitField, FixedBitField, LargeBitField, SmallBitField};
+///
+/// let mut small = SmallBitField::<u8>::new();
+/// small.set_bit(7);
+/// small.set_bit(8);
+///
+/// assert_eq!(small.get_highest_set_bit(), Some(7));
+/// assert_eq!(small.get_word(), 0x80);
+///
+/// assert_eq!(<LargeBitField<u16>>::BITS, 256);
+/// assert_eq!(<u32 as BitWord>::BITS, 32);
+/// ```
+pub trait BitWord:
+    private::Sealed
+    + Copy
+    + Eq
+    + Hash
+    + Default
+    + fmt::Debug
+    + fmt::LowerHex
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+    + Shl<usize, Output = Self>
+    + Shr<usize, Output = Self>
+{
+    /// Defines the number of bits in the word.
+    const BITS: usize;
+
+    /// Defines the word with no bits set.
+    const ZERO: Self;
+
+    /// Defines the word with only the lowest bit set.
+    const ONE: Self;
+
+    /// Defines the word with every bit set.
+    const MAX: Self;
+
+    /// Defines the groups of a LargeBitField made of this word, an array of `BITS` words.
+    type Groups: Copy
+        + Eq
+        + Hash
+        + fmt::Debug
+        + AsRef<[Self]>
+        + AsMut<[Self]>
+        + Index<usize, Output = Self>
+        + IndexMut<usize>;
+
+    /// Defines the groups with no bits set.
+    const EMPTY_GROUPS: Self::Groups;
+
+    /// Converts a boolean into a word without branching.
+    ///
+    /// # Arguments
+    /// value - Provides the boolean to convert.
+    ///
+    /// # Returns
+    /// `ONE` if value is `true`, `ZERO` otherwise.
+    fn from_bool(value: bool) -> Self;
+
+    /// Gets the lowest set bit of the word.
+    ///
+    /// # Returns
+    /// The lowest set bit index. The result is undefined if no bits are set.
+    fn find_lowest_set_bit(self) -> usize;
+
+    /// Gets the highest set bit of the word.
+    ///
+    /// # Returns
+    /// The highest set bit index. The result is undefined if no bits are set.
+    fn find_highest_set_bit(self) -> usize;
+
+    /// Gets the set bit of the word with a given rank.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits. The result is undefined if rank
+    /// is not less than the number of set bits.
+    fn find_set_bit_with_rank(self, rank: usize) -> usize;
+
+    /// Gets the number of set bits in the word.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    fn count_set_bits(self) -> usize;
+
+    /// Clears the lowest set bit of the word.
+    ///
+    /// # Returns
+    /// The word without its lowest set bit.
+    fn clear_lowest_set_bit(self) -> Self;
+
+    /// Gets a mask with a contiguous range of bits set.
+    ///
+    /// # Arguments
+    /// start - Provides the first bit to set.
+    /// end - Provides the bit after the last bit to set. Must be greater than start and no more
+    /// than `BITS`.
+    ///
+    /// # Returns
+    /// A mask with bits `start..end` set.
+    fn get_range_mask(start: usize, end: usize) -> Self {
+        (Self::MAX >> (Self::BITS - (end - start))) << start
+    }
+}
+
+/// Gets the set bit of a word wider than a usize with a given rank.
+///
+/// # Arguments
+/// value - The value to search.
+/// rank - The number of set bits that precede the bit to find.
+///
+/// # Returns
+/// The index of the set bit preceded by exactly rank set bits.
+fn find_wide_set_bit_with_rank<W: BitWord>(value: W, rank: usize) -> usize {
+    let mut value = value;
+    let mut rank = rank;
+    let mut index = 0;
+    let mut width = W::BITS;
+
+    //
+    // Narrow down to the half that holds the bit, skipping the set bits of the lower half when
+    // moving to the upper half.
+    //
+
+    while width > 1 {
+        width /= 2;
+        let lower_half = value & W::get_range_mask(0, width);
+        let lower_count = lower_half.count_set_bits();
+        if rank >= lower_count {
+            rank -= lower_count;
+            value = value >> width;
+            index += width;
+        } else {
+            value = lower_half;
+        }
+    }
+
+    index
+}
+
+/// Implements BitWord for primitive unsigned integers. Words no wider than a usize are widened so
+/// they can share the usize bit scan helpers, which pick the fastest scan the processor supports.
+macro_rules! impl_bit_word {
+    ($($word:ty),*) => {
+        $(
+            impl private::Sealed for $word {}
+
+            /// Defines the BitWord interface for the primitive.
+            impl BitWord for $word {
+                const BITS: usize = core::mem::size_of::<$word>() * 8;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$word>::MAX;
+                type Groups = [$word; core::mem::size_of::<$word>() * 8];
+                const EMPTY_GROUPS: Self::Groups = [0; core::mem::size_of::<$word>() * 8];
+
+                #[inline(always)]
+                fn from_bool(value: bool) -> Self {
+                    value as $word
+                }
+
+                #[inline(always)]
+                fn find_lowest_set_bit(self) -> usize {
+                    if <Self as BitWord>::BITS <= USIZE_BITS {
+                        find_lowest_set_bit(self as usize)
+                    } else {
+                        self.trailing_zeros() as usize
+                    }
+                }
+
+                #[inline(always)]
+                fn find_highest_set_bit(self) -> usize {
+                    if <Self as BitWord>::BITS <= USIZE_BITS {
+                        find_highest_set_bit(self as usize)
+                    } else {
+                        <Self as BitWord>::BITS - 1 - self.leading_zeros() as usize
+                    }
+                }
+
+                #[inline(always)]
+                fn find_set_bit_with_rank(self, rank: usize) -> usize {
+                    if <Self as BitWord>::BITS <= USIZE_BITS {
+                        find_set_bit_with_rank(self as usize, rank)
+                    } else {
+                        find_wide_set_bit_with_rank(self, rank)
+                    }
+                }
+
+                #[inline(always)]
+                fn count_set_bits(self) -> usize {
+                    self.count_ones() as usize
+                }
+
+                #[inline(always)]
+                fn clear_lowest_set_bit(self) -> Self {
+                    self & self.wrapping_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_word!(u8, u16, u32, u64, u128, usize);
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the bit scan helpers of a word type against a bit by bit scan.
+    fn validate_bit_scans<W: BitWord>() {
+        let patterns = [
+            W::ONE,
+            W::MAX,
+            W::ONE << (W::BITS - 1),
+            W::get_range_mask(3, W::BITS - 1),
+            W::get_range_mask(W::BITS / 2, W::BITS) | W::ONE << 2,
+        ];
+
+        for pattern in patterns.iter() {
+            let set_bits: Vec<usize> = (0..W::BITS)
+                .filter(|index| (*pattern & (W::ONE << *index)) != W::ZERO)
+                .collect();
+
+            assert_eq!(pattern.find_lowest_set_bit(), set_bits[0]);
+            assert_eq!(pattern.find_highest_set_bit(), set_bits[set_bits.len() - 1]);
+            assert_eq!(pattern.count_set_bits(), set_bits.len());
+            assert_eq!(
+                pattern.clear_lowest_set_bit().count_set_bits(),
+                set_bits.len() - 1
+            );
+
+            for (rank, index) in set_bits.iter().enumerate() {
+                assert_eq!(pattern.find_set_bit_with_rank(rank), *index);
+            }
+        }
+
+        assert_eq!(W::from_bool(true), W::ONE);
+        assert_eq!(W::from_bool(false), W::ZERO);
+        assert_eq!(W::EMPTY_GROUPS.as_ref().len(), W::BITS);
+    }
+
+    #[test]
+    fn validate_word_bit_scans() {
+        validate_bit_scans::<u8>();
+        validate_bit_scans::<u16>();
+        validate_bit_scans::<u32>();
+        validate_bit_scans::<u64>();
+        validate_bit_scans::<u128>();
+        validate_bit_scans::<usize>();
+    }
+}
@@ -0,0 +1,1436 @@
+use crate::{
+    find_highest_set_bit, find_lowest_set_bit, find_set_bit_with_rank, format_set_bits,
+    search_clear_run, BitFieldError, SearchBitField,
+};
+use core::fmt;
+
+/// Defines the number of bits in each word of a bitfield view, which is also the number of words
+/// described by each word of a summary layer.
+const BIT_FIELD_VIEW_WORD_BITS: usize = core::mem::size_of::<usize>() * 8;
+
+/// Defines whether or not a word should be marked in the summary layer above it.
+type WordPredicate = fn(usize) -> bool;
+
+/// Gets the number of words in a stack of summary layers describing a number of bitfield words.
+///
+/// # Arguments
+/// words - Provides the number of words in the bitfield.
+///
+/// # Returns
+/// The number of words in every layer of the stack combined.
+const fn get_layer_stack_size(words: usize) -> usize {
+    if words == 0 {
+        return 0;
+    }
+
+    let mut total = 0;
+    let mut size = words;
+    loop {
+        size = size.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+        total += size;
+        if size <= 1 {
+            return total;
+        }
+    }
+}
+
+/// Gets the number of summary words needed to describe a number of bitfield words.
+///
+/// # Arguments
+/// words - Provides the number of words in the bitfield.
+///
+/// # Returns
+/// The number of summary words.
+const fn get_summary_size(words: usize) -> usize {
+    get_layer_stack_size(words) * 2
+}
+
+/// Computes the summary word describing up to `sizeof(usize) * 8` words of the layer below.
+///
+/// # Arguments
+/// words - Provides the words to summarize.
+/// predicate - Provides whether or not a word should be marked in the summary.
+///
+/// # Returns
+/// A word with a bit set for every word that matches predicate.
+fn compute_summary_word(words: &[usize], predicate: WordPredicate) -> usize {
+    let mut summary_word = 0;
+    for (index, word) in words.iter().enumerate() {
+        //
+        // Turn booleans into usizes to avoid branching.
+        //
+
+        summary_word |= (predicate(*word) as usize) << index;
+    }
+
+    summary_word
+}
+
+/// Checks that a summary has the right size for a bitfield and describes it.
+///
+/// # Arguments
+/// bitfield - Provides the words of the bitfield.
+/// summary - Provides the summary to check.
+///
+/// # Returns
+/// `Ok(())` if the summary is valid.
+/// `BitFieldError::SizeMismatch` if the summary is not `get_summary_size(bitfield.len())` words.
+/// `BitFieldError::InvalidFormat` if the summary does not describe the bitfield.
+fn validate_summary(bitfield: &[usize], summary: &[usize]) -> Result<(), BitFieldError> {
+    if summary.len() != get_summary_size(bitfield.len()) {
+        return Err(BitFieldError::SizeMismatch);
+    }
+
+    let (layer_caches, clear_caches) = summary.split_at(summary.len() / 2);
+    let stacks: [(&[usize], WordPredicate); 2] = [
+        (layer_caches, |word| word != 0),
+        (clear_caches, |word| word != core::usize::MAX),
+    ];
+
+    for (stack, predicate) in stacks.iter() {
+        let caches = SummaryLayers::new(stack, bitfield.len());
+        let mut below = bitfield;
+        let mut predicate = *predicate;
+        for layer in 0..caches.count() {
+            let cache = caches.get(layer).unwrap_or(&[]);
+            let chunks = below.chunks(BIT_FIELD_VIEW_WORD_BITS);
+            for (chunk, summary_word) in chunks.zip(cache.iter()) {
+                if compute_summary_word(chunk, predicate) != *summary_word {
+                    return Err(BitFieldError::InvalidFormat);
+                }
+            }
+
+            //
+            // Above the first layer a word has set or clear bits below it exactly when it is
+            // non-zero.
+            //
+
+            below = cache;
+            predicate = |word| word != 0;
+        }
+    }
+
+    Ok(())
+}
+
+/// Defines a stack of summary layers held in caller provided memory. The layers are stored one
+/// after another, lowest layer first, and each layer has one bit for every word of the layer below
+/// it, up to a single top word.
+#[derive(Clone, Copy)]
+struct SummaryLayers<'a> {
+    /// Holds every layer of the stack.
+    stack: &'a [usize],
+
+    /// Holds the number of words in the bitfield the stack describes.
+    words: usize,
+}
+
+/// Defines functionality for SummaryLayers.
+impl<'a> SummaryLayers<'a> {
+    /// Creates a SummaryLayers over a stack of summary layers.
+    ///
+    /// # Arguments
+    /// stack - Provides the layers. Must be `get_layer_stack_size(words)` words long.
+    /// words - Provides the number of words in the bitfield the stack describes.
+    ///
+    /// # Returns
+    /// The SummaryLayers.
+    fn new(stack: &'a [usize], words: usize) -> Self {
+        SummaryLayers { stack, words }
+    }
+
+    /// Gets the number of layers in the stack.
+    ///
+    /// # Returns
+    /// The number of layers.
+    fn count(&self) -> usize {
+        if self.words == 0 {
+            return 0;
+        }
+
+        let mut count = 1;
+        let mut size = self.words.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+        while size > 1 {
+            size = size.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Gets a layer of the stack.
+    ///
+    /// # Arguments
+    /// layer - Provides the layer to get, where `0` is the lowest layer.
+    ///
+    /// # Returns
+    /// The words of the layer or `None` if there is no such layer.
+    fn get(&self, layer: usize) -> Option<&'a [usize]> {
+        if layer >= self.count() {
+            return None;
+        }
+
+        let mut start = 0;
+        let mut size = self.words.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+        for _ in 0..layer {
+            start += size;
+            size = size.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+        }
+
+        Some(&self.stack[start..(start + size)])
+    }
+
+    /// Gets the top word of the stack.
+    ///
+    /// # Returns
+    /// The top word or `0` if the bit field has no bits.
+    fn get_top(&self) -> usize {
+        match self.stack.last() {
+            Some(top) => *top,
+            None => 0,
+        }
+    }
+
+    /// Descends the summary layers to the lowest word they mark. The top layer must have a bit
+    /// set.
+    ///
+    /// # Returns
+    /// The index of the lowest marked word of the bit field.
+    fn find_lowest_word(&self) -> usize {
+        let mut index = 0;
+        for layer in (0..self.count()).rev() {
+            let cache = self.get(layer).unwrap_or(&[]);
+            index = (index * BIT_FIELD_VIEW_WORD_BITS) + find_lowest_set_bit(cache[index]);
+        }
+
+        index
+    }
+
+    /// Descends the summary layers to the highest word they mark. The top layer must have a bit
+    /// set.
+    ///
+    /// # Returns
+    /// The index of the highest marked word of the bit field.
+    fn find_highest_word(&self) -> usize {
+        let mut index = 0;
+        for layer in (0..self.count()).rev() {
+            let cache = self.get(layer).unwrap_or(&[]);
+            index = (index * BIT_FIELD_VIEW_WORD_BITS) + find_highest_set_bit(cache[index]);
+        }
+
+        index
+    }
+}
+
+/// Defines the structure for read-only Bitfield Views.
+/// A Bitfield View is a bitfield over memory owned by the caller, such as a bitmap in a reserved
+/// physical region that cannot be moved into a `LargeBitField`. The caller provides the words of
+/// the bitfield and the storage for its summary, which must be
+/// `BitFieldRef::get_summary_size(words)` words long.
+///
+/// The summary holds the same stacks of "layer_caches" and "clear_caches" as a DynamicBitField:
+/// each bit of a layer describes whether the corresponding word of the layer below has any bits
+/// set, or any bits clear, up to a single top word. The layer caches are stored first, lowest
+/// layer first, followed by the clear caches in the same order. Lowest and highest set and clear
+/// bit lookups take a single bit scan per layer, and a layer is only added each time the number of
+/// words grows by a factor of `sizeof(usize) * 8`.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::{BitFieldMut, BitFieldRef};
+///
+/// let mut words = [0; 4];
+/// let mut summary = [0; BitFieldRef::get_summary_size(4)];
+///
+/// let mut view = BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+/// view.set_bit(70);
+/// view.set_bit(100);
+///
+/// let view = BitFieldRef::new(&words, &summary).unwrap();
+/// assert_eq!(view.get_lowest_set_bit(), Some(70));
+/// assert_eq!(view.get_highest_set_bit(), Some(100));
+/// assert_eq!(view.get_lowest_clear_bit(), Some(0));
+/// ```
+#[derive(Clone, Copy)]
+pub struct BitFieldRef<'a> {
+    /// Holds the bitfield state.
+    bitfield: &'a [usize],
+
+    /// Holds the layer caches followed by the clear caches of the bitfield.
+    summary: &'a [usize],
+}
+
+/// Defines functionality for BitFieldRef.
+impl<'a> BitFieldRef<'a> {
+    /// Gets the number of summary words a view needs.
+    ///
+    /// # Arguments
+    /// words - Provides the number of words in the bitfield.
+    ///
+    /// # Returns
+    /// The number of summary words.
+    pub const fn get_summary_size(words: usize) -> usize {
+        get_summary_size(words)
+    }
+
+    /// Creates a BitFieldRef over caller provided memory, validating the summary.
+    ///
+    /// # Arguments
+    /// bitfield - Provides the words of the bitfield.
+    /// summary - Provides the summary of the bitfield.
+    ///
+    /// # Returns
+    /// The view.
+    /// `BitFieldError::SizeMismatch` if summary has the wrong number of words.
+    /// `BitFieldError::InvalidFormat` if summary does not describe bitfield.
+    ///
+    /// # Note
+    /// An all zero summary is not valid even for an all zero bitfield, since the clear caches mark
+    /// every word that has clear bits. Freshly reserved or zeroed memory must be given a summary
+    /// with `BitFieldMut::with_rebuilt_summary` before it can be viewed.
+    pub fn new(bitfield: &'a [usize], summary: &'a [usize]) -> Result<Self, BitFieldError> {
+        validate_summary(bitfield, summary)?;
+        Ok(BitFieldRef { bitfield, summary })
+    }
+
+    /// Gets the layer caches of the bit field.
+    ///
+    /// # Returns
+    /// The summary layers describing which words have any set bits.
+    fn layer_caches(&self) -> SummaryLayers<'a> {
+        let (layer_caches, _) = self.summary.split_at(self.summary.len() / 2);
+        SummaryLayers::new(layer_caches, self.bitfield.len())
+    }
+
+    /// Gets the clear caches of the bit field.
+    ///
+    /// # Returns
+    /// The summary layers describing which words have any clear bits.
+    fn clear_caches(&self) -> SummaryLayers<'a> {
+        let (_, clear_caches) = self.summary.split_at(self.summary.len() / 2);
+        SummaryLayers::new(clear_caches, self.bitfield.len())
+    }
+
+    /// Finds the lowest bit at or after an index that is set in the bit field, or clear if the
+    /// bit field is inverted.
+    ///
+    /// # Arguments
+    /// caches - Provides the summary layers matching invert.
+    /// invert - Provides `core::usize::MAX` to search for clear bits or `0` for set bits.
+    /// index - Provides the bit to start searching from. Must be less than `capacity()`.
+    ///
+    /// # Returns
+    /// The index of the bit or `None` if there is no such bit.
+    fn find_next(&self, caches: SummaryLayers<'_>, invert: usize, index: usize) -> Option<usize> {
+        let word_index = index / BIT_FIELD_VIEW_WORD_BITS;
+        let word = (self.bitfield[word_index] ^ invert)
+            & (core::usize::MAX << (index % BIT_FIELD_VIEW_WORD_BITS));
+
+        if word != 0 {
+            return Some((word_index * BIT_FIELD_VIEW_WORD_BITS) + find_lowest_set_bit(word));
+        }
+
+        //
+        // Climb the summary layers until one marks a word after the current position, then
+        // descend through the lowest marked words back to the bit field.
+        //
+
+        let mut position = word_index + 1;
+        let mut layer = 0;
+        loop {
+            let cache = caches.get(layer)?;
+            let cache_index = position / BIT_FIELD_VIEW_WORD_BITS;
+            let marked = cache.get(cache_index)?
+                & (core::usize::MAX << (position % BIT_FIELD_VIEW_WORD_BITS));
+
+            if marked != 0 {
+                position = (cache_index * BIT_FIELD_VIEW_WORD_BITS) + find_lowest_set_bit(marked);
+                break;
+            }
+
+            position = cache_index + 1;
+            layer += 1;
+        }
+
+        for layer in (0..layer).rev() {
+            let cache = caches.get(layer)?;
+            position = (position * BIT_FIELD_VIEW_WORD_BITS) + find_lowest_set_bit(cache[position]);
+        }
+
+        let word = self.bitfield[position] ^ invert;
+        Some((position * BIT_FIELD_VIEW_WORD_BITS) + find_lowest_set_bit(word))
+    }
+
+    /// Finds the highest set bit at or before an index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from. Must be less than `capacity()`.
+    ///
+    /// # Returns
+    /// The index of the bit or `None` if there is no such bit.
+    fn find_previous(&self, index: usize) -> Option<usize> {
+        let word_index = index / BIT_FIELD_VIEW_WORD_BITS;
+        let word = self.bitfield[word_index]
+            & (core::usize::MAX
+                >> (BIT_FIELD_VIEW_WORD_BITS - 1 - (index % BIT_FIELD_VIEW_WORD_BITS)));
+
+        if word != 0 {
+            return Some((word_index * BIT_FIELD_VIEW_WORD_BITS) + find_highest_set_bit(word));
+        }
+
+        //
+        // Climb the summary layers until one marks a word before the current position, then
+        // descend through the highest marked words back to the bit field.
+        //
+
+        let caches = self.layer_caches();
+        let mut position = word_index.checked_sub(1)?;
+        let mut layer = 0;
+        loop {
+            let cache = caches.get(layer)?;
+            let cache_index = position / BIT_FIELD_VIEW_WORD_BITS;
+            let marked = cache[cache_index]
+                & (core::usize::MAX
+                    >> (BIT_FIELD_VIEW_WORD_BITS - 1 - (position % BIT_FIELD_VIEW_WORD_BITS)));
+
+            if marked != 0 {
+                position = (cache_index * BIT_FIELD_VIEW_WORD_BITS) + find_highest_set_bit(marked);
+                break;
+            }
+
+            position = cache_index.checked_sub(1)?;
+            layer += 1;
+        }
+
+        for layer in (0..layer).rev() {
+            let cache = caches.get(layer)?;
+            position =
+                (position * BIT_FIELD_VIEW_WORD_BITS) + find_highest_set_bit(cache[position]);
+        }
+
+        Some((position * BIT_FIELD_VIEW_WORD_BITS) + find_highest_set_bit(self.bitfield[position]))
+    }
+
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    pub fn capacity(&self) -> usize {
+        self.bitfield.len() * BIT_FIELD_VIEW_WORD_BITS
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.layer_caches().get_top() == 0
+    }
+
+    /// Determines whether or not every bit in the bitfield is set. A bitfield with no bits is
+    /// considered full.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    pub fn is_full(&self) -> bool {
+        self.clear_caches().get_top() == 0
+    }
+
+    /// Gets the lowest set bit using one bit scan per summary layer.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
+    pub fn get_lowest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let word_index = self.layer_caches().find_lowest_word();
+        Some(
+            (word_index * BIT_FIELD_VIEW_WORD_BITS)
+                + find_lowest_set_bit(self.bitfield[word_index]),
+        )
+    }
+
+    /// Gets the highest set bit using one bit scan per summary layer.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    pub fn get_highest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let word_index = self.layer_caches().find_highest_word();
+        Some(
+            (word_index * BIT_FIELD_VIEW_WORD_BITS)
+                + find_highest_set_bit(self.bitfield[word_index]),
+        )
+    }
+
+    /// Gets the lowest clear bit using one bit scan per summary layer.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    pub fn get_lowest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        let word_index = self.clear_caches().find_lowest_word();
+        Some(
+            (word_index * BIT_FIELD_VIEW_WORD_BITS)
+                + find_lowest_set_bit(!self.bitfield[word_index]),
+        )
+    }
+
+    /// Gets the highest clear bit using one bit scan per summary layer.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    pub fn get_highest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        let word_index = self.clear_caches().find_highest_word();
+        Some(
+            (word_index * BIT_FIELD_VIEW_WORD_BITS)
+                + find_highest_set_bit(!self.bitfield[word_index]),
+        )
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    pub fn get_next_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= self.capacity() {
+            return None;
+        }
+
+        self.find_next(self.layer_caches(), 0, index)
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    pub fn get_previous_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= self.capacity() {
+            return None;
+        }
+
+        self.find_previous(index)
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    pub fn get_next_clear_bit(&self, index: usize) -> Option<usize> {
+        if index >= self.capacity() {
+            return None;
+        }
+
+        self.find_next(self.clear_caches(), core::usize::MAX, index)
+    }
+
+    /// Gets the number of set bits in the bitfield.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    pub fn count_set_bits(&self) -> usize {
+        self.bitfield
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Gets the number of set bits below a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    pub fn rank(&self, index: usize) -> usize {
+        if index >= self.capacity() {
+            return self.count_set_bits();
+        }
+
+        let word_index = index / BIT_FIELD_VIEW_WORD_BITS;
+        let below = self.bitfield[word_index] & ((1 << (index % BIT_FIELD_VIEW_WORD_BITS)) - 1);
+        let count: usize = self.bitfield[..word_index]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+
+        count + below.count_ones() as usize
+    }
+
+    /// Gets the set bit with a given rank.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    pub fn select(&self, rank: usize) -> Option<usize> {
+        let mut rank = rank;
+        for (word_index, word) in self.bitfield.iter().enumerate() {
+            let count = word.count_ones() as usize;
+            if rank < count {
+                return Some(
+                    (word_index * BIT_FIELD_VIEW_WORD_BITS) + find_set_bit_with_rank(*word, rank),
+                );
+            }
+
+            rank -= count;
+        }
+
+        None
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    pub fn test_bit(&self, index: usize) -> Option<bool> {
+        let word = self.bitfield.get(index / BIT_FIELD_VIEW_WORD_BITS)?;
+        Some((word & (1 << (index % BIT_FIELD_VIEW_WORD_BITS))) != 0)
+    }
+
+    /// Gets the value of a specific bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Ok(true)` if bit is set.
+    /// `Ok(false)` if bit is cleared.
+    /// `BitFieldError::IndexOutOfRange` if index is invalid.
+    pub fn try_test_bit(&self, index: usize) -> Result<bool, BitFieldError> {
+        self.test_bit(index).ok_or(BitFieldError::IndexOutOfRange)
+    }
+
+    /// Finds the lowest run of consecutive clear bits.
+    ///
+    /// # Arguments
+    /// len - Provides the number of consecutive clear bits required.
+    /// align - Provides the alignment the first bit of the run must have.
+    ///
+    /// # Returns
+    /// The index of the first bit of the lowest run of len clear bits starting at a multiple of
+    /// align or `None` if no such run exists. A len or align of zero results in `None`.
+    pub fn find_clear_run(&self, len: usize, align: usize) -> Option<usize> {
+        search_clear_run(self, len, align)
+    }
+}
+
+/// Defines the structure for mutable Bitfield Views.
+/// A mutable Bitfield View is a BitFieldRef that can also set and clear bits, keeping the caller
+/// provided summary up to date with one summary word update per layer.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::{BitFieldError, BitFieldMut, BitFieldRef};
+///
+/// let mut words = [0, 0b1000];
+/// let mut summary = [0; BitFieldRef::get_summary_size(2)];
+///
+/// //
+/// // The summary is stale, so it has to be rebuilt rather than validated.
+/// //
+///
+/// assert_eq!(
+///     BitFieldMut::new(&mut words, &mut summary).err(),
+///     Some(BitFieldError::InvalidFormat)
+/// );
+///
+/// let word_bits = core::mem::size_of::<usize>() * 8;
+/// let mut view = BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+/// assert_eq!(view.get_lowest_set_bit(), Some(word_bits + 3));
+///
+/// view.clear_bit(word_bits + 3);
+/// assert!(view.is_empty());
+/// ```
+pub struct BitFieldMut<'a> {
+    /// Holds the bitfield state.
+    bitfield: &'a mut [usize],
+
+    /// Holds the layer caches followed by the clear caches of the bitfield.
+    summary: &'a mut [usize],
+}
+
+/// Defines functionality unique to BitFieldMut.
+impl<'a> BitFieldMut<'a> {
+    /// Creates a BitFieldMut over caller provided memory, validating the summary.
+    ///
+    /// # Arguments
+    /// bitfield - Provides the words of the bitfield.
+    /// summary - Provides the summary of the bitfield.
+    ///
+    /// # Returns
+    /// The view.
+    /// `BitFieldError::SizeMismatch` if summary has the wrong number of words.
+    /// `BitFieldError::InvalidFormat` if summary does not describe bitfield.
+    ///
+    /// # Note
+    /// An all zero summary is not valid even for an all zero bitfield, since the clear caches mark
+    /// every word that has clear bits. Use `with_rebuilt_summary` for freshly reserved or zeroed
+    /// memory.
+    pub fn new(bitfield: &'a mut [usize], summary: &'a mut [usize]) -> Result<Self, BitFieldError> {
+        validate_summary(bitfield, summary)?;
+        Ok(BitFieldMut { bitfield, summary })
+    }
+
+    /// Creates a BitFieldMut over caller provided memory, overwriting the summary with one computed
+    /// from the bitfield. Use this when the summary storage is zeroed, uninitialized or stale.
+    ///
+    /// # Arguments
+    /// bitfield - Provides the words of the bitfield.
+    /// summary - Provides the storage for the summary of the bitfield.
+    ///
+    /// # Returns
+    /// The view or `BitFieldError::SizeMismatch` if summary has the wrong number of words.
+    pub fn with_rebuilt_summary(
+        bitfield: &'a mut [usize],
+        summary: &'a mut [usize],
+    ) -> Result<Self, BitFieldError> {
+        if summary.len() != get_summary_size(bitfield.len()) {
+            return Err(BitFieldError::SizeMismatch);
+        }
+
+        let mut view = BitFieldMut { bitfield, summary };
+        view.rebuild_summary();
+        Ok(view)
+    }
+
+    /// Recomputes every summary layer from the words of the bit field.
+    pub fn rebuild_summary(&mut self) {
+        let half = self.summary.len() / 2;
+        let (layer_caches, clear_caches) = self.summary.split_at_mut(half);
+        Self::summarize(self.bitfield, layer_caches, |word| word != 0);
+        Self::summarize(self.bitfield, clear_caches, |word| word != core::usize::MAX);
+    }
+
+    /// Gets a read-only view of the bit field.
+    ///
+    /// # Returns
+    /// A BitFieldRef over the same memory.
+    pub fn as_bitfield_ref(&self) -> BitFieldRef<'_> {
+        BitFieldRef {
+            bitfield: self.bitfield,
+            summary: self.summary,
+        }
+    }
+
+    /// Finds the lowest run of consecutive clear bits.
+    ///
+    /// # Arguments
+    /// len - Provides the number of consecutive clear bits required.
+    /// align - Provides the alignment the first bit of the run must have.
+    ///
+    /// # Returns
+    /// The index of the first bit of the lowest run of len clear bits starting at a multiple of
+    /// align or `None` if no such run exists. A len or align of zero results in `None`.
+    pub fn find_clear_run(&self, len: usize, align: usize) -> Option<usize> {
+        self.as_bitfield_ref().find_clear_run(len, align)
+    }
+
+    /// Builds a stack of summary layers for the words of a bit field.
+    ///
+    /// # Arguments
+    /// words - Provides the words of the bit field.
+    /// stack - Provides the storage for the layers, lowest layer first.
+    /// predicate - Provides whether or not a word of the bit field should be marked in the lowest
+    /// layer.
+    fn summarize(words: &[usize], stack: &mut [usize], predicate: WordPredicate) {
+        let mut predicate = predicate;
+        let mut below_start = 0;
+        let mut below_size = words.len();
+        let mut start = 0;
+        while start < stack.len() {
+            let size = below_size.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+            for index in 0..size {
+                let chunk_start = index * BIT_FIELD_VIEW_WORD_BITS;
+                let chunk_end = core::cmp::min(chunk_start + BIT_FIELD_VIEW_WORD_BITS, below_size);
+                stack[start + index] = if start == 0 {
+                    compute_summary_word(&words[chunk_start..chunk_end], predicate)
+                } else {
+                    let below = &stack[(below_start + chunk_start)..(below_start + chunk_end)];
+                    compute_summary_word(below, predicate)
+                };
+            }
+
+            //
+            // Above the first layer a word has set or clear bits below it exactly when it is
+            // non-zero.
+            //
+
+            predicate = |word| word != 0;
+            below_start = start;
+            below_size = size;
+            start += size;
+        }
+    }
+
+    /// Updates every summary layer above a word after the word has changed.
+    ///
+    /// # Arguments
+    /// word_index - Provides the index of the word that changed.
+    fn update_caches(&mut self, word_index: usize) {
+        let half = self.summary.len() / 2;
+        let (layer_caches, clear_caches) = self.summary.split_at_mut(half);
+        let mut index = word_index;
+        let mut has_set_bits = self.bitfield[index] != 0;
+        let mut has_clear_bits = self.bitfield[index] != core::usize::MAX;
+        let mut size = self.bitfield.len();
+        let mut start = 0;
+        while start < half {
+            let bit = 1 << (index % BIT_FIELD_VIEW_WORD_BITS);
+            index /= BIT_FIELD_VIEW_WORD_BITS;
+            size = size.div_ceil(BIT_FIELD_VIEW_WORD_BITS);
+
+            //
+            // Turn booleans into usizes to avoid branching.
+            //
+
+            let layer_cache = &mut layer_caches[start + index];
+            let clear_cache = &mut clear_caches[start + index];
+            *layer_cache = (*layer_cache & !bit) | ((has_set_bits as usize) * bit);
+            *clear_cache = (*clear_cache & !bit) | ((has_clear_bits as usize) * bit);
+            has_set_bits = *layer_cache != 0;
+            has_clear_bits = *clear_cache != 0;
+            start += size;
+        }
+    }
+}
+
+/// Defines the bit operations of BitFieldMut.
+impl BitFieldMut<'_> {
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    pub fn capacity(&self) -> usize {
+        self.as_bitfield_ref().capacity()
+    }
+
+    /// Sets a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set. Invalid indices are ignored.
+    pub fn set_bit(&mut self, index: usize) {
+        if index < self.capacity() {
+            let word_index = index / BIT_FIELD_VIEW_WORD_BITS;
+            self.bitfield[word_index] |= 1 << (index % BIT_FIELD_VIEW_WORD_BITS);
+            self.update_caches(word_index);
+        }
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear. Invalid indices are ignored.
+    pub fn clear_bit(&mut self, index: usize) {
+        if index < self.capacity() {
+            let word_index = index / BIT_FIELD_VIEW_WORD_BITS;
+            self.bitfield[word_index] &= !(1 << (index % BIT_FIELD_VIEW_WORD_BITS));
+            self.update_caches(word_index);
+        }
+    }
+
+    /// Sets a bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Returns
+    /// `Ok(())` if the bit was set or `BitFieldError::IndexOutOfRange` if index is invalid.
+    pub fn try_set_bit(&mut self, index: usize) -> Result<(), BitFieldError> {
+        if index >= self.capacity() {
+            return Err(BitFieldError::IndexOutOfRange);
+        }
+
+        self.set_bit(index);
+        Ok(())
+    }
+
+    /// Clears a bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Returns
+    /// `Ok(())` if the bit was cleared or `BitFieldError::IndexOutOfRange` if index is invalid.
+    pub fn try_clear_bit(&mut self, index: usize) -> Result<(), BitFieldError> {
+        if index >= self.capacity() {
+            return Err(BitFieldError::IndexOutOfRange);
+        }
+
+        self.clear_bit(index);
+        Ok(())
+    }
+
+    /// Gets the lowest set bit.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
+    pub fn get_lowest_set_bit(&self) -> Option<usize> {
+        self.as_bitfield_ref().get_lowest_set_bit()
+    }
+
+    /// Gets the highest set bit.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    pub fn get_highest_set_bit(&self) -> Option<usize> {
+        self.as_bitfield_ref().get_highest_set_bit()
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    pub fn get_next_set_bit(&self, index: usize) -> Option<usize> {
+        self.as_bitfield_ref().get_next_set_bit(index)
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    pub fn get_previous_set_bit(&self, index: usize) -> Option<usize> {
+        self.as_bitfield_ref().get_previous_set_bit(index)
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    pub fn test_bit(&self, index: usize) -> Option<bool> {
+        self.as_bitfield_ref().test_bit(index)
+    }
+
+    /// Gets the value of a specific bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Ok(true)` if bit is set.
+    /// `Ok(false)` if bit is cleared.
+    /// `BitFieldError::IndexOutOfRange` if index is invalid.
+    pub fn try_test_bit(&self, index: usize) -> Result<bool, BitFieldError> {
+        self.as_bitfield_ref().try_test_bit(index)
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.as_bitfield_ref().is_empty()
+    }
+
+    /// Determines whether or not every bit in the bitfield is set. A bitfield with no bits is
+    /// considered full.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    pub fn is_full(&self) -> bool {
+        self.as_bitfield_ref().is_full()
+    }
+
+    /// Gets the number of set bits in the bitfield.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    pub fn count_set_bits(&self) -> usize {
+        self.as_bitfield_ref().count_set_bits()
+    }
+
+    /// Gets the number of set bits below a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    pub fn rank(&self, index: usize) -> usize {
+        self.as_bitfield_ref().rank(index)
+    }
+
+    /// Gets the set bit with a given rank.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    pub fn select(&self, rank: usize) -> Option<usize> {
+        self.as_bitfield_ref().select(rank)
+    }
+
+    /// Gets the lowest clear bit.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    pub fn get_lowest_clear_bit(&self) -> Option<usize> {
+        self.as_bitfield_ref().get_lowest_clear_bit()
+    }
+
+    /// Gets the highest clear bit.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    pub fn get_highest_clear_bit(&self) -> Option<usize> {
+        self.as_bitfield_ref().get_highest_clear_bit()
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    pub fn get_next_clear_bit(&self, index: usize) -> Option<usize> {
+        self.as_bitfield_ref().get_next_clear_bit(index)
+    }
+}
+
+/// Defines the searches shared with the other bitfields for BitFieldRef.
+impl SearchBitField for BitFieldRef<'_> {
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    fn get_bit_count(&self) -> usize {
+        self.capacity()
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None`.
+    fn find_next_set_bit(&self, index: usize) -> Option<usize> {
+        self.get_next_set_bit(index)
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None`.
+    fn find_next_clear_bit(&self, index: usize) -> Option<usize> {
+        self.get_next_clear_bit(index)
+    }
+}
+
+/// Defines how a BitFieldRef is displayed for debugging.
+impl fmt::Debug for BitFieldRef<'_> {
+    /// Formats the set bits of the bitfield, collapsing runs of set bits into ranges.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the bitfield.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_set_bits(self, "BitFieldRef", f)
+    }
+}
+
+/// Defines how a BitFieldMut is displayed for debugging.
+impl fmt::Debug for BitFieldMut<'_> {
+    /// Formats the set bits of the bitfield, collapsing runs of set bits into ranges.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the bitfield.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_set_bits(&self.as_bitfield_ref(), "BitFieldMut", f)
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FastBitField, LargeBitField};
+
+    /// Holds enough words for two words in the lowest summary layer.
+    const WORDS: usize = BIT_FIELD_VIEW_WORD_BITS + 3;
+
+    /// Holds the numbers of words exercised by the tests, covering one, two and three summary
+    /// layers.
+    const WORD_COUNTS: [usize; 4] = [
+        1,
+        3,
+        BIT_FIELD_VIEW_WORD_BITS + 5,
+        (BIT_FIELD_VIEW_WORD_BITS * BIT_FIELD_VIEW_WORD_BITS) + 5,
+    ];
+
+    /// Builds the words of a bitfield and the matching list of bit values from a pseudo random
+    /// pattern.
+    fn make_pattern(words: usize, seed: usize) -> (Vec<usize>, Vec<bool>) {
+        let capacity = words * BIT_FIELD_VIEW_WORD_BITS;
+        let mut bitfield = vec![0; words];
+        let mut expected = vec![false; capacity];
+        let mut state = (seed as u64).wrapping_mul(2654435761).wrapping_add(1);
+        for _ in 0..64 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            let index = (state >> 33) as usize % capacity;
+            bitfield[index / BIT_FIELD_VIEW_WORD_BITS] |= 1 << (index % BIT_FIELD_VIEW_WORD_BITS);
+            expected[index] = true;
+        }
+
+        (bitfield, expected)
+    }
+
+    #[test]
+    fn validate_summary_size() {
+        assert_eq!(BitFieldRef::get_summary_size(0), 0);
+        assert_eq!(BitFieldRef::get_summary_size(1), 2);
+        assert_eq!(BitFieldRef::get_summary_size(BIT_FIELD_VIEW_WORD_BITS), 2);
+        assert_eq!(BitFieldRef::get_summary_size(WORDS), 6);
+        assert_eq!(
+            BitFieldRef::get_summary_size(BIT_FIELD_VIEW_WORD_BITS * BIT_FIELD_VIEW_WORD_BITS + 1),
+            (BIT_FIELD_VIEW_WORD_BITS + 1 + 2 + 1) * 2
+        );
+
+        for words in WORD_COUNTS.iter() {
+            let summary = vec![0; BitFieldRef::get_summary_size(*words)];
+            let caches = SummaryLayers::new(&summary[..summary.len() / 2], *words);
+            let mut total = 0;
+            for layer in 0..caches.count() {
+                total += caches.get(layer).unwrap().len();
+            }
+
+            assert_eq!(total, summary.len() / 2);
+            assert_eq!(caches.get(caches.count() - 1).unwrap().len(), 1);
+            assert_eq!(caches.get(caches.count()), None);
+        }
+    }
+
+    #[test]
+    fn validate_summary_checks() {
+        let mut words = [0; WORDS];
+        let mut summary = [0; BitFieldRef::get_summary_size(WORDS)];
+
+        //
+        // An empty bitfield has every word marked in the clear caches.
+        //
+
+        assert_eq!(
+            BitFieldRef::new(&words, &summary).err(),
+            Some(BitFieldError::InvalidFormat)
+        );
+
+        BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+        assert_eq!(summary, [0, 0, 0, core::usize::MAX, 0b111, 0b11]);
+        assert!(BitFieldRef::new(&words, &summary).is_ok());
+        assert_eq!(
+            BitFieldRef::new(&words, &summary[..1]).err(),
+            Some(BitFieldError::SizeMismatch)
+        );
+
+        //
+        // Summary bits past the last word are invalid as well.
+        //
+
+        summary[1] = 1 << 3;
+        assert_eq!(
+            BitFieldRef::new(&words, &summary).err(),
+            Some(BitFieldError::InvalidFormat)
+        );
+
+        //
+        // So are upper layers that do not describe the layers below them.
+        //
+
+        summary[1] = 0;
+        summary[2] = 1;
+        assert_eq!(
+            BitFieldRef::new(&words, &summary).err(),
+            Some(BitFieldError::InvalidFormat)
+        );
+
+        words[WORDS - 1] = 1;
+        assert_eq!(
+            BitFieldMut::new(&mut words, &mut summary).err(),
+            Some(BitFieldError::InvalidFormat)
+        );
+        assert_eq!(
+            BitFieldMut::with_rebuilt_summary(&mut words, &mut summary[..1]).err(),
+            Some(BitFieldError::SizeMismatch)
+        );
+
+        let view = BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+        assert_eq!(
+            view.get_lowest_set_bit(),
+            Some((WORDS - 1) * BIT_FIELD_VIEW_WORD_BITS)
+        );
+        assert_eq!(&summary[..3], [0, 1 << 2, 0b10]);
+    }
+
+    #[test]
+    fn create_empty_view() {
+        let view = BitFieldRef::new(&[], &[]).unwrap();
+        assert_eq!(view.capacity(), 0);
+        assert!(view.is_empty());
+        assert!(view.is_full());
+        assert_eq!(view.get_lowest_set_bit(), None);
+        assert_eq!(view.get_highest_set_bit(), None);
+        assert_eq!(view.get_lowest_clear_bit(), None);
+        assert_eq!(view.get_next_set_bit(0), None);
+        assert_eq!(view.test_bit(0), None);
+
+        let mut view = BitFieldMut::new(&mut [], &mut []).unwrap();
+        assert_eq!(view.capacity(), 0);
+        view.set_bit(0);
+        assert!(view.is_empty());
+        assert_eq!(view.try_set_bit(0), Err(BitFieldError::IndexOutOfRange));
+    }
+
+    #[test]
+    fn validate_against_large_bitfield() {
+        let mut words = [0; BIT_FIELD_VIEW_WORD_BITS];
+        let mut summary = [0; BitFieldRef::get_summary_size(BIT_FIELD_VIEW_WORD_BITS)];
+        let mut view = BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+        let mut large = LargeBitField::new();
+        assert_eq!(view.capacity(), LargeBitField::get_number_of_bits());
+
+        let indices = [5, 700, 64, 3000, 1, LargeBitField::get_number_of_bits() - 1];
+        for index in indices.iter() {
+            view.set_bit(*index);
+            large.set_bit(*index);
+            assert_eq!(view.get_lowest_set_bit(), large.get_lowest_set_bit());
+            assert_eq!(view.get_highest_set_bit(), large.get_highest_set_bit());
+            assert_eq!(view.get_lowest_clear_bit(), large.get_lowest_clear_bit());
+            assert_eq!(view.get_highest_clear_bit(), large.get_highest_clear_bit());
+        }
+
+        for index in indices.iter() {
+            view.clear_bit(*index);
+            large.clear_bit(*index);
+            assert_eq!(view.test_bit(*index), Some(false));
+            assert_eq!(view.get_lowest_set_bit(), large.get_lowest_set_bit());
+            assert_eq!(view.get_highest_set_bit(), large.get_highest_set_bit());
+        }
+
+        assert!(view.is_empty());
+        assert_eq!(&words[..], &large.as_words()[..]);
+    }
+
+    #[test]
+    fn validate_multiple_summary_words() {
+        let mut words = [0; WORDS];
+        let mut summary = [0; BitFieldRef::get_summary_size(WORDS)];
+        let capacity = WORDS * BIT_FIELD_VIEW_WORD_BITS;
+        let mut view = BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+        assert_eq!(view.capacity(), capacity);
+
+        view.set_bit(capacity - 1);
+        view.set_bit(capacity - 2);
+        view.set_bit(capacity);
+        assert_eq!(view.get_lowest_set_bit(), Some(capacity - 2));
+        assert_eq!(view.get_highest_set_bit(), Some(capacity - 1));
+        assert_eq!(view.test_bit(capacity), None);
+
+        view.set_bit(3);
+        assert_eq!(view.get_lowest_set_bit(), Some(3));
+
+        //
+        // Clearing one of two bits in a word must leave the word marked in the summary.
+        //
+
+        view.clear_bit(capacity - 1);
+        assert_eq!(view.get_highest_set_bit(), Some(capacity - 2));
+        view.clear_bit(capacity - 2);
+        assert_eq!(view.get_highest_set_bit(), Some(3));
+
+        //
+        // The summary written through the view must be valid for a new view.
+        //
+
+        let view = BitFieldRef::new(&words, &summary).unwrap();
+        assert_eq!(view.get_lowest_set_bit(), Some(3));
+    }
+
+    #[test]
+    fn validate_against_reference() {
+        for words in WORD_COUNTS.iter() {
+            let capacity = words * BIT_FIELD_VIEW_WORD_BITS;
+            for seed in 0..4 {
+                let (mut bitfield, expected) = make_pattern(*words, seed);
+                let mut summary = vec![0; BitFieldRef::get_summary_size(*words)];
+                let view = BitFieldMut::with_rebuilt_summary(&mut bitfield, &mut summary).unwrap();
+
+                let set_bits: Vec<usize> = (0..capacity).filter(|index| expected[*index]).collect();
+                let clear_bits: Vec<usize> =
+                    (0..capacity).filter(|index| !expected[*index]).collect();
+
+                assert_eq!(view.count_set_bits(), set_bits.len());
+                assert_eq!(view.get_lowest_set_bit(), set_bits.first().copied());
+                assert_eq!(view.get_highest_set_bit(), set_bits.last().copied());
+                assert_eq!(view.get_lowest_clear_bit(), clear_bits.first().copied());
+                assert_eq!(view.get_highest_clear_bit(), clear_bits.last().copied());
+
+                for (rank, index) in set_bits.iter().enumerate() {
+                    assert_eq!(view.select(rank), Some(*index));
+                    assert_eq!(view.rank(*index), rank);
+                }
+
+                assert_eq!(view.select(set_bits.len()), None);
+
+                //
+                // Sample the searches rather than visiting every index of the larger bitfields.
+                //
+
+                for index in (0..capacity).step_by(7) {
+                    let next = set_bits.iter().copied().find(|bit| *bit >= index);
+                    let previous = set_bits.iter().rev().copied().find(|bit| *bit <= index);
+                    let next_clear = (index..capacity).find(|bit| !expected[*bit]);
+                    assert_eq!(view.get_next_set_bit(index), next);
+                    assert_eq!(view.get_previous_set_bit(index), previous);
+                    assert_eq!(view.get_next_clear_bit(index), next_clear);
+                    assert_eq!(view.test_bit(index), Some(expected[index]));
+                }
+
+                assert_eq!(view.get_next_set_bit(capacity), None);
+                assert_eq!(view.test_bit(capacity), None);
+            }
+        }
+    }
+
+    #[test]
+    fn validate_fill_and_clear() {
+        for words in WORD_COUNTS.iter() {
+            let capacity = words * BIT_FIELD_VIEW_WORD_BITS;
+            let mut bitfield = vec![0; *words];
+            let mut summary = vec![0; BitFieldRef::get_summary_size(*words)];
+            let mut view = BitFieldMut::with_rebuilt_summary(&mut bitfield, &mut summary).unwrap();
+            for index in 0..capacity {
+                view.set_bit(index);
+            }
+
+            assert!(view.is_full());
+            assert_eq!(view.get_lowest_clear_bit(), None);
+            assert_eq!(view.get_next_clear_bit(0), None);
+
+            //
+            // Clearing a single bit deep in the bitfield should be found from the top layer.
+            //
+
+            let index = (capacity / 3) + 1;
+            view.clear_bit(index);
+            assert!(!view.is_full());
+            assert_eq!(view.get_lowest_clear_bit(), Some(index));
+            assert_eq!(view.get_highest_clear_bit(), Some(index));
+            assert_eq!(view.get_next_clear_bit(0), Some(index));
+            assert_eq!(view.find_clear_run(1, 1), Some(index));
+
+            for index in 0..capacity {
+                view.clear_bit(index);
+            }
+
+            assert!(view.is_empty());
+            assert_eq!(view.get_highest_set_bit(), None);
+            assert_eq!(view.get_previous_set_bit(capacity - 1), None);
+
+            //
+            // The summary maintained by the view must match a rebuilt one.
+            //
+
+            assert!(BitFieldRef::new(&bitfield, &summary).is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_fallible_operations() {
+        let mut words = [0; WORDS];
+        let mut summary = [0; BitFieldRef::get_summary_size(WORDS)];
+        let capacity = WORDS * BIT_FIELD_VIEW_WORD_BITS;
+        let mut view = BitFieldMut::with_rebuilt_summary(&mut words, &mut summary).unwrap();
+
+        assert_eq!(
+            view.try_set_bit(capacity),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(
+            view.try_clear_bit(capacity),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(view.try_set_bit(1), Ok(()));
+        assert_eq!(view.try_set_bit(2), Ok(()));
+        assert_eq!(view.try_set_bit(capacity - 1), Ok(()));
+        assert_eq!(view.try_test_bit(2), Ok(true));
+        assert_eq!(view.try_clear_bit(2), Ok(()));
+        assert_eq!(view.try_test_bit(2), Ok(false));
+        assert_eq!(
+            view.try_test_bit(capacity),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(view.find_clear_run(3, 4), Some(4));
+        assert_eq!(
+            format!("{:?}", view),
+            format!("BitFieldMut {{1, {}}}", capacity - 1)
+        );
+        assert_eq!(
+            format!("{:?}", view.as_bitfield_ref()),
+            format!("BitFieldRef {{1, {}}}", capacity - 1)
+        );
+    }
+}
@@ -0,0 +1,947 @@
+use crate::{
+    check_unchecked_index, find_highest_set_bit, find_lowest_set_bit, find_set_bit_with_rank,
+    format_set_bits, search_clear_run, FastBitField,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Defines the number of bits in each word of a dynamic bitfield, which is also the number of words
+/// described by each word of a summary layer.
+const DYNAMIC_BIT_FIELD_WORD_BITS: usize = core::mem::size_of::<usize>() * 8;
+
+/// Defines the structure and fast_bitfield interface for Dynamic Bitfields.
+/// A Dynamic Bitfield is a heap allocated structure whose number of bits is chosen at runtime and
+/// can grow. The bits are held in a vector of `usize` words summarized by a stack of
+/// "layer_caches", where each bit of a layer describes whether the corresponding word of the layer
+/// below has any bits set, up to a single top word. "clear_caches" likewise describe which words
+/// have any bits clear. They are the inverse of the "full_cache" of a LargeBitField so that the
+/// unused bits at the end of a partially filled layer never describe a word.
+///
+/// Lowest and highest set and clear bit lookups take a single bit scan per layer, and a layer is
+/// only added each time the capacity grows by a factor of `sizeof(usize) * 8`. The capacity is
+/// always a multiple of `sizeof(usize) * 8` bits.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::{DynamicBitField, FastBitField};
+///
+/// let word_bits = core::mem::size_of::<usize>() * 8;
+/// let mut descriptors = DynamicBitField::with_capacity(3);
+/// assert_eq!(descriptors.capacity(), word_bits);
+///
+/// while let Some(descriptor) = descriptors.get_lowest_clear_bit() {
+///     descriptors.set_bit(descriptor);
+/// }
+///
+/// descriptors.grow(word_bits * 2);
+/// assert_eq!(descriptors.get_lowest_clear_bit(), Some(word_bits));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DynamicBitField {
+    /// Holds the bitfield state.
+    bitfield: Vec<usize>,
+
+    /// Holds the summary layers describing which words of the layer below have any set bits,
+    /// lowest layer first.
+    layer_caches: Vec<Vec<usize>>,
+
+    /// Holds the summary layers describing which words of the layer below have any clear bits,
+    /// lowest layer first.
+    clear_caches: Vec<Vec<usize>>,
+}
+
+/// Defines functionality unique to DynamicBitField.
+impl DynamicBitField {
+    /// Creates a new, empty DynamicBitField able to hold at least a given number of bits.
+    ///
+    /// # Arguments
+    /// bits - Provides the number of bits required. The capacity is rounded up to a multiple of
+    /// `sizeof(usize) * 8`.
+    ///
+    /// # Returns
+    /// A DynamicBitField.
+    pub fn with_capacity(bits: usize) -> Self {
+        let mut dynamic = DynamicBitField::new();
+        dynamic.grow(bits);
+        dynamic
+    }
+
+    /// Grows the bitfield to hold at least a given number of bits. The new bits are clear and the
+    /// bitfield never shrinks.
+    ///
+    /// # Arguments
+    /// bits - Provides the number of bits required. The capacity is rounded up to a multiple of
+    /// `sizeof(usize) * 8`.
+    ///
+    /// # Note
+    /// Growing rebuilds the summary layers, adding layers as needed, which takes time proportional
+    /// to the number of words in the bitfield.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{DynamicBitField, FastBitField};
+    ///
+    /// let mut dynamic = DynamicBitField::new();
+    /// dynamic.set_bit(1000);
+    /// assert_eq!(dynamic.test_bit(1000), None);
+    ///
+    /// dynamic.grow(1001);
+    /// dynamic.set_bit(1000);
+    /// assert_eq!(dynamic.get_highest_set_bit(), Some(1000));
+    /// ```
+    pub fn grow(&mut self, bits: usize) {
+        let words = bits.div_ceil(DYNAMIC_BIT_FIELD_WORD_BITS);
+        if words <= self.bitfield.len() {
+            return;
+        }
+
+        self.bitfield.resize(words, 0);
+        self.rebuild_summary();
+    }
+
+    /// Finds the lowest run of consecutive clear bits.
+    ///
+    /// # Arguments
+    /// len - Provides the number of consecutive clear bits required.
+    /// align - Provides the alignment the first bit of the run must have.
+    ///
+    /// # Returns
+    /// The index of the first bit of the lowest run of len clear bits starting at a multiple of
+    /// align or `None` if no such run exists. A len or align of zero results in `None`.
+    pub fn find_clear_run(&self, len: usize, align: usize) -> Option<usize> {
+        search_clear_run(self, len, align)
+    }
+
+    /// Recomputes every summary layer from the words of the bit field.
+    fn rebuild_summary(&mut self) {
+        self.layer_caches.clear();
+        self.clear_caches.clear();
+        if self.bitfield.is_empty() {
+            return;
+        }
+
+        let layer_cache = Self::summarize(&self.bitfield, |word| word != 0);
+        let clear_cache = Self::summarize(&self.bitfield, |word| word != core::usize::MAX);
+        self.layer_caches.push(layer_cache);
+        self.clear_caches.push(clear_cache);
+
+        //
+        // Keep summarizing until a layer fits in a single word. Above the first layer a word has
+        // set or clear bits below it exactly when it is non-zero.
+        //
+
+        let mut top = 0;
+        while self.layer_caches[top].len() > 1 {
+            let layer_cache = Self::summarize(&self.layer_caches[top], |word| word != 0);
+            let clear_cache = Self::summarize(&self.clear_caches[top], |word| word != 0);
+            self.layer_caches.push(layer_cache);
+            self.clear_caches.push(clear_cache);
+            top += 1;
+        }
+    }
+
+    /// Builds a summary layer for a layer of words.
+    ///
+    /// # Arguments
+    /// words - Provides the layer to summarize.
+    /// predicate - Provides whether or not a word should be marked in the summary.
+    ///
+    /// # Returns
+    /// A layer with a bit set for every word that matches predicate.
+    fn summarize(words: &[usize], predicate: fn(usize) -> bool) -> Vec<usize> {
+        let mut summary = vec![0; words.len().div_ceil(DYNAMIC_BIT_FIELD_WORD_BITS)];
+        for (index, word) in words.iter().enumerate() {
+            //
+            // Turn booleans into usizes to avoid branching.
+            //
+
+            summary[index / DYNAMIC_BIT_FIELD_WORD_BITS] |=
+                (predicate(*word) as usize) << (index % DYNAMIC_BIT_FIELD_WORD_BITS);
+        }
+
+        summary
+    }
+
+    /// Updates every summary layer above a word after the word has changed.
+    ///
+    /// # Arguments
+    /// word_index - Provides the index of the word that changed.
+    fn update_caches(&mut self, word_index: usize) {
+        let mut index = word_index;
+        let mut has_set_bits = self.bitfield[index] != 0;
+        let mut has_clear_bits = self.bitfield[index] != core::usize::MAX;
+        let layers = self
+            .layer_caches
+            .iter_mut()
+            .zip(self.clear_caches.iter_mut());
+        for (layer_cache, clear_cache) in layers {
+            let bit = 1 << (index % DYNAMIC_BIT_FIELD_WORD_BITS);
+            index /= DYNAMIC_BIT_FIELD_WORD_BITS;
+
+            //
+            // Turn booleans into usizes to avoid branching.
+            //
+
+            layer_cache[index] = (layer_cache[index] & !bit) | ((has_set_bits as usize) * bit);
+            clear_cache[index] = (clear_cache[index] & !bit) | ((has_clear_bits as usize) * bit);
+            has_set_bits = layer_cache[index] != 0;
+            has_clear_bits = clear_cache[index] != 0;
+        }
+    }
+
+    /// Gets a word of the bit field, optionally inverted so that clear bits read as set.
+    ///
+    /// # Arguments
+    /// word_index - Provides the word to get.
+    /// invert - Provides `core::usize::MAX` to invert the word or `0` to leave it as is.
+    ///
+    /// # Returns
+    /// The word.
+    fn get_word(&self, word_index: usize, invert: usize) -> usize {
+        self.bitfield[word_index] ^ invert
+    }
+
+    /// Descends the summary layers to the lowest word they mark.
+    ///
+    /// # Arguments
+    /// caches - Provides the summary layers to descend. The top layer must have a bit set.
+    ///
+    /// # Returns
+    /// The index of the lowest marked word of the bit field.
+    fn find_lowest_word(caches: &[Vec<usize>]) -> usize {
+        let mut index = 0;
+        for cache in caches.iter().rev() {
+            index = (index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(cache[index]);
+        }
+
+        index
+    }
+
+    /// Descends the summary layers to the highest word they mark.
+    ///
+    /// # Arguments
+    /// caches - Provides the summary layers to descend. The top layer must have a bit set.
+    ///
+    /// # Returns
+    /// The index of the highest marked word of the bit field.
+    fn find_highest_word(caches: &[Vec<usize>]) -> usize {
+        let mut index = 0;
+        for cache in caches.iter().rev() {
+            index = (index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_highest_set_bit(cache[index]);
+        }
+
+        index
+    }
+
+    /// Finds the lowest bit at or after an index that is set in the bit field, or clear if the
+    /// bit field is inverted.
+    ///
+    /// # Arguments
+    /// caches - Provides the summary layers matching invert.
+    /// invert - Provides `core::usize::MAX` to search for clear bits or `0` for set bits.
+    /// index - Provides the bit to start searching from. Must be less than `capacity()`.
+    ///
+    /// # Returns
+    /// The index of the bit or `None` if there is no such bit.
+    fn find_next(&self, caches: &[Vec<usize>], invert: usize, index: usize) -> Option<usize> {
+        let word_index = index / DYNAMIC_BIT_FIELD_WORD_BITS;
+        let word = self.get_word(word_index, invert)
+            & (core::usize::MAX << (index % DYNAMIC_BIT_FIELD_WORD_BITS));
+
+        if word != 0 {
+            return Some((word_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(word));
+        }
+
+        //
+        // Climb the summary layers until one marks a word after the current position, then
+        // descend through the lowest marked words back to the bit field.
+        //
+
+        let mut position = word_index + 1;
+        let mut layer = 0;
+        loop {
+            let cache = caches.get(layer)?;
+            let cache_index = position / DYNAMIC_BIT_FIELD_WORD_BITS;
+            let marked = cache.get(cache_index)?
+                & (core::usize::MAX << (position % DYNAMIC_BIT_FIELD_WORD_BITS));
+
+            if marked != 0 {
+                position =
+                    (cache_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(marked);
+                break;
+            }
+
+            position = cache_index + 1;
+            layer += 1;
+        }
+
+        for cache in caches[..layer].iter().rev() {
+            position =
+                (position * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(cache[position]);
+        }
+
+        let word = self.get_word(position, invert);
+        Some((position * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(word))
+    }
+
+    /// Finds the highest set bit at or before an index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from. Must be less than `capacity()`.
+    ///
+    /// # Returns
+    /// The index of the bit or `None` if there is no such bit.
+    fn find_previous(&self, index: usize) -> Option<usize> {
+        let word_index = index / DYNAMIC_BIT_FIELD_WORD_BITS;
+        let word = self.bitfield[word_index]
+            & (core::usize::MAX
+                >> (DYNAMIC_BIT_FIELD_WORD_BITS - 1 - (index % DYNAMIC_BIT_FIELD_WORD_BITS)));
+
+        if word != 0 {
+            return Some((word_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_highest_set_bit(word));
+        }
+
+        //
+        // Climb the summary layers until one marks a word before the current position, then
+        // descend through the highest marked words back to the bit field.
+        //
+
+        let mut position = word_index.checked_sub(1)?;
+        let mut layer = 0;
+        loop {
+            let cache = self.layer_caches.get(layer)?;
+            let cache_index = position / DYNAMIC_BIT_FIELD_WORD_BITS;
+            let marked = cache[cache_index]
+                & (core::usize::MAX
+                    >> (DYNAMIC_BIT_FIELD_WORD_BITS
+                        - 1
+                        - (position % DYNAMIC_BIT_FIELD_WORD_BITS)));
+
+            if marked != 0 {
+                position =
+                    (cache_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_highest_set_bit(marked);
+                break;
+            }
+
+            position = cache_index.checked_sub(1)?;
+            layer += 1;
+        }
+
+        for cache in self.layer_caches[..layer].iter().rev() {
+            position =
+                (position * DYNAMIC_BIT_FIELD_WORD_BITS) + find_highest_set_bit(cache[position]);
+        }
+
+        Some(
+            (position * DYNAMIC_BIT_FIELD_WORD_BITS)
+                + find_highest_set_bit(self.bitfield[position]),
+        )
+    }
+
+    /// Gets the top word of a stack of summary layers.
+    ///
+    /// # Arguments
+    /// caches - Provides the summary layers.
+    ///
+    /// # Returns
+    /// The top word or `0` if the bit field has no bits.
+    fn get_top(caches: &[Vec<usize>]) -> usize {
+        match caches.last() {
+            Some(top) => top[0],
+            None => 0,
+        }
+    }
+}
+
+/// Defines the FastBitField interface for DynamicBitField.
+impl FastBitField for DynamicBitField {
+    /// Creates a new, empty DynamicBitField with no bits. Use `grow` or `with_capacity` to make room
+    /// for bits.
+    ///
+    /// # Returns
+    /// A DynamicBitField.
+    fn new() -> Self {
+        DynamicBitField {
+            bitfield: Vec::new(),
+            layer_caches: Vec::new(),
+            clear_caches: Vec::new(),
+        }
+    }
+
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    fn capacity(&self) -> usize {
+        self.bitfield.len() * DYNAMIC_BIT_FIELD_WORD_BITS
+    }
+
+    /// Sets a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set. Bits past the capacity are ignored.
+    fn set_bit(&mut self, index: usize) {
+        if index < self.capacity() {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                self.set_bit_unchecked(index);
+            }
+        }
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear. Bits past the capacity are ignored.
+    fn clear_bit(&mut self, index: usize) {
+        if index < self.capacity() {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                self.clear_bit_unchecked(index);
+            }
+        }
+    }
+
+    /// Gets the lowest set bit.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
+    fn get_lowest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.get_lowest_set_bit_unchecked())
+    }
+
+    /// Gets the highest set bit.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    fn get_highest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.get_highest_set_bit_unchecked())
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn get_next_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= self.capacity() {
+            return None;
+        }
+
+        self.find_next(&self.layer_caches, 0, index)
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn get_previous_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= self.capacity() {
+            return None;
+        }
+
+        self.find_previous(index)
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    fn test_bit(&self, index: usize) -> Option<bool> {
+        if index < self.capacity() {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                return Some(self.test_bit_unchecked(index));
+            }
+        }
+
+        None
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    fn is_empty(&self) -> bool {
+        Self::get_top(&self.layer_caches) == 0
+    }
+
+    /// Determines whether or not every bit in the bitfield is set. A bitfield with no bits is
+    /// considered full.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    fn is_full(&self) -> bool {
+        Self::get_top(&self.clear_caches) == 0
+    }
+
+    /// Gets the number of set bits in the bitfield.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    fn count_set_bits(&self) -> usize {
+        self.bitfield
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Gets the number of set bits below a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    fn rank(&self, index: usize) -> usize {
+        if index >= self.capacity() {
+            return self.count_set_bits();
+        }
+
+        let word_index = index / DYNAMIC_BIT_FIELD_WORD_BITS;
+        let below = self.bitfield[word_index] & ((1 << (index % DYNAMIC_BIT_FIELD_WORD_BITS)) - 1);
+        let count: usize = self.bitfield[..word_index]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+
+        count + below.count_ones() as usize
+    }
+
+    /// Gets the set bit with a given rank.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    fn select(&self, rank: usize) -> Option<usize> {
+        let mut rank = rank;
+        for (word_index, word) in self.bitfield.iter().enumerate() {
+            let count = word.count_ones() as usize;
+            if rank < count {
+                return Some(
+                    (word_index * DYNAMIC_BIT_FIELD_WORD_BITS)
+                        + find_set_bit_with_rank(*word, rank),
+                );
+            }
+
+            rank -= count;
+        }
+
+        None
+    }
+
+    /// Gets the lowest clear bit.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    fn get_lowest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_lowest_clear_bit_unchecked())
+    }
+
+    /// Gets the highest clear bit.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    fn get_highest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_highest_clear_bit_unchecked())
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    fn get_next_clear_bit(&self, index: usize) -> Option<usize> {
+        if index >= self.capacity() {
+            return None;
+        }
+
+        self.find_next(&self.clear_caches, core::usize::MAX, index)
+    }
+
+    /// Gets the lowest set bit using one bit scan per summary layer. If no bits are set, the result
+    /// is undefined.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `UNDEFINED` if no bits are set.
+    fn get_lowest_set_bit_unchecked(&self) -> usize {
+        let word_index = Self::find_lowest_word(&self.layer_caches);
+        (word_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(self.bitfield[word_index])
+    }
+
+    /// Gets the highest set bit using one bit scan per summary layer. If no bits are set, the
+    /// result is undefined.
+    ///
+    /// # Returns
+    /// The highest set bit index or `UNDEFINED` if no bits are set.
+    fn get_highest_set_bit_unchecked(&self) -> usize {
+        let word_index = Self::find_highest_word(&self.layer_caches);
+        (word_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_highest_set_bit(self.bitfield[word_index])
+    }
+
+    /// Gets the lowest clear bit using one bit scan per summary layer. If every bit is set, the
+    /// result is undefined.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_lowest_clear_bit_unchecked(&self) -> usize {
+        let word_index = Self::find_lowest_word(&self.clear_caches);
+        (word_index * DYNAMIC_BIT_FIELD_WORD_BITS) + find_lowest_set_bit(!self.bitfield[word_index])
+    }
+
+    /// Gets the highest clear bit using one bit scan per summary layer. If every bit is set, the
+    /// result is undefined.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_highest_clear_bit_unchecked(&self) -> usize {
+        let word_index = Self::find_highest_word(&self.clear_caches);
+        (word_index * DYNAMIC_BIT_FIELD_WORD_BITS)
+            + find_highest_set_bit(!self.bitfield[word_index])
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `UNDEFINED` if no such bit is
+    /// set.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or after the index. The caller must guarantee that the index is less
+    /// than `capacity()` and that a bit at or after the index is set.
+    unsafe fn get_next_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index("get_next_set_bit_unchecked", index, self.capacity());
+
+        self.find_next(&self.layer_caches, 0, index)
+            .unwrap_or(core::usize::MAX)
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `UNDEFINED` if no such bit is set.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or before the index. The caller must guarantee that the index is
+    /// less than `capacity()` and that a bit at or before the index is set.
+    unsafe fn get_previous_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index("get_previous_set_bit_unchecked", index, self.capacity());
+
+        self.find_previous(index).unwrap_or(core::usize::MAX)
+    }
+
+    /// Sets a bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn set_bit_unchecked(&mut self, index: usize) {
+        check_unchecked_index("set_bit_unchecked", index, self.capacity());
+
+        let word_index = index / DYNAMIC_BIT_FIELD_WORD_BITS;
+        *self.bitfield.get_unchecked_mut(word_index) |= 1 << (index % DYNAMIC_BIT_FIELD_WORD_BITS);
+        self.update_caches(word_index);
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn clear_bit_unchecked(&mut self, index: usize) {
+        check_unchecked_index("clear_bit_unchecked", index, self.capacity());
+
+        let word_index = index / DYNAMIC_BIT_FIELD_WORD_BITS;
+        *self.bitfield.get_unchecked_mut(word_index) &=
+            !(1 << (index % DYNAMIC_BIT_FIELD_WORD_BITS));
+
+        self.update_caches(word_index);
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `true` if bit is set.
+    /// `false` if bit is cleared.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn test_bit_unchecked(&self, index: usize) -> bool {
+        check_unchecked_index("test_bit_unchecked", index, self.capacity());
+
+        let word = *self
+            .bitfield
+            .get_unchecked(index / DYNAMIC_BIT_FIELD_WORD_BITS);
+        (word & (1 << (index % DYNAMIC_BIT_FIELD_WORD_BITS))) != 0
+    }
+}
+
+/// Defines the default DynamicBitField.
+impl Default for DynamicBitField {
+    /// Creates a new, empty DynamicBitField with no bits.
+    ///
+    /// # Returns
+    /// A DynamicBitField.
+    fn default() -> Self {
+        DynamicBitField::new()
+    }
+}
+
+/// Defines how a DynamicBitField is displayed for debugging.
+impl fmt::Debug for DynamicBitField {
+    /// Formats the set bits of the bitfield, collapsing runs of set bits into ranges.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the bitfield.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_set_bits(self, "DynamicBitField", f)
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Holds the capacities exercised by the tests, covering one, two and three summary layers.
+    const CAPACITIES: [usize; 4] = [
+        DYNAMIC_BIT_FIELD_WORD_BITS,
+        DYNAMIC_BIT_FIELD_WORD_BITS * 3,
+        DYNAMIC_BIT_FIELD_WORD_BITS * (DYNAMIC_BIT_FIELD_WORD_BITS + 5),
+        DYNAMIC_BIT_FIELD_WORD_BITS * DYNAMIC_BIT_FIELD_WORD_BITS * 3,
+    ];
+
+    /// Builds a dynamic bitfield and the matching list of bit values from a pseudo random pattern.
+    fn make_pattern(capacity: usize, seed: usize) -> (DynamicBitField, Vec<bool>) {
+        let mut dynamic = DynamicBitField::with_capacity(capacity);
+        let mut expected = vec![false; capacity];
+        let mut state = (seed as u64).wrapping_mul(2654435761).wrapping_add(1);
+        for _ in 0..64 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            let index = (state >> 33) as usize % capacity;
+            dynamic.set_bit(index);
+            expected[index] = true;
+        }
+
+        (dynamic, expected)
+    }
+
+    /// Checks that the summary layers of a dynamic bitfield match its words.
+    fn assert_caches_consistent(dynamic: &DynamicBitField) {
+        let mut rebuilt = dynamic.clone();
+        rebuilt.rebuild_summary();
+        assert_eq!(rebuilt.layer_caches, dynamic.layer_caches);
+        assert_eq!(rebuilt.clear_caches, dynamic.clear_caches);
+    }
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let mut dynamic = DynamicBitField::new();
+        assert_eq!(dynamic.capacity(), 0);
+        assert!(dynamic.is_empty());
+        assert!(dynamic.is_full());
+        assert_eq!(dynamic.get_lowest_set_bit(), None);
+        assert_eq!(dynamic.get_lowest_clear_bit(), None);
+        assert_eq!(dynamic.get_next_set_bit(0), None);
+        assert_eq!(dynamic.select(0), None);
+        assert_eq!(dynamic.rank(10), 0);
+
+        dynamic.set_bit(0);
+        assert_eq!(dynamic.test_bit(0), None);
+        assert_eq!(dynamic, DynamicBitField::default());
+    }
+
+    #[test]
+    fn validate_grow() {
+        let mut dynamic = DynamicBitField::with_capacity(1);
+        assert_eq!(dynamic.capacity(), DYNAMIC_BIT_FIELD_WORD_BITS);
+        assert_eq!(dynamic.layer_caches.len(), 1);
+
+        dynamic.set_bit(5);
+        dynamic.grow(DYNAMIC_BIT_FIELD_WORD_BITS.pow(3) + 1);
+        assert_eq!(dynamic.layer_caches.len(), 3);
+        assert_caches_consistent(&dynamic);
+
+        //
+        // Growing keeps the existing bits and never shrinks.
+        //
+
+        let capacity = dynamic.capacity();
+        dynamic.grow(1);
+        assert_eq!(dynamic.capacity(), capacity);
+        assert_eq!(dynamic.test_bit(5), Some(true));
+        assert_eq!(dynamic.get_lowest_clear_bit(), Some(0));
+        assert_eq!(dynamic.get_highest_clear_bit(), Some(capacity - 1));
+
+        dynamic.set_bit(capacity - 1);
+        assert_eq!(dynamic.get_highest_set_bit(), Some(capacity - 1));
+        assert_eq!(dynamic.get_previous_set_bit(capacity - 2), Some(5));
+        assert_eq!(dynamic.get_next_set_bit(6), Some(capacity - 1));
+    }
+
+    #[test]
+    fn validate_against_reference() {
+        for capacity in CAPACITIES.iter() {
+            for seed in 0..4 {
+                let (dynamic, expected) = make_pattern(*capacity, seed);
+                assert_caches_consistent(&dynamic);
+
+                let set_bits: Vec<usize> =
+                    (0..*capacity).filter(|index| expected[*index]).collect();
+                let clear_bits: Vec<usize> =
+                    (0..*capacity).filter(|index| !expected[*index]).collect();
+
+                assert_eq!(dynamic.count_set_bits(), set_bits.len());
+                assert_eq!(dynamic.get_lowest_set_bit(), set_bits.first().copied());
+                assert_eq!(dynamic.get_highest_set_bit(), set_bits.last().copied());
+                assert_eq!(dynamic.get_lowest_clear_bit(), clear_bits.first().copied());
+                assert_eq!(dynamic.get_highest_clear_bit(), clear_bits.last().copied());
+
+                for (rank, index) in set_bits.iter().enumerate() {
+                    assert_eq!(dynamic.select(rank), Some(*index));
+                    assert_eq!(dynamic.rank(*index), rank);
+                }
+
+                assert_eq!(dynamic.select(set_bits.len()), None);
+
+                //
+                // Sample the searches rather than visiting every index of the larger bitfields.
+                //
+
+                for index in (0..*capacity).step_by(7) {
+                    let next = set_bits.iter().copied().find(|bit| *bit >= index);
+                    let previous = set_bits.iter().rev().copied().find(|bit| *bit <= index);
+                    let next_clear = (index..*capacity).find(|bit| !expected[*bit]);
+                    assert_eq!(dynamic.get_next_set_bit(index), next);
+                    assert_eq!(dynamic.get_previous_set_bit(index), previous);
+                    assert_eq!(dynamic.get_next_clear_bit(index), next_clear);
+                    assert_eq!(dynamic.test_bit(index), Some(expected[index]));
+                }
+
+                assert_eq!(dynamic.get_next_set_bit(*capacity), None);
+                assert_eq!(dynamic.test_bit(*capacity), None);
+            }
+        }
+    }
+
+    #[test]
+    fn validate_fill_and_clear() {
+        for capacity in CAPACITIES.iter() {
+            let mut dynamic = DynamicBitField::with_capacity(*capacity);
+            for index in 0..*capacity {
+                dynamic.set_bit(index);
+            }
+
+            assert!(dynamic.is_full());
+            assert_eq!(dynamic.get_lowest_clear_bit(), None);
+            assert_eq!(dynamic.get_next_clear_bit(0), None);
+            assert_caches_consistent(&dynamic);
+
+            //
+            // Clearing a single bit deep in the bitfield should be found from the top layer.
+            //
+
+            let index = (*capacity / 3) + 1;
+            dynamic.clear_bit(index);
+            assert!(!dynamic.is_full());
+            assert_eq!(dynamic.get_lowest_clear_bit(), Some(index));
+            assert_eq!(dynamic.get_highest_clear_bit(), Some(index));
+            assert_eq!(dynamic.get_next_clear_bit(0), Some(index));
+            assert_eq!(dynamic.find_clear_run(1, 1), Some(index));
+            assert_caches_consistent(&dynamic);
+
+            for index in 0..*capacity {
+                dynamic.clear_bit(index);
+            }
+
+            assert!(dynamic.is_empty());
+            assert_eq!(dynamic.get_highest_set_bit(), None);
+            assert_eq!(dynamic.get_previous_set_bit(*capacity - 1), None);
+            assert_caches_consistent(&dynamic);
+        }
+    }
+
+    #[test]
+    fn validate_debug() {
+        let mut dynamic = DynamicBitField::with_capacity(100);
+        dynamic.set_bit(1);
+        dynamic.set_bit(2);
+        dynamic.set_bit(90);
+        assert_eq!(format!("{:?}", dynamic), "DynamicBitField {1..=2, 90}");
+    }
+}
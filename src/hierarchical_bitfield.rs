@@ -0,0 +1,1400 @@
+use crate::{
+    check_unchecked_index, find_highest_set_bit, find_lowest_set_bit, format_set_bits,
+    search_clear_run, FastBitField, FixedBitField,
+};
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ptr::addr_of_mut;
+
+#[cfg(feature = "alloc")]
+use alloc::alloc::{alloc, handle_alloc_error, Layout};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// Defines the number of sub bitfields summarized by each level of a hierarchical bitfield.
+const HIERARCHICAL_BIT_FIELD_GROUP_COUNT: usize = core::mem::size_of::<usize>() * 8;
+
+/// Defines the structure and fast_bitfield interface for Hierarchical Bitfields.
+/// A Hierarchical Bitfield is a structure that holds an array of `sizeof(usize) * 8` sub bitfields
+/// of type `T` as well as a "layer_cache" `usize` field describing which sub bitfields have any
+/// bits set, exactly like the "layer_cache" of a LargeBitField. A "full_cache" `usize` field
+/// likewise describes which sub bitfields have every bit set.
+///
+/// Since the sub bitfields may themselves be hierarchical bitfields, each level of nesting adds a
+/// summary level and multiplies the number of bits available by `sizeof(usize) * 8`, while lowest
+/// and highest set bit lookups remain a single bit scan per level:
+/// - `HierarchicalBitField<SmallBitField>` holds `(sizeof(usize) * 8)^2` bits.
+/// - `HierarchicalBitField<LargeBitField>` holds `(sizeof(usize) * 8)^3` bits.
+/// - `HierarchicalBitField<HierarchicalBitField<LargeBitField>>` holds `(sizeof(usize) * 8)^4`
+///   bits.
+///
+/// # Size
+/// Each level multiplies the size of the structure as well as the number of bits. On 64 bit
+/// targets `HierarchicalBitField<LargeBitField>` takes roughly 33 KB and
+/// `HierarchicalBitField<HierarchicalBitField<LargeBitField>>` roughly 2.1 MB, which is more than
+/// a typical thread stack. `new()`, `default()` and `clone()` build the value on the stack, so
+/// deeply nested bitfields should be created with `init_in_place()` or `new_boxed()` instead.
+/// For the same reason the structure is `Clone` but not `Copy`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HierarchicalBitField<T: FixedBitField> {
+    /// Holds a bitfield describing which sub bitfields currently have any set bits.
+    layer_cache: usize,
+
+    /// Holds a bitfield describing which sub bitfields currently have every bit set.
+    full_cache: usize,
+
+    /// Holds the sub bitfields.
+    bitfield: [T; HIERARCHICAL_BIT_FIELD_GROUP_COUNT],
+}
+
+/// Defines functionality unique to HierarchicalBitField.
+impl<T: FixedBitField> HierarchicalBitField<T> {
+    /// Gets the number of bits available in the bitfield type. This is the same as
+    /// `FixedBitField::get_number_of_bits()` but does not require the trait to be in scope.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let bits_of = core::mem::size_of::<usize>() * 8;
+    /// assert_eq!(
+    ///     HierarchicalBitField::<LargeBitField>::get_number_of_bits(),
+    ///     bits_of * bits_of * bits_of
+    /// );
+    /// ```
+    pub const fn get_number_of_bits() -> usize {
+        Self::BITS
+    }
+
+    /// Creates a new, empty HierarchicalBitField directly on the heap. Every level is initialized
+    /// in place, so no stack copy of the bitfield is ever made.
+    ///
+    /// # Returns
+    /// A boxed HierarchicalBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// type FourLevelBitField = HierarchicalBitField<HierarchicalBitField<LargeBitField>>;
+    ///
+    /// let mut hierarchical = FourLevelBitField::new_boxed();
+    /// let last = FourLevelBitField::get_number_of_bits() - 1;
+    ///
+    /// hierarchical.set_bit(last);
+    /// assert_eq!(hierarchical.get_lowest_set_bit(), Some(last));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn new_boxed() -> Box<Self> {
+        let layout = Layout::new::<Self>();
+
+        //
+        // UNSAFE: Self is never zero sized since it holds its layer cache, so the layout is valid
+        // for alloc. A failed allocation is reported before the memory is used, and the memory is
+        // fully initialized by init_in_place before it is handed to a Box, which frees it with
+        // the same layout.
+        //
+
+        unsafe {
+            let slot = alloc(layout) as *mut MaybeUninit<Self>;
+            if slot.is_null() {
+                handle_alloc_error(layout);
+            }
+
+            Self::init_in_place(&mut *slot);
+            Box::from_raw(slot as *mut Self)
+        }
+    }
+
+    /// Gets whether or not a specific group in the bit field has any bits set.
+    ///
+    /// # Arguments
+    /// group_index - Provides the group to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if the group has any bits set.
+    /// `Some(false)` if the group as no bits set.
+    /// `None` if group_index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let mut hierarchical = HierarchicalBitField::<LargeBitField>::new();
+    ///
+    /// assert_eq!(hierarchical.test_group(core::usize::MAX), None);
+    /// assert_eq!(hierarchical.test_group(1), Some(false));
+    ///
+    /// hierarchical.set_bit(LargeBitField::get_number_of_bits() + 2);
+    /// assert_eq!(hierarchical.test_group(1), Some(true));
+    /// ```
+    pub fn test_group(&self, group_index: usize) -> Option<bool> {
+        if group_index < HIERARCHICAL_BIT_FIELD_GROUP_COUNT {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                return Some(self.test_group_unchecked(group_index));
+            }
+        }
+
+        None
+    }
+
+    /// Gets whether or not a specific group in the bit field has any bits set.
+    ///
+    /// # Arguments
+    /// group_index - Provides the group to test.
+    ///
+    /// # Returns
+    /// `true` if the group has any bits set.
+    /// `false` if the group as no bits set.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the group_index is valid for the size of
+    /// the bit field. The caller must guarantee that group_index is within the number of
+    /// groups in the bit field.
+    pub unsafe fn test_group_unchecked(&self, group_index: usize) -> bool {
+        check_unchecked_index(
+            "test_group_unchecked",
+            group_index,
+            HIERARCHICAL_BIT_FIELD_GROUP_COUNT,
+        );
+
+        (self.layer_cache & (1 << group_index)) != 0
+    }
+
+    /// Finds the lowest run of consecutive clear bits.
+    ///
+    /// # Arguments
+    /// len - Provides the number of consecutive clear bits required.
+    /// align - Provides the alignment the first bit of the run must have.
+    ///
+    /// # Returns
+    /// The index of the first bit of the lowest run of len clear bits starting at a multiple of
+    /// align or `None` if no such run exists. A len or align of zero results in `None`.
+    ///
+    /// # Note
+    /// Full sub bitfields are skipped at every level using the full caches and runs may span any
+    /// number of sub bitfields.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let group_size = LargeBitField::get_number_of_bits();
+    /// let mut hierarchical = HierarchicalBitField::<LargeBitField>::new();
+    /// hierarchical.set_bit(group_size - 2);
+    ///
+    /// assert_eq!(hierarchical.find_clear_run(group_size, 1), Some(group_size - 1));
+    /// assert_eq!(hierarchical.find_clear_run(group_size, 2), Some(group_size));
+    /// ```
+    pub fn find_clear_run(&self, len: usize, align: usize) -> Option<usize> {
+        search_clear_run(self, len, align)
+    }
+}
+
+/// Defines the FixedBitField interface for HierarchicalBitField.
+impl<T: FixedBitField> FixedBitField for HierarchicalBitField<T> {
+    /// Defines the number of bits in a HierarchicalBitField.
+    const BITS: usize = HIERARCHICAL_BIT_FIELD_GROUP_COUNT * T::BITS;
+}
+
+/// Defines the FastBitField interface for HierarchicalBitField.
+impl<T: FixedBitField> FastBitField for HierarchicalBitField<T> {
+    /// Creates a new, empty HierarchicalBitField
+    ///
+    /// # Returns
+    /// A HierarchicalBitField.
+    ///
+    /// # Note
+    /// The bitfield is built on the stack. Use `init_in_place()` or `new_boxed()` for nested
+    /// bitfields too large for the stack.
+    fn new() -> Self {
+        HierarchicalBitField {
+            layer_cache: 0,
+            full_cache: 0,
+            bitfield: core::array::from_fn(|_| T::new()),
+        }
+    }
+
+    /// Initializes a new, empty HierarchicalBitField in caller provided memory. The caches are
+    /// written directly and each sub bitfield is initialized in place in turn, so at most a single
+    /// bottom level bitfield is ever built on the stack.
+    ///
+    /// # Arguments
+    /// slot - Provides the memory to initialize.
+    ///
+    /// # Returns
+    /// A reference to the initialized HierarchicalBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let mut slot = Box::new(MaybeUninit::<HierarchicalBitField<LargeBitField>>::uninit());
+    /// let hierarchical = HierarchicalBitField::init_in_place(&mut slot);
+    ///
+    /// assert!(hierarchical.is_empty());
+    /// ```
+    fn init_in_place(slot: &mut MaybeUninit<Self>) -> &mut Self {
+        let this = slot.as_mut_ptr();
+
+        //
+        // UNSAFE: this points to memory valid for writes of Self. Each field is written through a
+        // raw pointer without creating a reference to uninitialized memory, and an array of
+        // MaybeUninit<T> has the same layout as an array of T. Every field is initialized before
+        // the slot is assumed to be initialized.
+        //
+
+        unsafe {
+            addr_of_mut!((*this).layer_cache).write(0);
+            addr_of_mut!((*this).full_cache).write(0);
+
+            let sub_fields = addr_of_mut!((*this).bitfield) as *mut MaybeUninit<T>;
+            for index in 0..HIERARCHICAL_BIT_FIELD_GROUP_COUNT {
+                T::init_in_place(&mut *sub_fields.add(index));
+            }
+
+            slot.assume_init_mut()
+        }
+    }
+
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available, which is always `get_number_of_bits()`.
+    fn capacity(&self) -> usize {
+        Self::get_number_of_bits()
+    }
+
+    /// Sets a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    fn set_bit(&mut self, index: usize) {
+        if index < Self::get_number_of_bits() {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                self.set_bit_unchecked(index);
+            }
+        }
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    fn clear_bit(&mut self, index: usize) {
+        if index < Self::get_number_of_bits() {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                self.clear_bit_unchecked(index);
+            }
+        }
+    }
+
+    /// Gets the lowest set bit.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let mut hierarchical = HierarchicalBitField::<LargeBitField>::new();
+    /// let large_bits = LargeBitField::get_number_of_bits();
+    ///
+    /// assert_eq!(hierarchical.get_lowest_set_bit(), None);
+    ///
+    /// hierarchical.set_bit(large_bits + 7);
+    /// assert_eq!(hierarchical.get_lowest_set_bit(), Some(large_bits + 7));
+    ///
+    /// hierarchical.set_bit(large_bits * 2);
+    /// assert_eq!(hierarchical.get_lowest_set_bit(), Some(large_bits + 7));
+    /// ```
+    fn get_lowest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.get_lowest_set_bit_unchecked())
+    }
+
+    /// Gets the highest set bit.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let mut hierarchical = HierarchicalBitField::<LargeBitField>::new();
+    /// let large_bits = LargeBitField::get_number_of_bits();
+    ///
+    /// assert_eq!(hierarchical.get_highest_set_bit(), None);
+    ///
+    /// hierarchical.set_bit(large_bits + 7);
+    /// assert_eq!(hierarchical.get_highest_set_bit(), Some(large_bits + 7));
+    ///
+    /// hierarchical.set_bit(large_bits * 2);
+    /// assert_eq!(hierarchical.get_highest_set_bit(), Some(large_bits * 2));
+    /// ```
+    fn get_highest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.get_highest_set_bit_unchecked())
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let mut hierarchical = HierarchicalBitField::<LargeBitField>::new();
+    /// let large_bits = LargeBitField::get_number_of_bits();
+    ///
+    /// hierarchical.set_bit(4);
+    /// hierarchical.set_bit(large_bits * 3);
+    ///
+    /// assert_eq!(hierarchical.get_next_set_bit(4), Some(4));
+    /// assert_eq!(hierarchical.get_next_set_bit(5), Some(large_bits * 3));
+    /// assert_eq!(hierarchical.get_next_set_bit(large_bits * 3 + 1), None);
+    /// ```
+    fn get_next_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= Self::get_number_of_bits() {
+            return None;
+        }
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        //
+        // Check the remainder of the group containing index first, then use the layer cache to
+        // jump to the next group with any bits set.
+        //
+
+        if let Some(bit) = self.bitfield[top_layer].get_next_set_bit(bottom_layer) {
+            return Some((top_layer * T::get_number_of_bits()) + bit);
+        }
+
+        let groups = self.layer_cache & ((core::usize::MAX << top_layer) << 1);
+        if groups == 0 {
+            return None;
+        }
+
+        let level = find_lowest_set_bit(groups);
+        let sub_field = &self.bitfield[level];
+        Some((level * T::get_number_of_bits()) + sub_field.get_lowest_set_bit_unchecked())
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn get_previous_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= Self::get_number_of_bits() {
+            return None;
+        }
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        //
+        // Check the beginning of the group containing index first, then use the layer cache to
+        // jump to the previous group with any bits set.
+        //
+
+        if let Some(bit) = self.bitfield[top_layer].get_previous_set_bit(bottom_layer) {
+            return Some((top_layer * T::get_number_of_bits()) + bit);
+        }
+
+        let groups = self.layer_cache
+            & ((core::usize::MAX >> (HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1 - top_layer)) >> 1);
+
+        if groups == 0 {
+            return None;
+        }
+
+        let level = find_highest_set_bit(groups);
+        let sub_field = &self.bitfield[level];
+        Some((level * T::get_number_of_bits()) + sub_field.get_highest_set_bit_unchecked())
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    fn test_bit(&self, index: usize) -> Option<bool> {
+        if index < Self::get_number_of_bits() {
+            //
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
+            //
+
+            unsafe {
+                return Some(self.test_bit_unchecked(index));
+            }
+        }
+
+        None
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    fn is_empty(&self) -> bool {
+        self.layer_cache == 0
+    }
+
+    /// Determines whether or not every bit in the bitfield is set.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    fn is_full(&self) -> bool {
+        self.full_cache == core::usize::MAX
+    }
+
+    /// Gets the number of set bits in the bitfield. Only groups with bits set are visited.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    fn count_set_bits(&self) -> usize {
+        let mut count = 0;
+        let mut groups = self.layer_cache;
+        while groups != 0 {
+            let index = find_lowest_set_bit(groups);
+            groups &= groups - 1;
+            count += self.bitfield[index].count_set_bits();
+        }
+
+        count
+    }
+
+    /// Gets the number of set bits below a given index. Only groups with bits set are visited.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    fn rank(&self, index: usize) -> usize {
+        if index >= Self::get_number_of_bits() {
+            return self.count_set_bits();
+        }
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        let mut count = self.bitfield[top_layer].rank(bottom_layer);
+        let mut groups = self.layer_cache & ((1 << top_layer) - 1);
+        while groups != 0 {
+            let index = find_lowest_set_bit(groups);
+            groups &= groups - 1;
+            count += self.bitfield[index].count_set_bits();
+        }
+
+        count
+    }
+
+    /// Gets the set bit with a given rank. Only groups with bits set are visited.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, HierarchicalBitField, LargeBitField};
+    ///
+    /// let mut hierarchical = HierarchicalBitField::<LargeBitField>::new();
+    /// let large_bits = LargeBitField::get_number_of_bits();
+    ///
+    /// hierarchical.set_bit(7);
+    /// hierarchical.set_bit(large_bits * 2);
+    ///
+    /// assert_eq!(hierarchical.count_set_bits(), 2);
+    /// assert_eq!(hierarchical.rank(large_bits * 2), 1);
+    /// assert_eq!(hierarchical.select(1), Some(large_bits * 2));
+    /// ```
+    fn select(&self, rank: usize) -> Option<usize> {
+        let mut rank = rank;
+        let mut groups = self.layer_cache;
+        while groups != 0 {
+            let index = find_lowest_set_bit(groups);
+            groups &= groups - 1;
+
+            let sub_field = &self.bitfield[index];
+            let count = sub_field.count_set_bits();
+            if rank < count {
+                let bit = sub_field.select(rank)?;
+                return Some((index * T::get_number_of_bits()) + bit);
+            }
+
+            rank -= count;
+        }
+
+        None
+    }
+
+    /// Gets the lowest clear bit.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    fn get_lowest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_lowest_clear_bit_unchecked())
+    }
+
+    /// Gets the highest clear bit.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    fn get_highest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_highest_clear_bit_unchecked())
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    fn get_next_clear_bit(&self, index: usize) -> Option<usize> {
+        if index >= Self::get_number_of_bits() {
+            return None;
+        }
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        //
+        // Check the remainder of the group containing index first, then use the full cache to
+        // jump to the next group with any bits clear.
+        //
+
+        if let Some(bit) = self.bitfield[top_layer].get_next_clear_bit(bottom_layer) {
+            return Some((top_layer * T::get_number_of_bits()) + bit);
+        }
+
+        let groups = !self.full_cache & ((core::usize::MAX << top_layer) << 1);
+        if groups == 0 {
+            return None;
+        }
+
+        let level = find_lowest_set_bit(groups);
+        let sub_field = &self.bitfield[level];
+        Some((level * T::get_number_of_bits()) + sub_field.get_lowest_clear_bit_unchecked())
+    }
+
+    /// Gets the lowest set bit, guaranteed to have no branches and be in constant time, completely
+    /// invariant of the state of the bit field. If no bits are set, the result is undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit set.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `UNDEFINED` if no bits are set.
+    fn get_lowest_set_bit_unchecked(&self) -> usize {
+        let level = find_lowest_set_bit(self.layer_cache);
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1
+        // by the definition of find_lowest_set_bit. No need to perform bounds checking on the
+        // array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.get_unchecked(level);
+            (level * T::get_number_of_bits()) + sub_field.get_lowest_set_bit_unchecked()
+        }
+    }
+
+    /// Gets the highest set bit, guaranteed to have no branches and be in constant time, completely
+    /// invariant of the state of the bit field. If no bits are set, the result is undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit set.
+    ///
+    /// # Returns
+    /// The highest set bit index or `UNDEFINED` if no bits are set.
+    fn get_highest_set_bit_unchecked(&self) -> usize {
+        let level = find_highest_set_bit(self.layer_cache);
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1
+        // by the definition of find_highest_set_bit. No need to perform bounds checking on the
+        // array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.get_unchecked(level);
+            (level * T::get_number_of_bits()) + sub_field.get_highest_set_bit_unchecked()
+        }
+    }
+
+    /// Gets the lowest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_lowest_clear_bit_unchecked(&self) -> usize {
+        //
+        // Setting the highest group in the scan leaves the result unchanged while any group has a
+        // clear bit, and keeps level in range when every group is full.
+        //
+
+        let level =
+            find_lowest_set_bit(!self.full_cache | (1 << (HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1)));
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1
+        // since the scanned value always has a bit set. No need to perform bounds checking on the
+        // array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.get_unchecked(level);
+            (level * T::get_number_of_bits()) + sub_field.get_lowest_clear_bit_unchecked()
+        }
+    }
+
+    /// Gets the highest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_highest_clear_bit_unchecked(&self) -> usize {
+        //
+        // Setting the lowest group in the scan leaves the result unchanged while any group has a
+        // clear bit, and keeps level in range when every group is full.
+        //
+
+        let level = find_highest_set_bit(!self.full_cache | 1);
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1
+        // since the scanned value always has a bit set. No need to perform bounds checking on the
+        // array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.get_unchecked(level);
+            (level * T::get_number_of_bits()) + sub_field.get_highest_clear_bit_unchecked()
+        }
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `UNDEFINED` if no such bit is
+    /// set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or after the index. The caller must guarantee that the index is less
+    /// than `capacity()` and that a bit at or after the index is set.
+    unsafe fn get_next_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index(
+            "get_next_set_bit_unchecked",
+            index,
+            Self::get_number_of_bits(),
+        );
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        let sub_field = self.bitfield.get_unchecked(top_layer);
+        if let Some(bit) = sub_field.get_next_set_bit(bottom_layer) {
+            return (top_layer * T::get_number_of_bits()) + bit;
+        }
+
+        let groups = self.layer_cache & ((core::usize::MAX << top_layer) << 1);
+        let level = find_lowest_set_bit(groups);
+        let sub_field = self.bitfield.get_unchecked(level);
+        (level * T::get_number_of_bits()) + sub_field.get_lowest_set_bit_unchecked()
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `UNDEFINED` if no such bit is set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or before the index. The caller must guarantee that the index is
+    /// less than `capacity()` and that a bit at or before the index is set.
+    unsafe fn get_previous_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index(
+            "get_previous_set_bit_unchecked",
+            index,
+            Self::get_number_of_bits(),
+        );
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        let sub_field = self.bitfield.get_unchecked(top_layer);
+        if let Some(bit) = sub_field.get_previous_set_bit(bottom_layer) {
+            return (top_layer * T::get_number_of_bits()) + bit;
+        }
+
+        let groups = self.layer_cache
+            & ((core::usize::MAX >> (HIERARCHICAL_BIT_FIELD_GROUP_COUNT - 1 - top_layer)) >> 1);
+
+        let level = find_highest_set_bit(groups);
+        let sub_field = self.bitfield.get_unchecked(level);
+        (level * T::get_number_of_bits()) + sub_field.get_highest_set_bit_unchecked()
+    }
+
+    /// Sets a bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn set_bit_unchecked(&mut self, index: usize) {
+        check_unchecked_index("set_bit_unchecked", index, Self::get_number_of_bits());
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        self.layer_cache |= 1 << top_layer;
+        let sub_field = self.bitfield.get_unchecked_mut(top_layer);
+        sub_field.set_bit_unchecked(bottom_layer);
+
+        //
+        // Turn boolean into a usize to avoid branching.
+        //
+
+        let is_full = sub_field.is_full() as usize;
+        let full_cache_update = (1 << top_layer) * is_full;
+        self.full_cache |= full_cache_update;
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn clear_bit_unchecked(&mut self, index: usize) {
+        check_unchecked_index("clear_bit_unchecked", index, Self::get_number_of_bits());
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        let sub_field = self.bitfield.get_unchecked_mut(top_layer);
+        sub_field.clear_bit_unchecked(bottom_layer);
+        self.full_cache &= !(1 << top_layer);
+
+        //
+        // Turn boolean into a usize to avoid branching.
+        //
+
+        let is_clear = sub_field.is_empty() as usize;
+        let layer_cache_update = (1 << top_layer) * is_clear;
+        self.layer_cache &= !layer_cache_update
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `true` if bit is set.
+    /// `false` if bit is cleared.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn test_bit_unchecked(&self, index: usize) -> bool {
+        check_unchecked_index("test_bit_unchecked", index, Self::get_number_of_bits());
+
+        let top_layer = index / T::get_number_of_bits();
+        let bottom_layer = index % T::get_number_of_bits();
+
+        let sub_field = self.bitfield.get_unchecked(top_layer);
+        sub_field.test_bit_unchecked(bottom_layer)
+    }
+}
+
+/// Defines the default HierarchicalBitField.
+impl<T: FixedBitField> Default for HierarchicalBitField<T> {
+    /// Creates a new, empty HierarchicalBitField
+    ///
+    /// # Returns
+    /// A HierarchicalBitField.
+    fn default() -> Self {
+        HierarchicalBitField::new()
+    }
+}
+
+/// Defines how a HierarchicalBitField is displayed for debugging.
+impl<T: FixedBitField> fmt::Debug for HierarchicalBitField<T> {
+    /// Formats the set bits of the bitfield, collapsing runs of set bits into ranges.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the bitfield.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_set_bits(self, "HierarchicalBitField", f)
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LargeBitField, SmallBitField};
+
+    type TwoLevelBitField = HierarchicalBitField<SmallBitField>;
+    type ThreeLevelBitField = HierarchicalBitField<LargeBitField>;
+    type FourLevelBitField = HierarchicalBitField<ThreeLevelBitField>;
+
+    /// Exercises the ends and the sub bitfield boundaries of a hierarchical bitfield.
+    fn validate_large_configuration<T: FixedBitField>(hierarchical: &mut HierarchicalBitField<T>) {
+        let bits = HierarchicalBitField::<T>::get_number_of_bits();
+        let group_size = T::get_number_of_bits();
+
+        assert!(hierarchical.is_empty());
+        assert_eq!(hierarchical.get_lowest_clear_bit(), Some(0));
+        assert_eq!(hierarchical.get_highest_clear_bit(), Some(bits - 1));
+
+        hierarchical.set_bit(bits - 1);
+        assert_eq!(hierarchical.get_lowest_set_bit(), Some(bits - 1));
+        assert_eq!(hierarchical.get_highest_set_bit(), Some(bits - 1));
+
+        hierarchical.set_bit(group_size);
+        hierarchical.set_bit(group_size - 1);
+        assert_eq!(hierarchical.get_lowest_set_bit(), Some(group_size - 1));
+        assert_eq!(hierarchical.get_highest_set_bit(), Some(bits - 1));
+        assert_eq!(hierarchical.test_bit(group_size), Some(true));
+
+        hierarchical.clear_bit(group_size - 1);
+        hierarchical.clear_bit(group_size);
+        hierarchical.clear_bit(bits - 1);
+        assert!(hierarchical.is_empty());
+        assert_eq!(hierarchical.layer_cache, 0);
+    }
+
+    //
+    // Constructor Test
+    //
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let hierarchical = ThreeLevelBitField::new();
+
+        assert_eq!(hierarchical.layer_cache, 0);
+        assert_eq!(hierarchical.full_cache, 0);
+        for index in 0..HIERARCHICAL_BIT_FIELD_GROUP_COUNT {
+            assert!(hierarchical.bitfield[index].is_empty());
+        }
+
+        assert!(hierarchical.is_empty());
+    }
+
+    #[test]
+    fn create_in_place() {
+        let mut three_level = Box::new(MaybeUninit::<ThreeLevelBitField>::uninit());
+        validate_large_configuration(ThreeLevelBitField::init_in_place(&mut three_level));
+
+        //
+        // The four level bitfield is too large for a test thread stack in debug builds, so it is
+        // only ever initialized in place on the heap.
+        //
+
+        let mut four_level = Vec::<FourLevelBitField>::with_capacity(1);
+        let hierarchical =
+            FourLevelBitField::init_in_place(&mut four_level.spare_capacity_mut()[0]);
+        assert_eq!(
+            FourLevelBitField::get_number_of_bits(),
+            ThreeLevelBitField::get_number_of_bits() * HIERARCHICAL_BIT_FIELD_GROUP_COUNT
+        );
+
+        for sub_field in hierarchical.bitfield.iter() {
+            assert!(sub_field.is_empty());
+        }
+
+        validate_large_configuration(hierarchical);
+    }
+
+    #[test]
+    fn create_three_level_on_stack() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        validate_large_configuration(&mut hierarchical);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn create_boxed() {
+        let mut hierarchical = FourLevelBitField::new_boxed();
+        validate_large_configuration(&mut *hierarchical);
+    }
+
+    //
+    // Trait Tests
+    //
+
+    #[test]
+    fn number_of_bits() {
+        assert_eq!(
+            TwoLevelBitField::get_number_of_bits(),
+            LargeBitField::get_number_of_bits()
+        );
+
+        assert_eq!(
+            ThreeLevelBitField::get_number_of_bits(),
+            LargeBitField::get_number_of_bits() * HIERARCHICAL_BIT_FIELD_GROUP_COUNT
+        );
+
+        assert_eq!(
+            ThreeLevelBitField::BITS,
+            ThreeLevelBitField::get_number_of_bits()
+        );
+        assert_eq!(
+            ThreeLevelBitField::new().capacity(),
+            ThreeLevelBitField::get_number_of_bits()
+        );
+    }
+
+    #[test]
+    fn validate_set_and_clear_bit() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        let mut hierarchical_unsafe = ThreeLevelBitField::new();
+        let bits = ThreeLevelBitField::get_number_of_bits();
+        let group_bits = LargeBitField::get_number_of_bits();
+
+        //
+        // Out of bounds set and clear should do nothing.
+        //
+
+        hierarchical.set_bit(bits);
+        assert!(hierarchical.is_empty());
+        hierarchical.clear_bit(bits);
+        assert!(hierarchical.is_empty());
+
+        for i in (0..bits).step_by(group_bits / 2 + 1) {
+            let group = i / group_bits;
+
+            hierarchical.set_bit(i);
+            assert_eq!(hierarchical.test_bit(i), Some(true));
+            assert_eq!(hierarchical.test_group(group), Some(true));
+
+            unsafe {
+                hierarchical_unsafe.set_bit_unchecked(i);
+                assert!(hierarchical_unsafe.test_bit_unchecked(i));
+                assert!(hierarchical_unsafe.test_group_unchecked(group));
+            }
+
+            assert_eq!(hierarchical.layer_cache, hierarchical_unsafe.layer_cache);
+        }
+
+        for i in (0..bits).step_by(group_bits / 2 + 1) {
+            hierarchical.clear_bit(i);
+            assert_eq!(hierarchical.test_bit(i), Some(false));
+
+            unsafe {
+                hierarchical_unsafe.clear_bit_unchecked(i);
+                assert!(!hierarchical_unsafe.test_bit_unchecked(i));
+            }
+
+            assert_eq!(hierarchical.layer_cache, hierarchical_unsafe.layer_cache);
+        }
+
+        //
+        // Clearing every set bit should clear every group.
+        //
+
+        assert_eq!(hierarchical.layer_cache, 0);
+        assert!(hierarchical.is_empty());
+        assert!(hierarchical_unsafe.is_empty());
+    }
+
+    #[test]
+    fn validate_layer_cache_tracks_groups() {
+        let mut hierarchical = TwoLevelBitField::new();
+        let group_bits = SmallBitField::get_number_of_bits();
+        let first = 3;
+        let second = 5;
+
+        hierarchical.set_bit(group_bits + first);
+        hierarchical.set_bit(group_bits + second);
+        assert_eq!(hierarchical.layer_cache, 1 << 1);
+
+        //
+        // The group stays marked until its last bit is cleared.
+        //
+
+        hierarchical.clear_bit(group_bits + first);
+        assert_eq!(hierarchical.layer_cache, 1 << 1);
+
+        hierarchical.clear_bit(group_bits + second);
+        assert_eq!(hierarchical.layer_cache, 0);
+    }
+
+    #[test]
+    fn validate_get_lowest_set_bit() {
+        let mut hierarchical = TwoLevelBitField::new();
+        let bits = TwoLevelBitField::get_number_of_bits();
+
+        //
+        // Empty should result in None for checked variant
+        //
+
+        assert_eq!(hierarchical.get_lowest_set_bit(), None);
+
+        for i in 0..bits {
+            hierarchical.set_bit(i);
+            assert_eq!(hierarchical.get_lowest_set_bit(), Some(0));
+            assert_eq!(hierarchical.get_lowest_set_bit_unchecked(), 0);
+        }
+
+        for i in 0..bits {
+            assert_eq!(hierarchical.get_lowest_set_bit(), Some(i));
+            assert_eq!(hierarchical.get_lowest_set_bit_unchecked(), i);
+            hierarchical.clear_bit(i);
+        }
+    }
+
+    #[test]
+    fn validate_get_highest_set_bit() {
+        let mut hierarchical = TwoLevelBitField::new();
+        let bits = TwoLevelBitField::get_number_of_bits();
+
+        //
+        // Empty should result in None for checked variant
+        //
+
+        assert_eq!(hierarchical.get_highest_set_bit(), None);
+
+        for i in 0..bits {
+            hierarchical.set_bit(i);
+            assert_eq!(hierarchical.get_highest_set_bit(), Some(i));
+            assert_eq!(hierarchical.get_highest_set_bit_unchecked(), i);
+        }
+
+        for i in 0..bits {
+            assert_eq!(hierarchical.get_highest_set_bit(), Some(bits - 1));
+            assert_eq!(hierarchical.get_highest_set_bit_unchecked(), bits - 1);
+            hierarchical.clear_bit(i);
+        }
+    }
+
+    #[test]
+    fn validate_three_level_lowest_and_highest() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        let bits = ThreeLevelBitField::get_number_of_bits();
+        let low = LargeBitField::get_number_of_bits() + 9;
+        let high = bits - LargeBitField::get_number_of_bits() - 9;
+
+        hierarchical.set_bit(high);
+        hierarchical.set_bit(low);
+        assert_eq!(hierarchical.get_lowest_set_bit(), Some(low));
+        assert_eq!(hierarchical.get_highest_set_bit(), Some(high));
+
+        hierarchical.clear_bit(low);
+        assert_eq!(hierarchical.get_lowest_set_bit(), Some(high));
+
+        hierarchical.clear_bit(high);
+        assert_eq!(hierarchical.get_lowest_set_bit(), None);
+        assert_eq!(hierarchical.get_highest_set_bit(), None);
+    }
+
+    #[test]
+    fn validate_test_bit() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        let bits = ThreeLevelBitField::get_number_of_bits();
+
+        //
+        // Out of bounds should return None for checked variant
+        //
+
+        assert_eq!(hierarchical.test_bit(bits), None);
+
+        //
+        // Changing a bit in another group has no affect on the bit being tested.
+        //
+
+        hierarchical.set_bit(0);
+        hierarchical.set_bit(bits - 1);
+        hierarchical.clear_bit(bits - 1);
+        assert_eq!(hierarchical.test_bit(0), Some(true));
+        assert_eq!(hierarchical.test_bit(bits - 1), Some(false));
+    }
+
+    #[test]
+    fn validate_get_next_and_previous_set_bit() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        let bits = ThreeLevelBitField::get_number_of_bits();
+        let group_bits = LargeBitField::get_number_of_bits();
+        let low = group_bits + 9;
+        let high = bits - group_bits - 9;
+
+        //
+        // Out of bounds and empty should result in None for checked variants
+        //
+
+        assert_eq!(hierarchical.get_next_set_bit(bits), None);
+        assert_eq!(hierarchical.get_previous_set_bit(bits), None);
+        assert_eq!(hierarchical.get_next_set_bit(0), None);
+        assert_eq!(hierarchical.get_previous_set_bit(bits - 1), None);
+
+        hierarchical.set_bit(low);
+        hierarchical.set_bit(high);
+
+        for i in [0, low - 1, low].iter() {
+            assert_eq!(hierarchical.get_next_set_bit(*i), Some(low));
+            unsafe {
+                assert_eq!(hierarchical.get_next_set_bit_unchecked(*i), low);
+            }
+        }
+
+        for i in [low + 1, group_bits * 2, high].iter() {
+            assert_eq!(hierarchical.get_next_set_bit(*i), Some(high));
+            assert_eq!(hierarchical.get_previous_set_bit(*i - 1), Some(low));
+            unsafe {
+                assert_eq!(hierarchical.get_next_set_bit_unchecked(*i), high);
+                assert_eq!(hierarchical.get_previous_set_bit_unchecked(*i - 1), low);
+            }
+        }
+
+        for i in [high, high + 1, bits - 1].iter() {
+            assert_eq!(hierarchical.get_previous_set_bit(*i), Some(high));
+            unsafe {
+                assert_eq!(hierarchical.get_previous_set_bit_unchecked(*i), high);
+            }
+        }
+
+        assert_eq!(hierarchical.get_next_set_bit(high + 1), None);
+        assert_eq!(hierarchical.get_previous_set_bit(low - 1), None);
+    }
+
+    #[test]
+    fn validate_get_lowest_and_highest_clear_bit() {
+        let mut hierarchical = TwoLevelBitField::new();
+        let bits = TwoLevelBitField::get_number_of_bits();
+
+        for i in 0..bits {
+            assert_eq!(hierarchical.get_lowest_clear_bit(), Some(i));
+            assert_eq!(hierarchical.get_lowest_clear_bit_unchecked(), i);
+            assert_eq!(hierarchical.get_highest_clear_bit(), Some(bits - 1));
+            hierarchical.set_bit(i);
+        }
+
+        //
+        // Full should result in None for checked variants
+        //
+
+        assert_eq!(hierarchical.full_cache, core::usize::MAX);
+        assert!(hierarchical.is_full());
+        assert_eq!(hierarchical.get_lowest_clear_bit(), None);
+        assert_eq!(hierarchical.get_highest_clear_bit(), None);
+
+        for i in 0..bits {
+            hierarchical.clear_bit(i);
+            assert!(!hierarchical.is_full());
+            assert_eq!(hierarchical.get_lowest_clear_bit(), Some(0));
+            assert_eq!(hierarchical.get_highest_clear_bit(), Some(i));
+            assert_eq!(hierarchical.get_highest_clear_bit_unchecked(), i);
+        }
+
+        assert_eq!(hierarchical.full_cache, 0);
+    }
+
+    #[test]
+    fn validate_clear_bit_unchecked_when_full() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        for i in 0..ThreeLevelBitField::get_number_of_bits() {
+            hierarchical.set_bit(i);
+        }
+
+        //
+        // The result is undefined when every bit is set, but the queries must stay within the
+        // bitfield.
+        //
+
+        assert!(hierarchical.is_full());
+        assert_eq!(hierarchical.get_lowest_clear_bit(), None);
+        assert_eq!(hierarchical.get_highest_clear_bit(), None);
+        hierarchical.get_lowest_clear_bit_unchecked();
+        hierarchical.get_highest_clear_bit_unchecked();
+    }
+
+    #[test]
+    fn validate_count_rank_and_select() {
+        let mut hierarchical = ThreeLevelBitField::new();
+        let bits = ThreeLevelBitField::get_number_of_bits();
+        let stride = LargeBitField::get_number_of_bits() / 3 + 1;
+
+        //
+        // Empty has no bits to count or select.
+        //
+
+        assert_eq!(hierarchical.count_set_bits(), 0);
+        assert_eq!(hierarchical.rank(bits), 0);
+        assert_eq!(hierarchical.select(0), None);
+
+        for i in (0..bits).step_by(stride) {
+            hierarchical.set_bit(i);
+        }
+
+        let count = bits.div_ceil(stride);
+        assert_eq!(hierarchical.count_set_bits(), count);
+
+        for k in 0..count {
+            assert_eq!(hierarchical.select(k), Some(k * stride));
+            assert_eq!(hierarchical.rank(k * stride), k);
+            assert_eq!(hierarchical.rank((k * stride) + 1), k + 1);
+        }
+
+        assert_eq!(hierarchical.select(count), None);
+    }
+
+    #[test]
+    fn validate_get_next_clear_bit_and_find_clear_run() {
+        let mut hierarchical = TwoLevelBitField::new();
+        let group_size = SmallBitField::get_number_of_bits();
+        for index in 0..(group_size * 3) + 2 {
+            hierarchical.set_bit(index);
+        }
+
+        assert_eq!(
+            hierarchical.get_next_clear_bit(0),
+            Some((group_size * 3) + 2)
+        );
+        assert_eq!(
+            hierarchical.get_next_clear_bit(group_size * 5),
+            Some(group_size * 5)
+        );
+        assert_eq!(
+            hierarchical.get_next_clear_bit(TwoLevelBitField::get_number_of_bits()),
+            None
+        );
+
+        assert_eq!(hierarchical.find_clear_run(0, 1), None);
+        assert_eq!(hierarchical.find_clear_run(1, 0), None);
+        assert_eq!(
+            hierarchical.find_clear_run(group_size * 2, 1),
+            Some((group_size * 3) + 2)
+        );
+
+        assert_eq!(
+            hierarchical.find_clear_run(group_size, group_size),
+            Some(group_size * 4)
+        );
+
+        let mut hierarchical = ThreeLevelBitField::new();
+        let group_size = LargeBitField::get_number_of_bits();
+        hierarchical.set_bit((group_size * 2) + 1);
+        assert_eq!(hierarchical.find_clear_run(group_size * 2, 1), Some(0));
+        assert_eq!(
+            hierarchical.find_clear_run((group_size * 2) + 2, 1),
+            Some((group_size * 2) + 2)
+        );
+
+        assert_eq!(
+            hierarchical.find_clear_run(ThreeLevelBitField::get_number_of_bits(), 1),
+            None
+        );
+    }
+
+    #[test]
+    fn validate_standard_traits() {
+        let mut hierarchical = TwoLevelBitField::default();
+        assert_eq!(hierarchical, TwoLevelBitField::new());
+
+        let group_size = SmallBitField::get_number_of_bits();
+        hierarchical.set_bit(group_size - 1);
+        hierarchical.set_bit(group_size);
+        hierarchical.set_bit(group_size * 3);
+        let copy = hierarchical.clone();
+        assert_eq!(copy, hierarchical);
+        assert_eq!(
+            format!("{:?}", hierarchical),
+            format!(
+                "HierarchicalBitField {{{}..={}, {}}}",
+                group_size - 1,
+                group_size,
+                group_size * 3
+            )
+        );
+
+        hierarchical.clear_bit(group_size);
+        assert_ne!(copy, hierarchical);
+    }
+}
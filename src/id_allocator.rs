@@ -0,0 +1,281 @@
+use crate::{FastBitField, LargeBitField};
+
+/// Defines the errors reported by an IdAllocator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdAllocatorError {
+    /// The id is not less than the capacity of the allocator.
+    OutOfRange,
+
+    /// The id is already allocated.
+    AlreadyAllocated,
+
+    /// The id is not allocated.
+    NotAllocated,
+
+    /// Every id is allocated.
+    Exhausted,
+}
+
+/// Defines the structure for Id Allocators.
+/// An Id Allocator hands out the lowest free id in `0..capacity()` and tracks which ids are in use
+/// with a `LargeBitField`, so allocating, freeing and querying an id are all constant time
+/// operations. Unlike the underlying bitfield, invalid ids are reported as errors rather than
+/// ignored.
+pub struct IdAllocator {
+    /// Holds a set bit for every allocated id.
+    slots: LargeBitField,
+
+    /// Holds the number of allocated ids.
+    allocated: usize,
+}
+
+/// Defines functionality for IdAllocator.
+impl IdAllocator {
+    /// Creates a new IdAllocator with every id free.
+    ///
+    /// # Returns
+    /// An IdAllocator.
+    pub fn new() -> Self {
+        IdAllocator {
+            slots: LargeBitField::new(),
+            allocated: 0,
+        }
+    }
+
+    /// Gets the number of ids the allocator can hand out.
+    ///
+    /// # Returns
+    /// The capacity of the allocator.
+    pub fn capacity(&self) -> usize {
+        LargeBitField::get_number_of_bits()
+    }
+
+    /// Gets the number of ids currently allocated.
+    ///
+    /// # Returns
+    /// The number of allocated ids.
+    pub fn allocated_count(&self) -> usize {
+        self.allocated
+    }
+
+    /// Gets the number of ids currently free.
+    ///
+    /// # Returns
+    /// The number of free ids.
+    pub fn free_count(&self) -> usize {
+        self.capacity() - self.allocated
+    }
+
+    /// Allocates the lowest free id.
+    ///
+    /// # Returns
+    /// The allocated id or `IdAllocatorError::Exhausted` if every id is allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{IdAllocator, IdAllocatorError};
+    ///
+    /// let mut allocator = IdAllocator::new();
+    /// assert_eq!(allocator.alloc(), Ok(0));
+    /// assert_eq!(allocator.alloc(), Ok(1));
+    ///
+    /// allocator.free(0).unwrap();
+    /// assert_eq!(allocator.alloc(), Ok(0));
+    ///
+    /// while allocator.free_count() != 0 {
+    ///     allocator.alloc().unwrap();
+    /// }
+    ///
+    /// assert_eq!(allocator.alloc(), Err(IdAllocatorError::Exhausted));
+    /// ```
+    pub fn alloc(&mut self) -> Result<usize, IdAllocatorError> {
+        let id = self
+            .slots
+            .get_lowest_clear_bit()
+            .ok_or(IdAllocatorError::Exhausted)?;
+
+        //
+        // UNSAFE: id was found in the bitfield, so it is guaranteed to be within it.
+        //
+
+        unsafe {
+            self.slots.set_bit_unchecked(id);
+        }
+
+        self.allocated += 1;
+        Ok(id)
+    }
+
+    /// Allocates a specific id.
+    ///
+    /// # Arguments
+    /// id - Provides the id to allocate.
+    ///
+    /// # Returns
+    /// `Ok(())` if the id was allocated.
+    /// `IdAllocatorError::OutOfRange` if the id is invalid.
+    /// `IdAllocatorError::AlreadyAllocated` if the id is in use.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{IdAllocator, IdAllocatorError};
+    ///
+    /// let mut allocator = IdAllocator::new();
+    /// assert_eq!(allocator.alloc_at(0), Ok(()));
+    /// assert_eq!(allocator.alloc_at(0), Err(IdAllocatorError::AlreadyAllocated));
+    /// assert_eq!(
+    ///     allocator.alloc_at(allocator.capacity()),
+    ///     Err(IdAllocatorError::OutOfRange)
+    /// );
+    ///
+    /// assert_eq!(allocator.alloc(), Ok(1));
+    /// ```
+    pub fn alloc_at(&mut self, id: usize) -> Result<(), IdAllocatorError> {
+        if self.is_allocated(id)? {
+            return Err(IdAllocatorError::AlreadyAllocated);
+        }
+
+        //
+        // UNSAFE: is_allocated has validated id.
+        //
+
+        unsafe {
+            self.slots.set_bit_unchecked(id);
+        }
+
+        self.allocated += 1;
+        Ok(())
+    }
+
+    /// Frees an allocated id.
+    ///
+    /// # Arguments
+    /// id - Provides the id to free.
+    ///
+    /// # Returns
+    /// `Ok(())` if the id was freed.
+    /// `IdAllocatorError::OutOfRange` if the id is invalid.
+    /// `IdAllocatorError::NotAllocated` if the id is not in use.
+    pub fn free(&mut self, id: usize) -> Result<(), IdAllocatorError> {
+        if !self.is_allocated(id)? {
+            return Err(IdAllocatorError::NotAllocated);
+        }
+
+        //
+        // UNSAFE: is_allocated has validated id.
+        //
+
+        unsafe {
+            self.slots.clear_bit_unchecked(id);
+        }
+
+        self.allocated -= 1;
+        Ok(())
+    }
+
+    /// Determines whether or not an id is allocated.
+    ///
+    /// # Arguments
+    /// id - Provides the id to test.
+    ///
+    /// # Returns
+    /// `Ok(true)` if the id is in use.
+    /// `Ok(false)` if the id is free.
+    /// `IdAllocatorError::OutOfRange` if the id is invalid.
+    pub fn is_allocated(&self, id: usize) -> Result<bool, IdAllocatorError> {
+        self.slots.test_bit(id).ok_or(IdAllocatorError::OutOfRange)
+    }
+}
+
+/// Defines the default IdAllocator.
+impl Default for IdAllocator {
+    /// Creates a new IdAllocator with every id free.
+    ///
+    /// # Returns
+    /// An IdAllocator.
+    fn default() -> Self {
+        IdAllocator::new()
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let allocator = IdAllocator::new();
+        assert_eq!(allocator.capacity(), LargeBitField::get_number_of_bits());
+        assert_eq!(allocator.allocated_count(), 0);
+        assert_eq!(allocator.free_count(), allocator.capacity());
+        assert!(allocator.slots.is_empty());
+        assert_eq!(IdAllocator::default().allocated_count(), 0);
+    }
+
+    #[test]
+    fn validate_alloc_and_free() {
+        let mut allocator = IdAllocator::new();
+
+        //
+        // Allocation should hand out every id in order and then report exhaustion.
+        //
+
+        for id in 0..allocator.capacity() {
+            assert_eq!(allocator.alloc(), Ok(id));
+            assert_eq!(allocator.is_allocated(id), Ok(true));
+            assert_eq!(allocator.allocated_count(), id + 1);
+        }
+
+        assert_eq!(allocator.alloc(), Err(IdAllocatorError::Exhausted));
+        assert_eq!(allocator.free_count(), 0);
+
+        //
+        // Freed ids should be reused lowest first.
+        //
+
+        assert_eq!(allocator.free(70), Ok(()));
+        assert_eq!(allocator.free(3), Ok(()));
+        assert_eq!(allocator.free(3), Err(IdAllocatorError::NotAllocated));
+        assert_eq!(allocator.allocated_count(), allocator.capacity() - 2);
+        assert_eq!(allocator.alloc(), Ok(3));
+        assert_eq!(allocator.alloc(), Ok(70));
+        assert_eq!(allocator.alloc(), Err(IdAllocatorError::Exhausted));
+    }
+
+    #[test]
+    fn validate_alloc_at() {
+        let mut allocator = IdAllocator::new();
+        assert_eq!(allocator.alloc_at(0), Ok(()));
+        assert_eq!(allocator.alloc_at(2), Ok(()));
+        assert_eq!(
+            allocator.alloc_at(2),
+            Err(IdAllocatorError::AlreadyAllocated)
+        );
+        assert_eq!(allocator.allocated_count(), 2);
+
+        assert_eq!(allocator.alloc(), Ok(1));
+        assert_eq!(allocator.alloc(), Ok(3));
+    }
+
+    #[test]
+    fn validate_out_of_range_ids_are_errors() {
+        let mut allocator = IdAllocator::new();
+        let capacity = allocator.capacity();
+
+        assert_eq!(
+            allocator.alloc_at(capacity),
+            Err(IdAllocatorError::OutOfRange)
+        );
+        assert_eq!(allocator.free(capacity), Err(IdAllocatorError::OutOfRange));
+        assert_eq!(
+            allocator.is_allocated(core::usize::MAX),
+            Err(IdAllocatorError::OutOfRange)
+        );
+
+        assert_eq!(allocator.allocated_count(), 0);
+    }
+}
@@ -1,24 +1,452 @@
-use crate::{find_highest_set_bit, find_lowest_set_bit, FastBitField};
-
-/// Defines the number of bitfield groups in a large bitfield
+use crate::{
+    check_unchecked_index, find_lowest_set_bit, format_set_bits, get_serialized_size,
+    read_portable_bytes, search_clear_run, write_portable_bytes, BitFieldError, BitWord,
+    FastBitField, FixedBitField,
+};
+use core::fmt;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range, Sub, SubAssign,
+};
+
+/// Defines the number of bitfield groups in a large bitfield of `usize` words.
 const LARGE_BIT_FIELD_GROUP_COUNT: usize = core::mem::size_of::<usize>() * 8;
 
-/// Defines the maximum number of bits in a large bitfield.
-const LARGE_BIT_FIELD_BIT_SIZE: usize = LARGE_BIT_FIELD_GROUP_COUNT * LARGE_BIT_FIELD_GROUP_COUNT;
+/// Defines the maximum number of bits in a large bitfield of `usize` words.
+pub(crate) const LARGE_BIT_FIELD_BIT_SIZE: usize =
+    LARGE_BIT_FIELD_GROUP_COUNT * LARGE_BIT_FIELD_GROUP_COUNT;
 
 /// Defines the structure and fast_bitfield interface for Large Bitfieds.
 /// A Large Bitfield is a strcture that holds an array of `sizeof(usize) * 8` `usize` values as well
-/// as a "layer_cache" `usize` field to quickly determine highest and lowest set bits.
-pub struct LargeBitField {
+/// as a "layer_cache" `usize` field to quickly determine highest and lowest set bits and a
+/// "full_cache" `usize` field to quickly determine highest and lowest clear bits.
+///
+/// Another BitWord can be chosen in place of `usize`, in which case the bitfield holds as many
+/// groups as the word has bits. A `LargeBitField<u16>` holds 16 `u16` groups, or 256 bits.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LargeBitField<W: BitWord = usize> {
     /// Holds a bitfield describing which sub bitfields currently have any set bits.
-    layer_cache: usize,
+    layer_cache: W,
+
+    /// Holds a bitfield describing which sub bitfields currently have every bit set.
+    full_cache: W,
 
     /// Holds the bitfield state.
-    bitfield: [usize; LARGE_BIT_FIELD_GROUP_COUNT],
+    bitfield: W::Groups,
 }
 
-/// Defines the FastBitField interface for LargeBitField.
+/// Describes how a range of bits maps onto the groups of a large bitfield.
+struct RangeSpan<W: BitWord> {
+    /// Holds the index of the group containing the start of the range.
+    first_group: usize,
+
+    /// Holds the bits of the range within the first group.
+    first_mask: W,
+
+    /// Holds the index of the group containing the end of the range.
+    last_group: usize,
+
+    /// Holds the bits of the range within the last group. Empty if the range is contained in the
+    /// first group.
+    last_mask: W,
+
+    /// Holds a layer mask of the groups completely covered by the range, excluding the first and
+    /// last groups.
+    middle_groups: W,
+}
+
+/// Defines functionality unique to LargeBitFields of `usize` words, including the word and portable
+/// byte format conversions.
 impl LargeBitField {
+    /// Defines the number of bits in a LargeBitField. This is the same as `FixedBitField::BITS` but
+    /// names the `usize` word type for type inference.
+    pub const BITS: usize = LARGE_BIT_FIELD_BIT_SIZE;
+
+    /// Defines the number of bytes a LargeBitField takes up in the portable byte format.
+    pub const SERIALIZED_SIZE: usize = get_serialized_size(LARGE_BIT_FIELD_BIT_SIZE);
+
+    /// Defines the largest number of bytes a LargeBitField can take up in the sparse byte format.
+    pub const MAX_SPARSE_SERIALIZED_SIZE: usize = 16 + (LARGE_BIT_FIELD_GROUP_COUNT * 8);
+
+    /// Gets the number of bits available in the bitfield type. This is the same as
+    /// `FixedBitField::get_number_of_bits()` but does not require the trait to be in scope.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let bits_of = core::mem::size_of::<usize>() * 8;
+    /// assert_eq!(LargeBitField::get_number_of_bits(), bits_of * bits_of);
+    /// ```
+    pub const fn get_number_of_bits() -> usize {
+        LARGE_BIT_FIELD_BIT_SIZE
+    }
+
+    /// Creates a new, empty LargeBitField. This is the same as `FastBitField::new()` but does not
+    /// require the trait to be in scope, names the `usize` word type for type inference and can be
+    /// used to initialize a `const` or `static`.
+    ///
+    /// # Returns
+    /// A LargeBitField.
+    pub const fn new() -> Self {
+        LargeBitField {
+            layer_cache: 0,
+            full_cache: 0,
+            bitfield: [0; LARGE_BIT_FIELD_GROUP_COUNT],
+        }
+    }
+
+    /// Creates a LargeBitField with every bit set.
+    ///
+    /// # Returns
+    /// A full LargeBitField.
+    pub const fn full() -> Self {
+        LargeBitField {
+            layer_cache: core::usize::MAX,
+            full_cache: core::usize::MAX,
+            bitfield: [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT],
+        }
+    }
+
+    /// Creates a LargeBitField with a set of bits set. Invalid indices are ignored.
+    ///
+    /// # Arguments
+    /// bits - Provides the indices of the bits to set.
+    ///
+    /// # Returns
+    /// A LargeBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// static RESERVED_FRAMES: LargeBitField = LargeBitField::with_bits(&[0, 1, 2, 100]);
+    ///
+    /// assert_eq!(RESERVED_FRAMES.count_set_bits(), 4);
+    /// assert_eq!(RESERVED_FRAMES.get_lowest_clear_bit(), Some(3));
+    /// ```
+    pub const fn with_bits(bits: &[usize]) -> Self {
+        let mut large = LargeBitField::new();
+        let mut index = 0;
+        while index < bits.len() {
+            large = large.with_bit(bits[index]);
+            index += 1;
+        }
+
+        large
+    }
+
+    /// Creates a LargeBitField from its groups.
+    ///
+    /// # Arguments
+    /// words - Provides the bits to set in each group.
+    ///
+    /// # Returns
+    /// A LargeBitField with its layer cache and full cache computed from words.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut words = [0; BITS_OF];
+    /// words[2] = 0b100;
+    ///
+    /// let large = LargeBitField::from_words(words);
+    ///
+    /// assert_eq!(large.get_lowest_set_bit(), Some((BITS_OF * 2) + 2));
+    /// assert_eq!(large.as_words(), &words);
+    /// ```
+    pub const fn from_words(words: [usize; LARGE_BIT_FIELD_GROUP_COUNT]) -> Self {
+        let mut large = LargeBitField {
+            layer_cache: 0,
+            full_cache: 0,
+            bitfield: words,
+        };
+
+        //
+        // The caches are computed inline rather than through rebuild_summary so that this can be
+        // evaluated at compile time. Turn booleans into usizes to avoid branching.
+        //
+
+        let mut group_index = 0;
+        while group_index < LARGE_BIT_FIELD_GROUP_COUNT {
+            let group = words[group_index];
+            large.layer_cache |= ((group != 0) as usize) << group_index;
+            large.full_cache |= ((group == core::usize::MAX) as usize) << group_index;
+            group_index += 1;
+        }
+
+        large
+    }
+
+    /// Sets a bit in the bit field, returning the bit field so that bits can be set at compile
+    /// time. This is the same as `FastBitField::set_bit()` but can be evaluated in a `const fn`.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set. Invalid indices are ignored.
+    ///
+    /// # Returns
+    /// The LargeBitField with the bit set.
+    ///
+    /// # Note
+    /// This is not named `set_bit` so that it does not shadow `FastBitField::set_bit()`, which
+    /// verifies the caches after every mutation when the `checked` feature is enabled.
+    pub const fn with_bit(mut self, index: usize) -> Self {
+        if index >= LARGE_BIT_FIELD_BIT_SIZE {
+            return self;
+        }
+
+        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
+        let bottom_layer = index % LARGE_BIT_FIELD_GROUP_COUNT;
+        self.layer_cache |= 1 << top_layer;
+        self.bitfield[top_layer] |= 1 << bottom_layer;
+        if self.bitfield[top_layer] == core::usize::MAX {
+            self.full_cache |= 1 << top_layer;
+        }
+
+        self
+    }
+
+    /// Gets the value of a specific bit in the bit field. This is the same as
+    /// `FastBitField::test_bit()` but can be evaluated at compile time.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    pub const fn test_bit(&self, index: usize) -> Option<bool> {
+        if index >= LARGE_BIT_FIELD_BIT_SIZE {
+            return None;
+        }
+
+        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
+        let bottom_mask = 1 << (index % LARGE_BIT_FIELD_GROUP_COUNT);
+        Some((self.bitfield[top_layer] & bottom_mask) != 0)
+    }
+
+    /// Gets the groups of the bit field.
+    ///
+    /// # Returns
+    /// A reference to the groups of the bit field.
+    pub fn as_words(&self) -> &[usize; LARGE_BIT_FIELD_GROUP_COUNT] {
+        &self.bitfield
+    }
+
+    /// Converts the bit field into its groups.
+    ///
+    /// # Returns
+    /// The groups of the bit field.
+    pub fn into_words(self) -> [usize; LARGE_BIT_FIELD_GROUP_COUNT] {
+        self.bitfield
+    }
+
+    /// Writes the bit field into the portable byte format.
+    ///
+    /// The format is the number of bits as a little-endian `u64` followed by the bits packed into
+    /// little-endian `u64` words, so it can be exchanged between machines with different `usize`
+    /// sizes as long as the number of bits matches.
+    ///
+    /// # Arguments
+    /// buffer - Provides the buffer to write to. Must be at least `SERIALIZED_SIZE` bytes.
+    ///
+    /// # Returns
+    /// The number of bytes written or `BitFieldError::BufferTooSmall` if buffer is too small.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let mut buffer = [0; LargeBitField::SERIALIZED_SIZE];
+    /// let mut large = LargeBitField::new();
+    /// large.set_range(100..300);
+    ///
+    /// assert_eq!(large.to_bytes(&mut buffer), Ok(LargeBitField::SERIALIZED_SIZE));
+    /// assert_eq!(LargeBitField::from_bytes(&buffer), Ok(large));
+    /// ```
+    pub fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, BitFieldError> {
+        write_portable_bytes(&self.bitfield, buffer)
+    }
+
+    /// Reads a bit field from the portable byte format.
+    ///
+    /// # Arguments
+    /// bytes - Provides the serialized bit field. Bytes past `SERIALIZED_SIZE` are ignored.
+    ///
+    /// # Returns
+    /// The bit field with its layer cache and full cache computed from the bits, or an error if
+    /// bytes is too short, holds a different number of bits or is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BitFieldError> {
+        let mut words = [0; LARGE_BIT_FIELD_GROUP_COUNT];
+        read_portable_bytes(bytes, &mut words)?;
+        Ok(LargeBitField::from_words(words))
+    }
+
+    /// Gets the number of bytes the bit field takes up in the sparse byte format.
+    ///
+    /// The sparse format is the number of bits as a little-endian `u64`, followed by the layer
+    /// cache as a little-endian `u64`, followed by each group with any bits set as a little-endian
+    /// `u64`, lowest group first. Empty groups are not written.
+    ///
+    /// On a 64-bit machine an empty bit field takes 16 bytes and each group with any bits set adds
+    /// 8 bytes, compared to the 520 bytes of the portable byte format. A bit field with only a
+    /// handful of bits set is therefore roughly 10 to 30 times smaller, while a bit field with every
+    /// group populated takes 8 bytes more than the portable byte format.
+    ///
+    /// # Returns
+    /// The number of bytes in the sparse serialized bit field.
+    pub fn get_sparse_serialized_size(&self) -> usize {
+        16 + (self.layer_cache.count_ones() as usize * 8)
+    }
+
+    /// Writes the bit field into the sparse byte format.
+    ///
+    /// # Arguments
+    /// buffer - Provides the buffer to write to. Must be at least `get_sparse_serialized_size()`
+    /// bytes. `MAX_SPARSE_SERIALIZED_SIZE` bytes are always enough.
+    ///
+    /// # Returns
+    /// The number of bytes written or `BitFieldError::BufferTooSmall` if buffer is too small.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let mut buffer = [0; LargeBitField::MAX_SPARSE_SERIALIZED_SIZE];
+    /// let mut large = LargeBitField::new();
+    /// large.set_bit(5);
+    /// large.set_bit(1000);
+    ///
+    /// assert_eq!(large.to_sparse_bytes(&mut buffer), Ok(32));
+    /// assert_eq!(LargeBitField::from_sparse_bytes(&buffer[..32]), Ok(large));
+    /// ```
+    pub fn to_sparse_bytes(&self, buffer: &mut [u8]) -> Result<usize, BitFieldError> {
+        let size = self.get_sparse_serialized_size();
+        if buffer.len() < size {
+            return Err(BitFieldError::BufferTooSmall);
+        }
+
+        buffer[..8].copy_from_slice(&(LARGE_BIT_FIELD_BIT_SIZE as u64).to_le_bytes());
+        buffer[8..16].copy_from_slice(&(self.layer_cache as u64).to_le_bytes());
+
+        //
+        // Walk the set bits of the layer cache, clearing the lowest one each time.
+        //
+
+        let mut remaining = self.layer_cache;
+        let outputs = buffer[16..size].chunks_mut(8);
+        for output in outputs {
+            let group_index = find_lowest_set_bit(remaining);
+            remaining &= remaining - 1;
+            output.copy_from_slice(&(self.bitfield[group_index] as u64).to_le_bytes());
+        }
+
+        Ok(size)
+    }
+
+    /// Reads a bit field from the sparse byte format.
+    ///
+    /// # Arguments
+    /// bytes - Provides the serialized bit field. Bytes past the end of the serialized bit field
+    /// are ignored, so `get_sparse_serialized_size()` of the result gives the offset of anything
+    /// written after it.
+    ///
+    /// # Returns
+    /// The bit field with its layer cache and full cache computed from the bits.
+    /// `BitFieldError::BufferTooSmall` if bytes is too short.
+    /// `BitFieldError::SizeMismatch` if the serialized bit count does not match.
+    /// `BitFieldError::InvalidFormat` if the summary names groups that do not exist, a written group
+    /// is empty or a written group has bits set past the end of the group.
+    pub fn from_sparse_bytes(bytes: &[u8]) -> Result<Self, BitFieldError> {
+        if bytes.len() < 16 {
+            return Err(BitFieldError::BufferTooSmall);
+        }
+
+        let mut word = [0; 8];
+        word.copy_from_slice(&bytes[..8]);
+        if u64::from_le_bytes(word) != LARGE_BIT_FIELD_BIT_SIZE as u64 {
+            return Err(BitFieldError::SizeMismatch);
+        }
+
+        word.copy_from_slice(&bytes[8..16]);
+        let summary = u64::from_le_bytes(word);
+        if (summary as usize) as u64 != summary {
+            return Err(BitFieldError::InvalidFormat);
+        }
+
+        let size = 16 + (summary.count_ones() as usize * 8);
+        if bytes.len() < size {
+            return Err(BitFieldError::BufferTooSmall);
+        }
+
+        let mut words = [0; LARGE_BIT_FIELD_GROUP_COUNT];
+        let mut remaining = summary as usize;
+        for input in bytes[16..size].chunks(8) {
+            word.copy_from_slice(input);
+            let value = u64::from_le_bytes(word);
+
+            //
+            // Groups named by the summary must be non-empty and fit in a usize.
+            //
+
+            if value == 0 || (value as usize) as u64 != value {
+                return Err(BitFieldError::InvalidFormat);
+            }
+
+            words[find_lowest_set_bit(remaining)] = value as usize;
+            remaining &= remaining - 1;
+        }
+
+        Ok(LargeBitField::from_words(words))
+    }
+}
+
+/// Defines functionality unique to LargeBitField.
+impl<W: BitWord> LargeBitField<W> {
+    /// Creates a LargeBitField from its groups.
+    ///
+    /// # Arguments
+    /// groups - Provides the bits to set in each group.
+    ///
+    /// # Returns
+    /// A LargeBitField with its layer cache and full cache computed from groups.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let mut groups = [0u8; 8];
+    /// groups[2] = 0b100;
+    ///
+    /// let large = LargeBitField::<u8>::from_groups(groups);
+    ///
+    /// assert_eq!(large.get_lowest_set_bit(), Some(18));
+    /// assert_eq!(large.as_groups(), &groups);
+    /// ```
+    pub fn from_groups(groups: W::Groups) -> Self {
+        let mut large = LargeBitField {
+            layer_cache: W::ZERO,
+            full_cache: W::ZERO,
+            bitfield: groups,
+        };
+
+        large.rebuild_summary();
+        large
+    }
+
+    /// Gets the groups of the bit field.
+    ///
+    /// # Returns
+    /// A reference to the groups of the bit field.
+    pub fn as_groups(&self) -> &W::Groups {
+        &self.bitfield
+    }
+
     /// Gets whether or not a specific group in the bit field has any bits set.
     ///
     /// # Arguments
@@ -45,7 +473,7 @@ impl LargeBitField {
     /// assert_eq!(large.test_group(0), Some(true));
     /// ```
     pub fn test_group(&self, group_index: usize) -> Option<bool> {
-        if group_index < LARGE_BIT_FIELD_GROUP_COUNT {
+        if group_index < W::BITS {
             //
             // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
             // calling it.
@@ -68,8 +496,8 @@ impl LargeBitField {
     /// # Note
     /// If the group_index provided is larger than the number of groups in the bit field. The field
     /// will remain unchanged.
-    pub fn set_group(&mut self, group_index: usize, group_field: usize) {
-        if group_index < LARGE_BIT_FIELD_GROUP_COUNT {
+    pub fn set_group(&mut self, group_index: usize, group_field: W) {
+        if group_index < W::BITS {
             //
             // UNSAFE: The group_index check that makes the unsafe variant unsafe is performed before
             // calling it.
@@ -90,8 +518,8 @@ impl LargeBitField {
     /// # Note
     /// If the group_index provided is larger than the number of groups in the bit field. The field
     /// will remain unchanged.
-    pub fn clear_group(&mut self, group_index: usize, group_field: usize) {
-        if group_index < LARGE_BIT_FIELD_GROUP_COUNT {
+    pub fn clear_group(&mut self, group_index: usize, group_field: W) {
+        if group_index < W::BITS {
             //
             // UNSAFE: The group_index check that makes the unsafe variant unsafe is performed before
             // calling it.
@@ -103,191 +531,210 @@ impl LargeBitField {
         }
     }
 
-    /// Sets bits in the bitfield
+    /// Gets whether or not a specific group in the bit field has any bits set, reporting invalid
+    /// groups.
     ///
     /// # Arguments
-    /// values - Provides the bits to be set in the bitfield.
-    pub fn set_field(&mut self, values: &[usize; LARGE_BIT_FIELD_GROUP_COUNT]) {
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            //
-            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
-            //
-
-            unsafe {
-                self.set_group_unchecked(index, values[index]);
-            }
-        }
-    }
-
-    /// Clears bits in the bitfield
+    /// group_index - Provides the group to test.
     ///
-    /// # Arguments
-    /// values - Provides the bits to be cleared in the bitfield.
-    pub fn clear_field(&mut self, values: &[usize; LARGE_BIT_FIELD_GROUP_COUNT]) {
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            //
-            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
-            //
-
-            unsafe {
-                self.clear_group_unchecked(index, values[index]);
-            }
-        }
+    /// # Returns
+    /// `Ok(true)` if the group has any bits set.
+    /// `Ok(false)` if the group as no bits set.
+    /// `BitFieldError::GroupOutOfRange` if group_index is invalid.
+    pub fn try_test_group(&self, group_index: usize) -> Result<bool, BitFieldError> {
+        self.test_group(group_index)
+            .ok_or(BitFieldError::GroupOutOfRange)
     }
 
-    /// Gets whether or not a specific group in the bit field has any bits set.
+    /// Sets bits in a specific group in the bit field, reporting invalid groups.
     ///
     /// # Arguments
-    /// group_index - Provides the group to test.
+    /// group_index - Provides the group within the bit field to set.
+    /// group_field - Provides the bits to set within the group.
     ///
     /// # Returns
-    /// `true` if the group has any bits set.
-    /// `false` if the group as no bits set.
-    ///
-    /// # Unsafe
-    /// This unsafe variant does not check if the group_index is valid for the size of
-    /// the bit field. The caller must guarantee that group_index is within the number of
-    /// groups in the bit field.
+    /// `Ok(())` if the bits were set or `BitFieldError::GroupOutOfRange` if group_index is invalid.
     ///
     /// # Examples
     /// ```
-    /// use fast_bitfield::{FastBitField, LargeBitField};
-    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    /// use fast_bitfield::{BitFieldError, FastBitField, LargeBitField};
     ///
     /// let mut large = LargeBitField::new();
-    /// let clear_value = [core::usize::MAX; BITS_OF];
-    /// large.clear_field(&clear_value);
-    ///
-    /// unsafe {
-    ///     assert_eq!(large.test_group_unchecked(0), false);
     ///
-    ///     large.set_bit_unchecked(2);
-    ///     assert_eq!(large.test_group_unchecked(0), true);
-    /// }
+    /// assert_eq!(large.try_set_group(1, 0b101), Ok(()));
+    /// assert_eq!(large.try_test_group(1), Ok(true));
+    /// assert_eq!(
+    ///     large.try_set_group(core::usize::MAX, 1),
+    ///     Err(BitFieldError::GroupOutOfRange)
+    /// );
     /// ```
-    pub unsafe fn test_group_unchecked(&self, group_index: usize) -> bool {
-        (self.layer_cache & (1 << group_index)) != 0
-    }
+    pub fn try_set_group(
+        &mut self,
+        group_index: usize,
+        group_field: W,
+    ) -> Result<(), BitFieldError> {
+        if group_index >= W::BITS {
+            return Err(BitFieldError::GroupOutOfRange);
+        }
 
-    /// Sets bits in a specific group in the bit field.
-    ///
-    /// # Arguments
-    /// group_index - Provides the group within the bit field to set.
-    /// group_field - Provides the bits to set within the group.
-    ///
-    /// # Unsafe
-    /// This unsafe variant does not check if the group_index is valid for the size of
-    /// the bit field. The caller must guarantee that group_index is within the number of
-    /// groups in the bit field.
-    pub unsafe fn set_group_unchecked(&mut self, group_index: usize, group_field: usize) {
         //
-        // Turn boolean into a usize to avoid branching.
+        // UNSAFE: The group_index check that makes the unsafe variant unsafe is performed before
+        // calling it.
         //
 
-        let field_has_values = (group_field != 0) as usize;
-        let layer_cache_update = (1 << group_index) * field_has_values;
-
-        let subfield = self.bitfield.get_unchecked_mut(group_index);
-        *subfield |= group_field;
+        unsafe {
+            self.set_group_unchecked(group_index, group_field);
+        }
 
-        self.layer_cache |= layer_cache_update;
+        Ok(())
     }
 
-    /// Clears bits in a specific group in the bit field.
+    /// Clears bits in a specific group in the bit field, reporting invalid groups.
     ///
     /// # Arguments
     /// group_index - Provides the group within the bit field to clear.
     /// group_field - Provides the bits to clear within the group.
     ///
-    /// # Unsafe
-    /// This unsafe variant does not check if the group_index is valid for the size of
-    /// the bit field. The caller must guarantee that group_index is within the number of
-    /// groups in the bit field.
-    pub unsafe fn clear_group_unchecked(&mut self, group_index: usize, group_field: usize) {
-        let subfield = self.bitfield.get_unchecked_mut(group_index);
-        *subfield &= !group_field;
+    /// # Returns
+    /// `Ok(())` if the bits were cleared or `BitFieldError::GroupOutOfRange` if group_index is
+    /// invalid.
+    pub fn try_clear_group(
+        &mut self,
+        group_index: usize,
+        group_field: W,
+    ) -> Result<(), BitFieldError> {
+        if group_index >= W::BITS {
+            return Err(BitFieldError::GroupOutOfRange);
+        }
 
         //
-        // Turn boolean into a usize to avoid branching.
+        // UNSAFE: The group_index check that makes the unsafe variant unsafe is performed before
+        // calling it.
         //
 
-        let is_clear = (*subfield == 0) as usize;
-        let layer_cache_update = (1 << group_index) * is_clear;
-        self.layer_cache &= !layer_cache_update;
+        unsafe {
+            self.clear_group_unchecked(group_index, group_field);
+        }
+
+        Ok(())
     }
-}
 
-/// Defines the FastBitField interface for LargeBitField.
-impl FastBitField for LargeBitField {
-    /// Creates a new, empty LargeBitField
+    /// Sets bits in the bitfield
     ///
-    /// # Returns
-    /// A LargeBitField.
-    fn new() -> Self {
-        LargeBitField {
-            layer_cache: 0,
-            bitfield: [0; LARGE_BIT_FIELD_GROUP_COUNT],
+    /// # Arguments
+    /// values - Provides the bits to be set in the bitfield.
+    pub fn set_field(&mut self, values: &W::Groups) {
+        for index in 0..W::BITS {
+            //
+            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
+            //
+
+            unsafe {
+                self.set_group_unchecked(index, values[index]);
+            }
         }
     }
 
-    /// Gets the number of bits available in the bitfield type.
+    /// Clears bits in the bitfield
     ///
-    /// # Returns
-    /// The number of bits available.
+    /// # Arguments
+    /// values - Provides the bits to be cleared in the bitfield.
+    pub fn clear_field(&mut self, values: &W::Groups) {
+        for index in 0..W::BITS {
+            //
+            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
+            //
+
+            unsafe {
+                self.clear_group_unchecked(index, values[index]);
+            }
+        }
+    }
+
+    /// Sets a range of bits in the bit field.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to set.
+    ///
+    /// # Note
+    /// If the range extends past the end of the bit field or starts after it ends, the field will
+    /// remain unchanged. Groups completely covered by the range are filled without inspecting
+    /// their previous value and the layer cache is updated for all of them at once.
     ///
     /// # Examples
     /// ```
     /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
     ///
-    /// let bits_of = core::mem::size_of::<usize>() * 8;
-    /// assert_eq!(LargeBitField::get_number_of_bits(), bits_of * bits_of);
-    /// ```
-    fn get_number_of_bits() -> usize {
-        LARGE_BIT_FIELD_BIT_SIZE
-    }
-
-    /// Sets a bit in the bit field
+    /// let mut large = LargeBitField::new();
+    /// large.set_range(3..(BITS_OF * 4) + 2);
     ///
-    /// # Arguments
-    /// index - Provides the bit to set.
-    fn set_bit(&mut self, index: usize) {
-        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
-        let bottom_layer = index % LARGE_BIT_FIELD_GROUP_COUNT;
-
-        let sub_field = self.bitfield.get_mut(top_layer);
-        let sub_field = match sub_field {
-            Some(s) => s,
+    /// assert_eq!(large.count_set_bits(), (BITS_OF * 4) - 1);
+    /// assert_eq!(large.get_lowest_set_bit(), Some(3));
+    /// assert_eq!(large.get_highest_set_bit(), Some((BITS_OF * 4) + 1));
+    /// ```
+    pub fn set_range(&mut self, range: Range<usize>) {
+        let span = match Self::get_range_span(&range) {
+            Some(span) => span,
             None => return,
         };
 
-        self.layer_cache |= 1 << top_layer;
-        *sub_field |= 1 << bottom_layer;
+        //
+        // UNSAFE: get_range_span only describes groups within the bit field.
+        //
+
+        unsafe {
+            for group_index in (span.first_group + 1)..span.last_group {
+                *self.bitfield.as_mut().get_unchecked_mut(group_index) = W::MAX;
+            }
+
+            self.layer_cache |= span.middle_groups;
+            self.full_cache |= span.middle_groups;
+            self.set_group_unchecked(span.first_group, span.first_mask);
+            self.set_group_unchecked(span.last_group, span.last_mask);
+        }
     }
 
-    /// Clears a bit in the bit field
+    /// Clears a range of bits in the bit field.
     ///
     /// # Arguments
-    /// index - Provides the bit to clear.
-    fn clear_bit(&mut self, index: usize) {
-        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
-        let bottom_layer = index % LARGE_BIT_FIELD_GROUP_COUNT;
-
-        let sub_field = self.bitfield.get_mut(top_layer);
-        let sub_field = match sub_field {
-            Some(s) => s,
+    /// range - Provides the bits to clear.
+    ///
+    /// # Note
+    /// If the range extends past the end of the bit field or starts after it ends, the field will
+    /// remain unchanged. Groups completely covered by the range are emptied without inspecting
+    /// their previous value and the layer cache is updated for all of them at once.
+    pub fn clear_range(&mut self, range: Range<usize>) {
+        let span = match Self::get_range_span(&range) {
+            Some(span) => span,
             None => return,
         };
 
-        *sub_field &= !(1 << bottom_layer);
-        if *sub_field == 0 {
-            self.layer_cache &= !(1 << top_layer);
+        //
+        // UNSAFE: get_range_span only describes groups within the bit field.
+        //
+
+        unsafe {
+            for group_index in (span.first_group + 1)..span.last_group {
+                *self.bitfield.as_mut().get_unchecked_mut(group_index) = W::ZERO;
+            }
+
+            self.layer_cache &= !span.middle_groups;
+            self.full_cache &= !span.middle_groups;
+            self.clear_group_unchecked(span.first_group, span.first_mask);
+            self.clear_group_unchecked(span.last_group, span.last_mask);
         }
     }
 
-    /// Gets the lowest set bit.
+    /// Determines whether or not every bit in a range is set.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to test.
     ///
     /// # Returns
-    /// The lowest set bit index or `None` if no bits are set.
+    /// `Some(true)` if every bit in the range is set.
+    /// `Some(false)` if any bit in the range is cleared.
+    /// `None` if the range is invalid.
     ///
     /// # Examples
     /// ```
@@ -295,29 +742,60 @@ impl FastBitField for LargeBitField {
     /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
     ///
     /// let mut large = LargeBitField::new();
-    /// let clear_value = [core::usize::MAX; BITS_OF];
-    /// large.clear_field(&clear_value);
+    /// large.set_range(BITS_OF..BITS_OF * 3);
     ///
-    /// assert_eq!(large.get_lowest_set_bit(), None);
+    /// assert_eq!(large.is_range_set(BITS_OF..BITS_OF * 3), Some(true));
+    /// assert_eq!(large.is_range_set(BITS_OF - 1..BITS_OF * 3), Some(false));
+    /// assert_eq!(large.is_range_set(0..core::usize::MAX), None);
+    /// ```
+    pub fn is_range_set(&self, range: Range<usize>) -> Option<bool> {
+        let span = Self::get_range_span(&range)?;
+
+        //
+        // The groups in the middle are checked through the full cache.
+        //
+
+        let first_group = self.bitfield[span.first_group];
+        let last_group = self.bitfield[span.last_group];
+        Some(
+            ((first_group & span.first_mask) == span.first_mask)
+                && ((last_group & span.last_mask) == span.last_mask)
+                && ((self.full_cache & span.middle_groups) == span.middle_groups),
+        )
+    }
+
+    /// Determines whether or not every bit in a range is cleared.
     ///
-    /// large.set_bit(7);
-    /// assert_eq!(large.get_lowest_set_bit(), Some(7));
+    /// # Arguments
+    /// range - Provides the bits to test.
     ///
-    /// large.set_bit(9);
-    /// assert_eq!(large.get_lowest_set_bit(), Some(7));
-    /// ```
-    fn get_lowest_set_bit(&self) -> Option<usize> {
-        if self.is_empty() {
-            return None;
-        }
+    /// # Returns
+    /// `Some(true)` if every bit in the range is cleared.
+    /// `Some(false)` if any bit in the range is set.
+    /// `None` if the range is invalid.
+    pub fn is_range_clear(&self, range: Range<usize>) -> Option<bool> {
+        let span = Self::get_range_span(&range)?;
 
-        Some(self.get_lowest_set_bit_unchecked())
+        //
+        // The groups in the middle are checked through the layer cache.
+        //
+
+        let first_group = self.bitfield[span.first_group];
+        let last_group = self.bitfield[span.last_group];
+        Some(
+            ((first_group & span.first_mask) == W::ZERO)
+                && ((last_group & span.last_mask) == W::ZERO)
+                && ((self.layer_cache & span.middle_groups) == W::ZERO),
+        )
     }
 
-    /// Gets the highest set bit.
+    /// Gets the number of set bits in a range.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to count.
     ///
     /// # Returns
-    /// The highest set bit index or `None` if no bits are set.
+    /// The number of set bits in the range or `None` if the range is invalid.
     ///
     /// # Examples
     /// ```
@@ -325,34 +803,44 @@ impl FastBitField for LargeBitField {
     /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
     ///
     /// let mut large = LargeBitField::new();
-    /// let clear_value = [core::usize::MAX; BITS_OF];
-    /// large.clear_field(&clear_value);
-    ///
-    /// assert_eq!(large.get_highest_set_bit(), None);
-    ///
-    /// large.set_bit(7);
-    /// assert_eq!(large.get_highest_set_bit(), Some(7));
+    /// large.set_bit(1);
+    /// large.set_bit(BITS_OF + 4);
+    /// large.set_bit(BITS_OF * 6);
     ///
-    /// large.set_bit(9);
-    /// assert_eq!(large.get_highest_set_bit(), Some(9));
+    /// assert_eq!(large.count_in_range(2..BITS_OF * 6), Some(1));
+    /// assert_eq!(large.count_in_range(0..(BITS_OF * 6) + 1), Some(3));
     /// ```
-    fn get_highest_set_bit(&self) -> Option<usize> {
-        if self.is_empty() {
-            return None;
+    pub fn count_in_range(&self, range: Range<usize>) -> Option<usize> {
+        let span = Self::get_range_span(&range)?;
+        let mut count = (self.bitfield[span.first_group] & span.first_mask).count_set_bits();
+        count += (self.bitfield[span.last_group] & span.last_mask).count_set_bits();
+
+        //
+        // Only the groups in the middle with any bits set need to be visited.
+        //
+
+        let mut groups = self.layer_cache & span.middle_groups;
+        while groups != W::ZERO {
+            let group_index = groups.find_lowest_set_bit();
+            groups &= !(W::ONE << group_index);
+            count += self.bitfield[group_index].count_set_bits();
         }
 
-        Some(self.get_highest_set_bit_unchecked())
+        Some(count)
     }
 
-    /// Gets the value of a specific bit in the bit field.
+    /// Finds the lowest run of consecutive clear bits.
     ///
     /// # Arguments
-    /// index - Provides the bit to test.
+    /// len - Provides the number of consecutive clear bits required.
+    /// align - Provides the alignment the first bit of the run must have.
     ///
     /// # Returns
-    /// `Some(true)` if bit is set.
-    /// `Some(false)` if bit is cleared.
-    /// `None` if index is invalid.
+    /// The index of the first bit of the lowest run of len clear bits starting at a multiple of
+    /// align or `None` if no such run exists. A len or align of zero results in `None`.
+    ///
+    /// # Note
+    /// Full groups are skipped using the full cache and runs may span any number of groups.
     ///
     /// # Examples
     /// ```
@@ -360,63 +848,131 @@ impl FastBitField for LargeBitField {
     /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
     ///
     /// let mut large = LargeBitField::new();
-    /// let clear_value = [core::usize::MAX; BITS_OF];
-    /// large.clear_field(&clear_value);
+    /// large.set_range(0..BITS_OF + 3);
+    /// large.set_bit(BITS_OF + 9);
     ///
-    /// assert_eq!(large.test_bit(core::usize::MAX), None);
-    /// assert_eq!(large.test_bit(10), Some(false));
-    ///
-    /// large.set_bit(10);
-    /// assert_eq!(large.test_bit(10), Some(true));
+    /// assert_eq!(large.find_clear_run(6, 1), Some(BITS_OF + 3));
+    /// assert_eq!(large.find_clear_run(7, 1), Some(BITS_OF + 10));
+    /// assert_eq!(large.find_clear_run(4, 8), Some(BITS_OF + 16));
     /// ```
-    fn test_bit(&self, index: usize) -> Option<bool> {
-        if index < LARGE_BIT_FIELD_BIT_SIZE {
-            //
-            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
-            // calling it.
-            //
+    pub fn find_clear_run(&self, len: usize, align: usize) -> Option<usize> {
+        search_clear_run(self, len, align)
+    }
 
-            unsafe {
-                return Some(self.test_bit_unchecked(index));
-            }
+    /// Splits a range of bits into the groups it covers.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to split.
+    ///
+    /// # Returns
+    /// The groups covered by the range or `None` if the range is invalid. An empty range results
+    /// in a span with no bits.
+    fn get_range_span(range: &Range<usize>) -> Option<RangeSpan<W>> {
+        if range.start > range.end || range.end > Self::BITS {
+            return None;
         }
 
-        None
+        if range.start == range.end {
+            return Some(RangeSpan {
+                first_group: 0,
+                first_mask: W::ZERO,
+                last_group: 0,
+                last_mask: W::ZERO,
+                middle_groups: W::ZERO,
+            });
+        }
+
+        let first_group = range.start / W::BITS;
+        let first_bit = range.start % W::BITS;
+        let last_group = (range.end - 1) / W::BITS;
+        let last_bit = (range.end - 1) % W::BITS;
+
+        if first_group == last_group {
+            return Some(RangeSpan {
+                first_group,
+                first_mask: W::get_range_mask(first_bit, last_bit + 1),
+                last_group,
+                last_mask: W::ZERO,
+                middle_groups: W::ZERO,
+            });
+        }
+
+        let middle_groups = if last_group > first_group + 1 {
+            W::get_range_mask(first_group + 1, last_group)
+        } else {
+            W::ZERO
+        };
+
+        Some(RangeSpan {
+            first_group,
+            first_mask: W::get_range_mask(first_bit, W::BITS),
+            last_group,
+            last_mask: W::get_range_mask(0, last_bit + 1),
+            middle_groups,
+        })
     }
 
-    /// Determines whether or not the bitfield is empty.
+    /// Checks that the layer cache and full cache match the groups of the bit field.
     ///
     /// # Returns
-    /// `true` if empty, `false` otherwise.
+    /// `Ok(())` if the caches are consistent or `Err(groups)` with a bit set for every group whose
+    /// layer cache or full cache entry does not match its value.
+    ///
+    /// # Note
+    /// The caches can only fall out of sync if the bit field is modified without going through its
+    /// methods, for example by restoring it from raw memory. `rebuild_summary` repairs them.
     ///
     /// # Examples
     /// ```
     /// use fast_bitfield::{FastBitField, LargeBitField};
     ///
-    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
-    ///
     /// let mut large = LargeBitField::new();
+    /// large.set_bit(100);
     ///
-    /// let clear_value = [core::usize::MAX; BITS_OF];
-    ///
-    /// large.clear_field(&clear_value);
-    /// assert!(large.is_empty());
-    ///
-    /// large.set_bit(0);
-    /// assert!(!large.is_empty());
+    /// assert_eq!(large.verify_invariants(), Ok(()));
     /// ```
-    fn is_empty(&self) -> bool {
-        self.layer_cache == 0
+    pub fn verify_invariants(&self) -> Result<(), W> {
+        let (layer_cache, full_cache) = self.compute_caches();
+        let mismatched = (layer_cache ^ self.layer_cache) | (full_cache ^ self.full_cache);
+        if mismatched != W::ZERO {
+            return Err(mismatched);
+        }
+
+        Ok(())
     }
 
-    /// Gets the lowest set bit, guaranteed to have no branches and be in constant time, completely
-    /// invariant of the state of the bit field. If no bits are set, the result is undefined.
+    /// Recomputes the layer cache and full cache from the groups of the bit field.
+    pub fn rebuild_summary(&mut self) {
+        let (layer_cache, full_cache) = self.compute_caches();
+        self.layer_cache = layer_cache;
+        self.full_cache = full_cache;
+    }
+
+    /// Computes the layer cache and full cache from the groups of the bit field.
     ///
-    /// This function should only be used if the caller can guarantee the bitfield will always
-    /// have at least one bit set.
+    /// # Returns
+    /// The layer cache and full cache the bit field should have.
+    fn compute_caches(&self) -> (W, W) {
+        let mut layer_cache = W::ZERO;
+        let mut full_cache = W::ZERO;
+        for (group_index, group) in self.bitfield.as_ref().iter().enumerate() {
+            //
+            // Turn booleans into words to avoid branching.
+            //
+
+            layer_cache |= W::from_bool(*group != W::ZERO) << group_index;
+            full_cache |= W::from_bool(*group == W::MAX) << group_index;
+        }
+
+        (layer_cache, full_cache)
+    }
+
+    /// Gets an iterator over the set bits in the bit field.
     ///
     /// # Returns
-    /// The lowest set bit index or `UNDEFINED` if no bits are set.
+    /// An iterator yielding the index of each set bit, from lowest to highest. The iterator may
+    /// also be consumed from highest to lowest. Groups with no bits set are skipped using the
+    /// layer cache.
     ///
     /// # Examples
     /// ```
@@ -424,39 +980,42 @@ impl FastBitField for LargeBitField {
     /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
     ///
     /// let mut large = LargeBitField::new();
-    /// let clear_value = [core::usize::MAX; BITS_OF];
-    /// large.clear_field(&clear_value);
-    ///
-    /// large.set_bit(7);
-    /// assert_eq!(large.get_lowest_set_bit_unchecked(), 7);
-    ///
-    /// large.set_bit(9);
-    /// assert_eq!(large.get_lowest_set_bit_unchecked(), 7);
+    /// large.set_bit(3);
+    /// large.set_bit(BITS_OF + 1);
+    /// large.set_bit(BITS_OF * 5);
+    ///
+    /// let mut iter = large.iter_set_bits();
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next_back(), Some(BITS_OF * 5));
+    /// assert_eq!(iter.next(), Some(BITS_OF + 1));
+    /// assert_eq!(iter.next(), None);
     /// ```
-    fn get_lowest_set_bit_unchecked(&self) -> usize {
-        let level = find_lowest_set_bit(self.layer_cache);
-
-        //
-        // UNSAFE: level is guaranteed to be between 0 and SMALL_BIT_FIELD_SIZE - 1 by the
-        // the definition of find_lowest_set_bit. No need to perform bounds checking on the array.
-        //
-
-        unsafe {
-            let sub_field = self.bitfield.get_unchecked(level);
-            return (level * LARGE_BIT_FIELD_GROUP_COUNT) + find_lowest_set_bit(*sub_field);
+    pub fn iter_set_bits(&self) -> LargeBitFieldIter<'_, W> {
+        LargeBitFieldIter {
+            bitfield: self.bitfield.as_ref(),
+            remaining_groups: self.layer_cache,
+            front_group: 0,
+            front_bits: W::ZERO,
+            back_group: 0,
+            back_bits: W::ZERO,
         }
     }
 
-    /// Gets the highest set bit, guaranteed to have no branches and be in constant time, completely
-    /// invariant of the state of the bit field. If no bits are set, the result is undefined.
+    /// Gets whether or not a specific group in the bit field has any bits set.
     ///
-    /// This function should only be used if the caller can guarantee the bitfield will always
-    /// have at least one bit set.
+    /// # Arguments
+    /// group_index - Provides the group to test.
     ///
     /// # Returns
-    /// The highest set bit index or `UNDEFINED` if no bits are set.
+    /// `true` if the group has any bits set.
+    /// `false` if the group as no bits set.
     ///
-    /// # Examples
+    /// # Unsafe
+    /// This unsafe variant does not check if the group_index is valid for the size of
+    /// the bit field. The caller must guarantee that group_index is within the number of
+    /// groups in the bit field.
+    ///
+    /// # Examples
     /// ```
     /// use fast_bitfield::{FastBitField, LargeBitField};
     /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
@@ -465,79 +1024,211 @@ impl FastBitField for LargeBitField {
     /// let clear_value = [core::usize::MAX; BITS_OF];
     /// large.clear_field(&clear_value);
     ///
-    /// large.set_bit(7);
-    /// assert_eq!(large.get_highest_set_bit_unchecked(), 7);
+    /// unsafe {
+    ///     assert_eq!(large.test_group_unchecked(0), false);
     ///
-    /// large.set_bit(9);
-    /// assert_eq!(large.get_highest_set_bit_unchecked(), 9);
+    ///     large.set_bit_unchecked(2);
+    ///     assert_eq!(large.test_group_unchecked(0), true);
+    /// }
     /// ```
-    fn get_highest_set_bit_unchecked(&self) -> usize {
-        let level = find_highest_set_bit(self.layer_cache);
+    pub unsafe fn test_group_unchecked(&self, group_index: usize) -> bool {
+        check_unchecked_index("test_group_unchecked", group_index, W::BITS);
+
+        (self.layer_cache & (W::ONE << group_index)) != W::ZERO
+    }
+
+    /// Sets bits in a specific group in the bit field.
+    ///
+    /// # Arguments
+    /// group_index - Provides the group within the bit field to set.
+    /// group_field - Provides the bits to set within the group.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the group_index is valid for the size of
+    /// the bit field. The caller must guarantee that group_index is within the number of
+    /// groups in the bit field.
+    pub unsafe fn set_group_unchecked(&mut self, group_index: usize, group_field: W) {
+        check_unchecked_index("set_group_unchecked", group_index, W::BITS);
 
         //
-        // UNSAFE: level is guaranteed to be between 0 and SMALL_BIT_FIELD_SIZE - 1 by the
-        // the definition of find_highest_set_bit. No need to perform bounds checking on the array.
+        // Turn boolean into a word to avoid branching.
         //
 
-        unsafe {
-            let sub_field = self.bitfield.get_unchecked(level);
-            return (level * LARGE_BIT_FIELD_GROUP_COUNT) + find_highest_set_bit(*sub_field);
-        }
+        let field_has_values = W::from_bool(group_field != W::ZERO);
+        let layer_cache_update = field_has_values << group_index;
+
+        let subfield = self.bitfield.as_mut().get_unchecked_mut(group_index);
+        *subfield |= group_field;
+
+        let is_full = W::from_bool(*subfield == W::MAX);
+        let full_cache_update = is_full << group_index;
+
+        self.layer_cache |= layer_cache_update;
+        self.full_cache |= full_cache_update;
+        self.check_caches("set_group_unchecked");
     }
 
-    /// Sets a bit in the bit field.
+    /// Clears bits in a specific group in the bit field.
     ///
     /// # Arguments
-    /// index - Provides the bit to set.
+    /// group_index - Provides the group within the bit field to clear.
+    /// group_field - Provides the bits to clear within the group.
     ///
     /// # Unsafe
-    /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
-    unsafe fn set_bit_unchecked(&mut self, index: usize) {
-        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
-        let bottom_layer = index % LARGE_BIT_FIELD_GROUP_COUNT;
+    /// This unsafe variant does not check if the group_index is valid for the size of
+    /// the bit field. The caller must guarantee that group_index is within the number of
+    /// groups in the bit field.
+    pub unsafe fn clear_group_unchecked(&mut self, group_index: usize, group_field: W) {
+        check_unchecked_index("clear_group_unchecked", group_index, W::BITS);
 
-        self.layer_cache |= 1 << top_layer;
-        let sub_field = self.bitfield.get_unchecked_mut(top_layer);
-        *sub_field |= 1 << bottom_layer;
+        let subfield = self.bitfield.as_mut().get_unchecked_mut(group_index);
+        *subfield &= !group_field;
+
+        //
+        // Turn boolean into a word to avoid branching.
+        //
+
+        let is_clear = W::from_bool(*subfield == W::ZERO);
+        let layer_cache_update = is_clear << group_index;
+        self.layer_cache &= !layer_cache_update;
+
+        let is_not_full = W::from_bool(*subfield != W::MAX);
+        let full_cache_update = is_not_full << group_index;
+        self.full_cache &= !full_cache_update;
+        self.check_caches("clear_group_unchecked");
     }
 
-    /// Clears a bit in the bit field
+    /// Recalculates the layer cache and full cache entries of a group from its current value.
     ///
     /// # Arguments
-    /// index - Provides the bit to clear.
+    /// group_index - Provides the group to recalculate.
     ///
     /// # Unsafe
-    /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
-    unsafe fn clear_bit_unchecked(&mut self, index: usize) {
-        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
-        let bottom_layer = index % LARGE_BIT_FIELD_GROUP_COUNT;
+    /// This unsafe variant does not check if the group_index is valid for the size of
+    /// the bit field. The caller must guarantee that group_index is within the number of
+    /// groups in the bit field.
+    unsafe fn update_group_caches(&mut self, group_index: usize) {
+        check_unchecked_index("update_group_caches", group_index, W::BITS);
 
-        let sub_field = self.bitfield.get_unchecked_mut(top_layer);
-        *sub_field &= !(1 << bottom_layer);
+        let subfield = *self.bitfield.as_ref().get_unchecked(group_index);
+        let group_mask = W::ONE << group_index;
 
         //
-        // Turn boolean into a usize to avoid branching.
+        // Turn booleans into words to avoid branching.
         //
 
-        let is_clear = (*sub_field == 0) as usize;
-        let layer_cache_update = (1 << top_layer) * is_clear;
-        self.layer_cache &= !layer_cache_update
+        let has_values = W::from_bool(subfield != W::ZERO);
+        let is_full = W::from_bool(subfield == W::MAX);
+
+        self.layer_cache = (self.layer_cache & !group_mask) | (has_values << group_index);
+        self.full_cache = (self.full_cache & !group_mask) | (is_full << group_index);
+        self.check_caches("update_group_caches");
     }
 
-    /// Gets the value of a specific bit in the bit field.
+    /// Panics if the layer cache or full cache does not match the groups. The check is only
+    /// performed when the `checked` feature is enabled.
     ///
     /// # Arguments
-    /// index - Provides the bit to test.
+    /// operation - Provides the name of the operation that last modified the bit field.
+    #[inline(always)]
+    fn check_caches(&self, operation: &str) {
+        #[cfg(feature = "checked")]
+        if let Err(groups) = self.verify_invariants() {
+            let group_index = groups.find_lowest_set_bit();
+            panic!(
+                "LargeBitField caches are out of sync after {}: group {} is {:#x} but the layer \
+                 cache bit is {} and the full cache bit is {}",
+                operation,
+                group_index,
+                self.bitfield[group_index],
+                (self.layer_cache & (W::ONE << group_index)) != W::ZERO,
+                (self.full_cache & (W::ONE << group_index)) != W::ZERO
+            );
+        }
+
+        #[cfg(not(feature = "checked"))]
+        let _ = operation;
+    }
+}
+
+/// Defines the FixedBitField interface for LargeBitField.
+impl<W: BitWord> FixedBitField for LargeBitField<W> {
+    /// Defines the number of bits in a LargeBitField.
+    const BITS: usize = W::BITS * W::BITS;
+}
+
+/// Defines the FastBitField interface for LargeBitField.
+impl<W: BitWord> FastBitField for LargeBitField<W> {
+    /// Creates a new, empty LargeBitField
     ///
     /// # Returns
-    /// `true` if bit is set.
-    /// `false` if bit is cleared.
+    /// A LargeBitField.
+    fn new() -> Self {
+        LargeBitField {
+            layer_cache: W::ZERO,
+            full_cache: W::ZERO,
+            bitfield: W::EMPTY_GROUPS,
+        }
+    }
+
+    /// Gets the number of bits available in the bitfield.
     ///
-    /// # Unsafe
-    /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// # Returns
+    /// The number of bits available, which is always `get_number_of_bits()`.
+    fn capacity(&self) -> usize {
+        Self::BITS
+    }
+
+    /// Sets a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    fn set_bit(&mut self, index: usize) {
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        let sub_field = self.bitfield.as_mut().get_mut(top_layer);
+        let sub_field = match sub_field {
+            Some(s) => s,
+            None => return,
+        };
+
+        self.layer_cache |= W::ONE << top_layer;
+        *sub_field |= W::ONE << bottom_layer;
+        if *sub_field == W::MAX {
+            self.full_cache |= W::ONE << top_layer;
+        }
+
+        self.check_caches("set_bit");
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    fn clear_bit(&mut self, index: usize) {
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        let sub_field = self.bitfield.as_mut().get_mut(top_layer);
+        let sub_field = match sub_field {
+            Some(s) => s,
+            None => return,
+        };
+
+        *sub_field &= !(W::ONE << bottom_layer);
+        self.full_cache &= !(W::ONE << top_layer);
+        if *sub_field == W::ZERO {
+            self.layer_cache &= !(W::ONE << top_layer);
+        }
+
+        self.check_caches("clear_bit");
+    }
+
+    /// Gets the lowest set bit.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `None` if no bits are set.
     ///
     /// # Examples
     /// ```
@@ -548,583 +1239,2829 @@ impl FastBitField for LargeBitField {
     /// let clear_value = [core::usize::MAX; BITS_OF];
     /// large.clear_field(&clear_value);
     ///
-    /// unsafe {
-    ///     assert_eq!(large.test_bit_unchecked(10), false);
+    /// assert_eq!(large.get_lowest_set_bit(), None);
     ///
-    ///     large.set_bit_unchecked(10);
-    ///     assert_eq!(large.test_bit_unchecked(10), true);
-    /// }
+    /// large.set_bit(7);
+    /// assert_eq!(large.get_lowest_set_bit(), Some(7));
+    ///
+    /// large.set_bit(9);
+    /// assert_eq!(large.get_lowest_set_bit(), Some(7));
     /// ```
-    unsafe fn test_bit_unchecked(&self, index: usize) -> bool {
-        let top_layer = index / LARGE_BIT_FIELD_GROUP_COUNT;
-        let bottom_mask = 1 << (index % LARGE_BIT_FIELD_GROUP_COUNT);
+    fn get_lowest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
 
-        let sub_field = self.bitfield.get_unchecked(top_layer);
-        (*sub_field & bottom_mask) != 0
+        Some(self.get_lowest_set_bit_unchecked())
     }
-}
 
-//
-// Unit Tests
-//
+    /// Gets the highest set bit.
+    ///
+    /// # Returns
+    /// The highest set bit index or `None` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// let clear_value = [core::usize::MAX; BITS_OF];
+    /// large.clear_field(&clear_value);
+    ///
+    /// assert_eq!(large.get_highest_set_bit(), None);
+    ///
+    /// large.set_bit(7);
+    /// assert_eq!(large.get_highest_set_bit(), Some(7));
+    ///
+    /// large.set_bit(9);
+    /// assert_eq!(large.get_highest_set_bit(), Some(9));
+    /// ```
+    fn get_highest_set_bit(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        Some(self.get_highest_set_bit_unchecked())
+    }
 
-    //
-    // Constructor Test
-    //
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_bit(4);
+    /// large.set_bit(BITS_OF * 3);
+    ///
+    /// assert_eq!(large.get_next_set_bit(0), Some(4));
+    /// assert_eq!(large.get_next_set_bit(4), Some(4));
+    /// assert_eq!(large.get_next_set_bit(5), Some(BITS_OF * 3));
+    /// assert_eq!(large.get_next_set_bit(BITS_OF * 3 + 1), None);
+    /// assert_eq!(large.get_next_set_bit(core::usize::MAX), None);
+    /// ```
+    fn get_next_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= Self::BITS {
+            return None;
+        }
 
-    #[test]
-    fn create_defaults_to_empty() {
-        let large = LargeBitField::new();
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
 
-        assert_eq!(large.layer_cache, 0);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], 0);
-        }
+        //
+        // Check the remainder of the group containing index first, then use the layer cache to
+        // jump to the next group with any bits set.
+        //
 
-        assert!(large.is_empty());
-    }
+        let sub_field = self.bitfield[top_layer] & (W::MAX << bottom_layer);
+        if sub_field != W::ZERO {
+            return Some((top_layer * W::BITS) + sub_field.find_lowest_set_bit());
+        }
 
-    //
-    // Trait Tests
-    //
+        let groups = self.layer_cache & ((W::MAX << top_layer) << 1);
+        if groups == W::ZERO {
+            return None;
+        }
 
-    #[test]
-    fn number_of_bits() {
-        assert_eq!(
-            LargeBitField::get_number_of_bits(),
-            LARGE_BIT_FIELD_BIT_SIZE
-        );
+        let level = groups.find_lowest_set_bit();
+        Some((level * W::BITS) + self.bitfield[level].find_lowest_set_bit())
     }
 
-    #[test]
-    fn validate_set_bit() {
-        let mut large = LargeBitField::new();
-        let mut large_unsafe = LargeBitField::new();
-        let mut expected_toplayer = 0 as usize;
-        let mut expected_bitfield = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_bit(4);
+    /// large.set_bit(BITS_OF * 3);
+    ///
+    /// assert_eq!(large.get_previous_set_bit(BITS_OF * 4), Some(BITS_OF * 3));
+    /// assert_eq!(large.get_previous_set_bit(BITS_OF * 3), Some(BITS_OF * 3));
+    /// assert_eq!(large.get_previous_set_bit(BITS_OF * 3 - 1), Some(4));
+    /// assert_eq!(large.get_previous_set_bit(3), None);
+    /// assert_eq!(large.get_previous_set_bit(core::usize::MAX), None);
+    /// ```
+    fn get_previous_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= Self::BITS {
+            return None;
+        }
 
-        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
-            //
-            // Out of bounds set should do nothing.
-            //
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
 
-            large.set_bit(LARGE_BIT_FIELD_BIT_SIZE);
-            assert_eq!(large.layer_cache, expected_toplayer);
-            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                assert_eq!(large.bitfield[index], expected_bitfield[index]);
-            }
+        //
+        // Check the beginning of the group containing index first, then use the layer cache to
+        // jump to the previous group with any bits set.
+        //
 
-            let active_group = i / LARGE_BIT_FIELD_GROUP_COUNT;
-            expected_toplayer |= 1 << active_group;
-            expected_bitfield[active_group] |= 1 << (i % LARGE_BIT_FIELD_GROUP_COUNT);
+        let sub_field = self.bitfield[top_layer] & (W::MAX >> (W::BITS - 1 - bottom_layer));
 
-            large.set_bit(i);
-            assert_eq!(large.layer_cache, expected_toplayer);
-            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                assert_eq!(large.bitfield[index], expected_bitfield[index]);
-            }
+        if sub_field != W::ZERO {
+            return Some((top_layer * W::BITS) + sub_field.find_highest_set_bit());
+        }
+
+        let groups = self.layer_cache & ((W::MAX >> (W::BITS - 1 - top_layer)) >> 1);
+
+        if groups == W::ZERO {
+            return None;
+        }
+
+        let level = groups.find_highest_set_bit();
+        Some((level * W::BITS) + self.bitfield[level].find_highest_set_bit())
+    }
 
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// let clear_value = [core::usize::MAX; BITS_OF];
+    /// large.clear_field(&clear_value);
+    ///
+    /// assert_eq!(large.test_bit(core::usize::MAX), None);
+    /// assert_eq!(large.test_bit(10), Some(false));
+    ///
+    /// large.set_bit(10);
+    /// assert_eq!(large.test_bit(10), Some(true));
+    /// ```
+    fn test_bit(&self, index: usize) -> Option<bool> {
+        if index < Self::BITS {
             //
-            // Calling set for an already set bit should result in no change.
+            // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+            // calling it.
             //
 
-            large.set_bit(i);
-            assert_eq!(large.layer_cache, expected_toplayer);
-            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            unsafe {
+                return Some(self.test_bit_unchecked(index));
+            }
+        }
+
+        None
+    }
+
+    /// Determines whether or not the bitfield is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    ///
+    /// let clear_value = [core::usize::MAX; BITS_OF];
+    ///
+    /// large.clear_field(&clear_value);
+    /// assert!(large.is_empty());
+    ///
+    /// large.set_bit(0);
+    /// assert!(!large.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.layer_cache == W::ZERO
+    }
+
+    /// Determines whether or not every bit in the bitfield is set.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_field(&[core::usize::MAX; BITS_OF]);
+    /// assert!(large.is_full());
+    ///
+    /// large.clear_bit(0);
+    /// assert!(!large.is_full());
+    /// ```
+    fn is_full(&self) -> bool {
+        self.full_cache == W::MAX
+    }
+
+    /// Gets the number of set bits in the bitfield. Only groups with bits set are visited.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let mut large = LargeBitField::new();
+    /// assert_eq!(large.count_set_bits(), 0);
+    ///
+    /// large.set_bit(1);
+    /// large.set_group(3, 0b1011);
+    /// assert_eq!(large.count_set_bits(), 4);
+    /// ```
+    fn count_set_bits(&self) -> usize {
+        let mut count = 0;
+        let mut groups = self.layer_cache;
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+            count += self.bitfield[index].count_set_bits();
+        }
+
+        count
+    }
+
+    /// Gets the number of set bits below a given index. Only groups with bits set are visited.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_bit(1);
+    /// large.set_bit(BITS_OF + 1);
+    ///
+    /// assert_eq!(large.rank(1), 0);
+    /// assert_eq!(large.rank(BITS_OF + 1), 1);
+    /// assert_eq!(large.rank(core::usize::MAX), 2);
+    /// ```
+    fn rank(&self, index: usize) -> usize {
+        if index >= Self::BITS {
+            return self.count_set_bits();
+        }
+
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        let mut count = (self.bitfield[top_layer] & !(W::MAX << bottom_layer)).count_set_bits();
+        let mut groups = self.layer_cache & !(W::MAX << top_layer);
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+            count += self.bitfield[index].count_set_bits();
+        }
+
+        count
+    }
+
+    /// Gets the set bit with a given rank. Only groups with bits set are visited.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_bit(1);
+    /// large.set_bit(BITS_OF + 1);
+    ///
+    /// assert_eq!(large.select(0), Some(1));
+    /// assert_eq!(large.select(1), Some(BITS_OF + 1));
+    /// assert_eq!(large.select(2), None);
+    /// ```
+    fn select(&self, rank: usize) -> Option<usize> {
+        let mut rank = rank;
+        let mut groups = self.layer_cache;
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+
+            let sub_field = self.bitfield[index];
+            let count = sub_field.count_set_bits();
+            if rank < count {
+                return Some((index * W::BITS) + sub_field.find_set_bit_with_rank(rank));
             }
 
-            unsafe {
-                large_unsafe.set_bit_unchecked(i);
-                assert_eq!(large.layer_cache, expected_toplayer);
-                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
-                }
+            rank -= count;
+        }
+
+        None
+    }
+
+    /// Gets the lowest clear bit.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_field(&[core::usize::MAX; BITS_OF]);
+    ///
+    /// assert_eq!(large.get_lowest_clear_bit(), None);
+    ///
+    /// large.clear_bit(BITS_OF + 7);
+    /// assert_eq!(large.get_lowest_clear_bit(), Some(BITS_OF + 7));
+    ///
+    /// large.clear_bit(BITS_OF * 2);
+    /// assert_eq!(large.get_lowest_clear_bit(), Some(BITS_OF + 7));
+    /// ```
+    fn get_lowest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_lowest_clear_bit_unchecked())
+    }
+
+    /// Gets the highest clear bit.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_field(&[core::usize::MAX; BITS_OF]);
+    ///
+    /// assert_eq!(large.get_highest_clear_bit(), None);
+    ///
+    /// large.clear_bit(BITS_OF + 7);
+    /// assert_eq!(large.get_highest_clear_bit(), Some(BITS_OF + 7));
+    ///
+    /// large.clear_bit(BITS_OF * 2);
+    /// assert_eq!(large.get_highest_clear_bit(), Some(BITS_OF * 2));
+    /// ```
+    fn get_highest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_highest_clear_bit_unchecked())
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_range(0..(BITS_OF * 3) + 1);
+    ///
+    /// assert_eq!(large.get_next_clear_bit(0), Some((BITS_OF * 3) + 1));
+    /// assert_eq!(large.get_next_clear_bit(BITS_OF * 5), Some(BITS_OF * 5));
+    /// assert_eq!(large.get_next_clear_bit(core::usize::MAX), None);
+    /// ```
+    fn get_next_clear_bit(&self, index: usize) -> Option<usize> {
+        if index >= Self::BITS {
+            return None;
+        }
+
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        //
+        // Check the remainder of the group containing index first, then use the full cache to
+        // jump to the next group with any bits clear.
+        //
+
+        let sub_field = !self.bitfield[top_layer] & (W::MAX << bottom_layer);
+        if sub_field != W::ZERO {
+            return Some((top_layer * W::BITS) + sub_field.find_lowest_set_bit());
+        }
+
+        let groups = !self.full_cache & ((W::MAX << top_layer) << 1);
+        if groups == W::ZERO {
+            return None;
+        }
+
+        let level = groups.find_lowest_set_bit();
+        Some((level * W::BITS) + (!self.bitfield[level]).find_lowest_set_bit())
+    }
+
+    /// Gets the lowest set bit, guaranteed to have no branches and be in constant time, completely
+    /// invariant of the state of the bit field. If no bits are set, the result is undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit set.
+    ///
+    /// # Returns
+    /// The lowest set bit index or `UNDEFINED` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// let clear_value = [core::usize::MAX; BITS_OF];
+    /// large.clear_field(&clear_value);
+    ///
+    /// large.set_bit(7);
+    /// assert_eq!(large.get_lowest_set_bit_unchecked(), 7);
+    ///
+    /// large.set_bit(9);
+    /// assert_eq!(large.get_lowest_set_bit_unchecked(), 7);
+    /// ```
+    fn get_lowest_set_bit_unchecked(&self) -> usize {
+        let level = self.layer_cache.find_lowest_set_bit();
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and SMALL_BIT_FIELD_SIZE - 1 by the
+        // the definition of find_lowest_set_bit. No need to perform bounds checking on the array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.as_ref().get_unchecked(level);
+            return (level * W::BITS) + (*sub_field).find_lowest_set_bit();
+        }
+    }
+
+    /// Gets the highest set bit, guaranteed to have no branches and be in constant time, completely
+    /// invariant of the state of the bit field. If no bits are set, the result is undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit set.
+    ///
+    /// # Returns
+    /// The highest set bit index or `UNDEFINED` if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// let clear_value = [core::usize::MAX; BITS_OF];
+    /// large.clear_field(&clear_value);
+    ///
+    /// large.set_bit(7);
+    /// assert_eq!(large.get_highest_set_bit_unchecked(), 7);
+    ///
+    /// large.set_bit(9);
+    /// assert_eq!(large.get_highest_set_bit_unchecked(), 9);
+    /// ```
+    fn get_highest_set_bit_unchecked(&self) -> usize {
+        let level = self.layer_cache.find_highest_set_bit();
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and SMALL_BIT_FIELD_SIZE - 1 by the
+        // the definition of find_highest_set_bit. No need to perform bounds checking on the array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.as_ref().get_unchecked(level);
+            return (level * W::BITS) + (*sub_field).find_highest_set_bit();
+        }
+    }
+
+    /// Gets the lowest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `UNDEFINED` if every bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let mut large = LargeBitField::new();
+    ///
+    /// large.set_bit(0);
+    /// assert_eq!(large.get_lowest_clear_bit_unchecked(), 1);
+    ///
+    /// large.set_bit(1);
+    /// assert_eq!(large.get_lowest_clear_bit_unchecked(), 2);
+    /// ```
+    fn get_lowest_clear_bit_unchecked(&self) -> usize {
+        //
+        // Setting the highest group in the scan leaves the result unchanged while any group has a
+        // clear bit, and keeps level in range when every group is full.
+        //
+
+        let level = (!self.full_cache | (W::ONE << (W::BITS - 1))).find_lowest_set_bit();
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and W::BITS - 1 since the scanned value
+        // always has a bit set. No need to perform bounds checking on the array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.as_ref().get_unchecked(level);
+            (level * W::BITS) + (!*sub_field).find_lowest_set_bit()
+        }
+    }
+
+    /// Gets the highest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_highest_clear_bit_unchecked(&self) -> usize {
+        //
+        // Setting the lowest group in the scans leaves the result unchanged while any group has a
+        // clear bit, and keeps level in range when every group is full.
+        //
+
+        let level = (!self.full_cache | W::ONE).find_highest_set_bit();
+
+        //
+        // UNSAFE: level is guaranteed to be between 0 and W::BITS - 1 since the scanned value
+        // always has a bit set. No need to perform bounds checking on the array.
+        //
+
+        unsafe {
+            let sub_field = self.bitfield.as_ref().get_unchecked(level);
+            (level * W::BITS) + (!*sub_field | W::ONE).find_highest_set_bit()
+        }
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `UNDEFINED` if no such bit is
+    /// set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or after the index. The caller must guarantee that the index is less
+    /// than `capacity()` and that a bit at or after the index is set.
+    unsafe fn get_next_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index("get_next_set_bit_unchecked", index, Self::BITS);
+
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        let sub_field = *self.bitfield.as_ref().get_unchecked(top_layer) & (W::MAX << bottom_layer);
+        if sub_field != W::ZERO {
+            return (top_layer * W::BITS) + sub_field.find_lowest_set_bit();
+        }
+
+        let groups = self.layer_cache & ((W::MAX << top_layer) << 1);
+        let level = groups.find_lowest_set_bit();
+        let sub_field = self.bitfield.as_ref().get_unchecked(level);
+        (level * W::BITS) + (*sub_field).find_lowest_set_bit()
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `UNDEFINED` if no such bit is set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or before the index. The caller must guarantee that the index is
+    /// less than `capacity()` and that a bit at or before the index is set.
+    unsafe fn get_previous_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index("get_previous_set_bit_unchecked", index, Self::BITS);
+
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        let sub_field = *self.bitfield.as_ref().get_unchecked(top_layer)
+            & (W::MAX >> (W::BITS - 1 - bottom_layer));
+
+        if sub_field != W::ZERO {
+            return (top_layer * W::BITS) + sub_field.find_highest_set_bit();
+        }
+
+        let groups = self.layer_cache & ((W::MAX >> (W::BITS - 1 - top_layer)) >> 1);
+
+        let level = groups.find_highest_set_bit();
+        let sub_field = self.bitfield.as_ref().get_unchecked(level);
+        (level * W::BITS) + (*sub_field).find_highest_set_bit()
+    }
+
+    /// Sets a bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn set_bit_unchecked(&mut self, index: usize) {
+        check_unchecked_index("set_bit_unchecked", index, Self::BITS);
+
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        self.layer_cache |= W::ONE << top_layer;
+        let sub_field = self.bitfield.as_mut().get_unchecked_mut(top_layer);
+        *sub_field |= W::ONE << bottom_layer;
+
+        //
+        // Turn boolean into a word to avoid branching.
+        //
+
+        let is_full = W::from_bool(*sub_field == W::MAX);
+        let full_cache_update = is_full << top_layer;
+        self.full_cache |= full_cache_update;
+        self.check_caches("set_bit_unchecked");
+    }
+
+    /// Clears a bit in the bit field
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    unsafe fn clear_bit_unchecked(&mut self, index: usize) {
+        check_unchecked_index("clear_bit_unchecked", index, Self::BITS);
+
+        let top_layer = index / W::BITS;
+        let bottom_layer = index % W::BITS;
+
+        let sub_field = self.bitfield.as_mut().get_unchecked_mut(top_layer);
+        *sub_field &= !(W::ONE << bottom_layer);
+        self.full_cache &= !(W::ONE << top_layer);
+
+        //
+        // Turn boolean into a word to avoid branching.
+        //
+
+        let is_clear = W::from_bool(*sub_field == W::ZERO);
+        let layer_cache_update = is_clear << top_layer;
+        self.layer_cache &= !layer_cache_update;
+        self.check_caches("clear_bit_unchecked");
+    }
+
+    /// Gets the value of a specific bit in the bit field.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `true` if bit is set.
+    /// `false` if bit is cleared.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    /// const BITS_OF: usize = core::mem::size_of::<usize>() * 8;
+    ///
+    /// let mut large = LargeBitField::new();
+    /// let clear_value = [core::usize::MAX; BITS_OF];
+    /// large.clear_field(&clear_value);
+    ///
+    /// unsafe {
+    ///     assert_eq!(large.test_bit_unchecked(10), false);
+    ///
+    ///     large.set_bit_unchecked(10);
+    ///     assert_eq!(large.test_bit_unchecked(10), true);
+    /// }
+    /// ```
+    unsafe fn test_bit_unchecked(&self, index: usize) -> bool {
+        check_unchecked_index("test_bit_unchecked", index, Self::BITS);
+
+        let top_layer = index / W::BITS;
+        let bottom_mask = W::ONE << (index % W::BITS);
+
+        let sub_field = self.bitfield.as_ref().get_unchecked(top_layer);
+        (*sub_field & bottom_mask) != W::ZERO
+    }
+}
+
+/// Defines the in place union of two LargeBitFields.
+impl<'a, W: BitWord> BitOrAssign<&'a LargeBitField<W>> for LargeBitField<W> {
+    /// Sets every bit that is set in rhs. Only groups with bits set in rhs are visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitor_assign(&mut self, rhs: &'a LargeBitField<W>) {
+        let mut groups = rhs.layer_cache;
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+
+            //
+            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
+            //
+
+            unsafe {
+                self.set_group_unchecked(index, rhs.bitfield[index]);
+            }
+        }
+    }
+}
+
+/// Defines the in place union of two LargeBitFields.
+impl<W: BitWord> BitOrAssign for LargeBitField<W> {
+    /// Sets every bit that is set in rhs. Only groups with bits set in rhs are visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitor_assign(&mut self, rhs: LargeBitField<W>) {
+        *self |= &rhs;
+    }
+}
+
+/// Defines the union of two LargeBitFields.
+impl<'a, W: BitWord> BitOr<&'a LargeBitField<W>> for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in either bitfield.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the union.
+    fn bitor(mut self, rhs: &'a LargeBitField<W>) -> LargeBitField<W> {
+        self |= rhs;
+        self
+    }
+}
+
+/// Defines the union of two LargeBitFields.
+impl<W: BitWord> BitOr for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in either bitfield.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the union.
+    fn bitor(mut self, rhs: LargeBitField<W>) -> LargeBitField<W> {
+        self |= &rhs;
+        self
+    }
+}
+
+/// Defines the in place intersection of two LargeBitFields.
+impl<'a, W: BitWord> BitAndAssign<&'a LargeBitField<W>> for LargeBitField<W> {
+    /// Clears every bit that is not set in rhs. Only groups with bits set in this bitfield are
+    /// visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitand_assign(&mut self, rhs: &'a LargeBitField<W>) {
+        let mut groups = self.layer_cache;
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+
+            //
+            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
+            //
+
+            unsafe {
+                self.clear_group_unchecked(index, !rhs.bitfield[index]);
+            }
+        }
+    }
+}
+
+/// Defines the in place intersection of two LargeBitFields.
+impl<W: BitWord> BitAndAssign for LargeBitField<W> {
+    /// Clears every bit that is not set in rhs. Only groups with bits set in this bitfield are
+    /// visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitand_assign(&mut self, rhs: LargeBitField<W>) {
+        *self &= &rhs;
+    }
+}
+
+/// Defines the intersection of two LargeBitFields.
+impl<'a, W: BitWord> BitAnd<&'a LargeBitField<W>> for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in both bitfields.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the intersection.
+    fn bitand(mut self, rhs: &'a LargeBitField<W>) -> LargeBitField<W> {
+        self &= rhs;
+        self
+    }
+}
+
+/// Defines the intersection of two LargeBitFields.
+impl<W: BitWord> BitAnd for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in both bitfields.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the intersection.
+    fn bitand(mut self, rhs: LargeBitField<W>) -> LargeBitField<W> {
+        self &= &rhs;
+        self
+    }
+}
+
+/// Defines the in place symmetric difference of two LargeBitFields.
+impl<'a, W: BitWord> BitXorAssign<&'a LargeBitField<W>> for LargeBitField<W> {
+    /// Toggles every bit that is set in rhs. Only groups with bits set in either bitfield are
+    /// visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitxor_assign(&mut self, rhs: &'a LargeBitField<W>) {
+        let mut groups = self.layer_cache | rhs.layer_cache;
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+            self.bitfield[index] ^= rhs.bitfield[index];
+
+            //
+            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
+            //
+
+            unsafe {
+                self.update_group_caches(index);
+            }
+        }
+    }
+}
+
+/// Defines the in place symmetric difference of two LargeBitFields.
+impl<W: BitWord> BitXorAssign for LargeBitField<W> {
+    /// Toggles every bit that is set in rhs. Only groups with bits set in either bitfield are
+    /// visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitxor_assign(&mut self, rhs: LargeBitField<W>) {
+        *self ^= &rhs;
+    }
+}
+
+/// Defines the symmetric difference of two LargeBitFields.
+impl<'a, W: BitWord> BitXor<&'a LargeBitField<W>> for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in exactly one of the bitfields.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the symmetric difference.
+    fn bitxor(mut self, rhs: &'a LargeBitField<W>) -> LargeBitField<W> {
+        self ^= rhs;
+        self
+    }
+}
+
+/// Defines the symmetric difference of two LargeBitFields.
+impl<W: BitWord> BitXor for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in exactly one of the bitfields.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the symmetric difference.
+    fn bitxor(mut self, rhs: LargeBitField<W>) -> LargeBitField<W> {
+        self ^= &rhs;
+        self
+    }
+}
+
+/// Defines the in place difference of two LargeBitFields.
+impl<'a, W: BitWord> SubAssign<&'a LargeBitField<W>> for LargeBitField<W> {
+    /// Clears every bit that is set in rhs. Only groups with bits set in both bitfields are
+    /// visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn sub_assign(&mut self, rhs: &'a LargeBitField<W>) {
+        let mut groups = self.layer_cache & rhs.layer_cache;
+        while groups != W::ZERO {
+            let index = groups.find_lowest_set_bit();
+            groups = groups.clear_lowest_set_bit();
+
+            //
+            // UNSAFE: index is guaranteed to be less than the number of groups in the bitfield.
+            //
+
+            unsafe {
+                self.clear_group_unchecked(index, rhs.bitfield[index]);
+            }
+        }
+    }
+}
+
+/// Defines the in place difference of two LargeBitFields.
+impl<W: BitWord> SubAssign for LargeBitField<W> {
+    /// Clears every bit that is set in rhs. Only groups with bits set in both bitfields are
+    /// visited.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn sub_assign(&mut self, rhs: LargeBitField<W>) {
+        *self -= &rhs;
+    }
+}
+
+/// Defines the difference of two LargeBitFields.
+impl<'a, W: BitWord> Sub<&'a LargeBitField<W>> for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in this bitfield but not in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the difference.
+    fn sub(mut self, rhs: &'a LargeBitField<W>) -> LargeBitField<W> {
+        self -= rhs;
+        self
+    }
+}
+
+/// Defines the difference of two LargeBitFields.
+impl<W: BitWord> Sub for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits set in this bitfield but not in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the difference.
+    fn sub(mut self, rhs: LargeBitField<W>) -> LargeBitField<W> {
+        self -= &rhs;
+        self
+    }
+}
+
+/// Defines the complement of a LargeBitField<W>.
+impl<W: BitWord> Not for LargeBitField<W> {
+    type Output = LargeBitField<W>;
+
+    /// Gets the bits that are not set in the bitfield.
+    ///
+    /// # Returns
+    /// A LargeBitField<W> holding the complement.
+    fn not(mut self) -> LargeBitField<W> {
+        for index in 0..W::BITS {
+            self.bitfield[index] = !self.bitfield[index];
+        }
+
+        //
+        // Groups that were full become empty and groups that were empty become full, so the
+        // caches swap roles.
+        //
+
+        let layer_cache = self.layer_cache;
+        self.layer_cache = !self.full_cache;
+        self.full_cache = !layer_cache;
+        self.check_caches("not");
+        self
+    }
+}
+
+/// Defines the default LargeBitField<W>.
+impl<W: BitWord> Default for LargeBitField<W> {
+    /// Creates a new, empty LargeBitField<W>
+    ///
+    /// # Returns
+    /// A LargeBitField.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Defines how a LargeBitField<W> is displayed for debugging.
+impl<W: BitWord> fmt::Debug for LargeBitField<W> {
+    /// Formats the set bits of the bitfield, collapsing runs of set bits into ranges.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the bitfield.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, LargeBitField};
+    ///
+    /// let mut large = LargeBitField::new();
+    /// large.set_range(60..70);
+    /// large.set_bit(1000);
+    ///
+    /// assert_eq!(format!("{:?}", large), "LargeBitField {60..=69, 1000}");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_set_bits(self, "LargeBitField", f)
+    }
+}
+
+/// Defines an iterator over the set bits of a LargeBitField.
+///
+/// The iterator loads one group at a time from each end, taking the next group to load from the
+/// layer cache so that empty groups are never visited.
+pub struct LargeBitFieldIter<'a, W: BitWord = usize> {
+    /// Holds the groups of the bitfield being iterated.
+    bitfield: &'a [W],
+
+    /// Holds a bitfield describing which non-empty groups have not been loaded yet.
+    remaining_groups: W,
+
+    /// Holds the group currently being yielded from the front.
+    front_group: usize,
+
+    /// Holds the bits of the front group that have not been yielded yet.
+    front_bits: W,
+
+    /// Holds the group currently being yielded from the back.
+    back_group: usize,
+
+    /// Holds the bits of the back group that have not been yielded yet.
+    back_bits: W,
+}
+
+/// Defines the Iterator interface for LargeBitFieldIter.
+impl<'a, W: BitWord> Iterator for LargeBitFieldIter<'a, W> {
+    type Item = usize;
+
+    /// Gets the lowest set bit that has not been yielded yet.
+    ///
+    /// # Returns
+    /// The lowest remaining set bit index or `None` if all set bits have been yielded.
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if self.front_bits != W::ZERO {
+                let index = self.front_bits.find_lowest_set_bit();
+                self.front_bits = self.front_bits.clear_lowest_set_bit();
+                return Some((self.front_group * W::BITS) + index);
+            }
+
+            if self.remaining_groups == W::ZERO {
+                break;
+            }
+
+            let group = self.remaining_groups.find_lowest_set_bit();
+            self.remaining_groups &= !(W::ONE << group);
+            self.front_group = group;
+            self.front_bits = self.bitfield[group];
+        }
+
+        //
+        // Every group has been loaded. Any remaining bits are held by the back group.
+        //
+
+        if self.back_bits == W::ZERO {
+            return None;
+        }
+
+        let index = self.back_bits.find_lowest_set_bit();
+        self.back_bits = self.back_bits.clear_lowest_set_bit();
+        Some((self.back_group * W::BITS) + index)
+    }
+}
+
+/// Defines the DoubleEndedIterator interface for LargeBitFieldIter.
+impl<'a, W: BitWord> DoubleEndedIterator for LargeBitFieldIter<'a, W> {
+    /// Gets the highest set bit that has not been yielded yet.
+    ///
+    /// # Returns
+    /// The highest remaining set bit index or `None` if all set bits have been yielded.
+    fn next_back(&mut self) -> Option<usize> {
+        loop {
+            if self.back_bits != W::ZERO {
+                let index = self.back_bits.find_highest_set_bit();
+                self.back_bits &= !(W::ONE << index);
+                return Some((self.back_group * W::BITS) + index);
+            }
+
+            if self.remaining_groups == W::ZERO {
+                break;
+            }
+
+            let group = self.remaining_groups.find_highest_set_bit();
+            self.remaining_groups &= !(W::ONE << group);
+            self.back_group = group;
+            self.back_bits = self.bitfield[group];
+        }
+
+        //
+        // Every group has been loaded. Any remaining bits are held by the front group.
+        //
+
+        if self.front_bits == W::ZERO {
+            return None;
+        }
+
+        let index = self.front_bits.find_highest_set_bit();
+        self.front_bits &= !(W::ONE << index);
+        Some((self.front_group * W::BITS) + index)
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //
+    // Constructor Test
+    //
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let large = LargeBitField::new();
+
+        assert_eq!(large.layer_cache, 0);
+        assert_eq!(large.full_cache, 0);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], 0);
+        }
+
+        assert!(large.is_empty());
+    }
+
+    //
+    // Trait Tests
+    //
+
+    #[test]
+    fn number_of_bits() {
+        assert_eq!(
+            LargeBitField::get_number_of_bits(),
+            LARGE_BIT_FIELD_BIT_SIZE
+        );
+
+        assert_eq!(
+            <LargeBitField as FixedBitField>::get_number_of_bits(),
+            LARGE_BIT_FIELD_BIT_SIZE
+        );
+        assert_eq!(LargeBitField::BITS, LARGE_BIT_FIELD_BIT_SIZE);
+        assert_eq!(LargeBitField::new().capacity(), LARGE_BIT_FIELD_BIT_SIZE);
+    }
+
+    #[test]
+    fn validate_set_bit() {
+        let mut large = LargeBitField::new();
+        let mut large_unsafe = LargeBitField::new();
+        let mut expected_toplayer = 0 as usize;
+        let mut expected_bitfield = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            //
+            // Out of bounds set should do nothing.
+            //
+
+            large.set_bit(LARGE_BIT_FIELD_BIT_SIZE);
+            assert_eq!(large.layer_cache, expected_toplayer);
+            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            }
+
+            let active_group = i / LARGE_BIT_FIELD_GROUP_COUNT;
+            expected_toplayer |= 1 << active_group;
+            expected_bitfield[active_group] |= 1 << (i % LARGE_BIT_FIELD_GROUP_COUNT);
+
+            large.set_bit(i);
+            assert_eq!(large.layer_cache, expected_toplayer);
+            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            }
+
+            //
+            // Calling set for an already set bit should result in no change.
+            //
+
+            large.set_bit(i);
+            assert_eq!(large.layer_cache, expected_toplayer);
+            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            }
+
+            unsafe {
+                large_unsafe.set_bit_unchecked(i);
+                assert_eq!(large.layer_cache, expected_toplayer);
+                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
+                }
+
+                //
+                // Calling set for an already set bit should result in no change.
+                //
+
+                large_unsafe.set_bit_unchecked(i);
+                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_clear_bit() {
+        let mut large = LargeBitField::new();
+        let mut large_unsafe = LargeBitField::new();
+        let mut expected_toplayer = core::usize::MAX;
+        let mut expected_bitfield = [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT];
+
+        large.layer_cache = core::usize::MAX;
+        large.full_cache = core::usize::MAX;
+        large.bitfield = [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT];
+        large_unsafe.layer_cache = core::usize::MAX;
+        large_unsafe.full_cache = core::usize::MAX;
+        large_unsafe.bitfield = [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT];
+
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            //
+            // Out of bounds set should do nothing.
+            //
+
+            large.clear_bit(LARGE_BIT_FIELD_BIT_SIZE);
+            assert_eq!(large.layer_cache, expected_toplayer);
+            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            }
+
+            let active_group = i / LARGE_BIT_FIELD_GROUP_COUNT;
+            expected_bitfield[active_group] &= !(1 << (i % LARGE_BIT_FIELD_GROUP_COUNT));
+            if expected_bitfield[active_group] == 0 {
+                expected_toplayer &= !(1 << active_group);
+            }
+
+            large.clear_bit(i);
+            assert_eq!(large.layer_cache, expected_toplayer);
+            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            }
+
+            //
+            // Calling clear for an already cleared bit should result in no change.
+            //
+
+            large.clear_bit(i);
+            assert_eq!(large.layer_cache, expected_toplayer);
+            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+            }
+
+            unsafe {
+                large_unsafe.clear_bit_unchecked(i);
+                assert_eq!(large.layer_cache, expected_toplayer);
+                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
+                }
+
+                //
+                // Calling clear for an already cleared bit should result in no change.
+                //
+
+                large_unsafe.clear_bit_unchecked(i);
+                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_get_lowest_set_bit() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Empty should result in None for checked variant
+        //
+
+        assert_eq!(large.get_lowest_set_bit(), None);
+
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            large.set_bit(i);
+            assert_eq!(large.get_lowest_set_bit(), Some(0));
+            assert_eq!(large.get_lowest_set_bit_unchecked(), 0);
+        }
+
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            assert_eq!(large.get_lowest_set_bit(), Some(i));
+            assert_eq!(large.get_lowest_set_bit_unchecked(), i);
+            large.clear_bit(i);
+        }
+    }
+
+    #[test]
+    fn validate_get_highest_set_bit() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Empty should result in None for checked variant
+        //
+
+        assert_eq!(large.get_highest_set_bit(), None);
+
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            large.set_bit(i);
+            assert_eq!(large.get_highest_set_bit(), Some(i));
+            assert_eq!(large.get_highest_set_bit_unchecked(), i);
+        }
+
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            assert_eq!(
+                large.get_highest_set_bit(),
+                Some(LARGE_BIT_FIELD_BIT_SIZE - 1)
+            );
+            assert_eq!(
+                large.get_highest_set_bit_unchecked(),
+                LARGE_BIT_FIELD_BIT_SIZE - 1
+            );
+            large.clear_bit(i);
+        }
+    }
+
+    #[test]
+    fn validate_test_bit() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Out of bounds should return None for checked variant
+        //
+
+        assert_eq!(large.test_bit(LARGE_BIT_FIELD_BIT_SIZE), None);
+
+        //
+        // Set causes test to return true.
+        //
+
+        large.set_bit(0);
+        assert_eq!(large.test_bit(0), Some(true));
+        unsafe {
+            assert_eq!(large.test_bit_unchecked(0), true);
+        }
+
+        //
+        // Clear causes test to return false.
+        //s
+
+        large.clear_bit(0);
+        assert_eq!(large.test_bit(0), Some(false));
+        unsafe {
+            assert_eq!(large.test_bit_unchecked(0), false);
+        }
+
+        //
+        // Changing another bit has no affect on the bit being tested.
+        //
+
+        large.set_bit(1);
+        assert_eq!(large.test_bit(0), Some(false));
+        unsafe {
+            assert_eq!(large.test_bit_unchecked(0), false);
+        }
+
+        //
+        // Clear causes test to return false.
+        //
+
+        large.set_bit(0);
+        large.clear_bit(1);
+        assert_eq!(large.test_bit(0), Some(true));
+        unsafe {
+            assert_eq!(large.test_bit_unchecked(0), true);
+        }
+    }
+
+    //
+    // Method Tests
+    //
+
+    #[test]
+    fn validate_set_and_clear_field() {
+        let mut large = LargeBitField::new();
+        let mut expected_toplayer: usize = 0;
+        let mut expected_bitfield = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+
+        let zeros = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+        let fives =
+            [(0x55555555_55555555 & core::usize::MAX) as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+
+        let a_s = [(0xAAAAAAAA_AAAAAAAA & core::usize::MAX) as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+        let f_s = [(0xFFFFFFFF_FFFFFFFF & core::usize::MAX) as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+
+        //
+        // Calling set with 0 results in no change.
+        //
+
+        assert_eq!(large.layer_cache, 0);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], zeros[index]);
+        }
+
+        large.set_field(&zeros);
+
+        assert_eq!(large.layer_cache, 0);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], zeros[index]);
+        }
+
+        //
+        // Setting only sets bits expected bits.
+        //
+
+        expected_bitfield[1 / LARGE_BIT_FIELD_GROUP_COUNT] |=
+            1 << (1 % LARGE_BIT_FIELD_GROUP_COUNT);
+
+        expected_toplayer |= 1 << (1 / LARGE_BIT_FIELD_GROUP_COUNT);
+
+        large.set_bit(1);
+
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            expected_bitfield[index] |= fives[index];
+            if fives[index] != 0 {
+                expected_toplayer |= 1 << index;
+            }
+        }
+
+        large.set_field(&fives);
+
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        //
+        // Settings already set values should result in no change.
+        //
+
+        large.set_field(&fives);
+
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        large.set_field(&a_s);
+        assert_eq!(large.layer_cache, core::usize::MAX);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], f_s[index]);
+        }
+
+        //
+        // Clearing only clears expected bits.
+        //
+
+        large.clear_field(&fives);
+        assert_eq!(large.layer_cache, core::usize::MAX);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], a_s[index]);
+        }
+
+        //
+        // Clearing already cleared values should result in no change.
+        //
+
+        large.clear_field(&fives);
+        assert_eq!(large.layer_cache, core::usize::MAX);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], a_s[index]);
+        }
+
+        //
+        // Calling clear with 0 results in no change.
+        //
+
+        large.clear_field(&zeros);
+        assert_eq!(large.layer_cache, core::usize::MAX);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], a_s[index]);
+        }
+    }
+
+    #[test]
+    fn validate_set_and_clear_group() {
+        let mut large = LargeBitField::new();
+        let mut large_unsafe = LargeBitField::new();
+        let mut expected_toplayer: usize = 0;
+        let mut expected_bitfield = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+        let fives = (0x55555555_55555555 & core::usize::MAX) as usize;
+        let first_group = 0;
+        let second_group = 2;
+        let third_group = 5;
+
+        //
+        // Verify Set Group
+        //
+
+        expected_toplayer |= 1 << first_group;
+        expected_bitfield[first_group] |= fives;
+
+        expected_toplayer |= 1 << second_group;
+        expected_bitfield[second_group] |= fives;
+
+        large.set_group(first_group, fives);
+        large.set_group(second_group, fives);
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        unsafe {
+            large_unsafe.set_group_unchecked(first_group, fives);
+            large_unsafe.set_group_unchecked(second_group, fives);
+        }
+
+        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
+        }
+
+        //
+        // Calling set out of bounds results in no change
+        //
+
+        large.set_group(LARGE_BIT_FIELD_GROUP_COUNT, fives);
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        //
+        // Calling set with 0, will result in no change
+        //
+
+        large.set_group(third_group, 0);
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        unsafe {
+            large_unsafe.set_group_unchecked(third_group, 0);
+        }
+
+        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
+        }
+
+        //
+        // Verify Clear Group
+        //
+
+        expected_toplayer &= !(1 << first_group);
+        expected_bitfield[first_group] &= !fives;
+
+        large.clear_group(first_group, fives);
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        unsafe {
+            large_unsafe.clear_group_unchecked(first_group, fives);
+        }
+
+        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
+        }
+
+        //
+        // Calling clear out of bounds results in no change
+        //
+
+        large.clear_group(LARGE_BIT_FIELD_GROUP_COUNT, fives);
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        //
+        // Calling clear with 0, will result in no change
+        //
+
+        large.clear_group(second_group, 0);
+        assert_eq!(large.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        }
+
+        unsafe {
+            large_unsafe.clear_group_unchecked(second_group, 0);
+        }
+
+        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
+        }
+    }
+
+    #[test]
+    fn validate_test_group() {
+        let mut large = LargeBitField::new();
+        let bit = 20;
+        let different_group_bit = bit + LARGE_BIT_FIELD_GROUP_COUNT;
+
+        //
+        // Out of bounds should return None for checked variant
+        //
+
+        assert_eq!(large.test_group(LARGE_BIT_FIELD_GROUP_COUNT), None);
+
+        //
+        // Set causes test to return true.
+        //
+
+        large.set_bit(bit);
+        assert_eq!(
+            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+            Some(true)
+        );
+        unsafe {
+            assert_eq!(
+                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+                true
+            );
+        }
+
+        //
+        // Clear causes test to return false.
+        //
+
+        large.clear_bit(bit);
+        assert_eq!(
+            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+            Some(false)
+        );
+        unsafe {
+            assert_eq!(
+                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+                false
+            );
+        }
+
+        //
+        // Changing another group has no affect on the bit being tested.
+        //
+
+        large.set_bit(different_group_bit);
+        assert_eq!(
+            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+            Some(false)
+        );
+        unsafe {
+            assert_eq!(
+                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+                false
+            );
+        }
+
+        //
+        // Clear causes test to return false.
+        //
+
+        large.set_bit(bit);
+        large.clear_bit(different_group_bit);
+        assert_eq!(
+            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+            Some(true)
+        );
+        unsafe {
+            assert_eq!(
+                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+                true
+            );
+        }
+    }
+
+    #[test]
+    fn validate_iter_set_bits() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Empty bitfield yields nothing.
+        //
+
+        assert_eq!(large.iter_set_bits().next(), None);
+        assert_eq!(large.iter_set_bits().next_back(), None);
+
+        //
+        // Set every third bit so runs cross group boundaries.
+        //
+
+        for i in (0..LARGE_BIT_FIELD_BIT_SIZE).step_by(3) {
+            large.set_bit(i);
+        }
+
+        let mut expected = 0;
+        for index in large.iter_set_bits() {
+            assert_eq!(index, expected);
+            expected += 3;
+        }
+
+        assert!(expected >= LARGE_BIT_FIELD_BIT_SIZE);
+
+        let mut expected = (LARGE_BIT_FIELD_BIT_SIZE - 1) / 3 * 3;
+        for index in large.iter_set_bits().rev() {
+            assert_eq!(index, expected);
+            expected = expected.wrapping_sub(3);
+        }
+
+        assert_eq!(expected, 0usize.wrapping_sub(3));
+    }
+
+    #[test]
+    fn validate_iter_set_bits_skips_empty_groups() {
+        let mut large = LargeBitField::new();
+        let first = 5;
+        let second = (LARGE_BIT_FIELD_GROUP_COUNT * 7) + 1;
+        let third = LARGE_BIT_FIELD_BIT_SIZE - 1;
+
+        large.set_bit(third);
+        large.set_bit(first);
+        large.set_bit(second);
+
+        let mut iter = large.iter_set_bits();
+        assert_eq!(iter.next(), Some(first));
+        assert_eq!(iter.next(), Some(second));
+        assert_eq!(iter.next(), Some(third));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn validate_iter_set_bits_from_both_ends() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Both ends meeting within a single group should yield each bit exactly once.
+        //
+
+        large.set_bit(1);
+        large.set_bit(2);
+        large.set_bit(3);
+
+        let mut iter = large.iter_set_bits();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = large.iter_set_bits();
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), None);
+
+        //
+        // Both ends meeting across groups.
+        //
+
+        large.set_bit(LARGE_BIT_FIELD_GROUP_COUNT);
+        large.set_bit(LARGE_BIT_FIELD_GROUP_COUNT * 2);
+
+        let mut iter = large.iter_set_bits();
+        assert_eq!(iter.next_back(), Some(LARGE_BIT_FIELD_GROUP_COUNT * 2));
+        assert_eq!(iter.next_back(), Some(LARGE_BIT_FIELD_GROUP_COUNT));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn validate_get_next_set_bit() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Out of bounds and empty should result in None for checked variant
+        //
+
+        assert_eq!(large.get_next_set_bit(LARGE_BIT_FIELD_BIT_SIZE), None);
+        assert_eq!(large.get_next_set_bit(0), None);
+
+        large.set_bit(LARGE_BIT_FIELD_BIT_SIZE - 1);
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            assert_eq!(
+                large.get_next_set_bit(i),
+                Some(LARGE_BIT_FIELD_BIT_SIZE - 1)
+            );
+            unsafe {
+                assert_eq!(
+                    large.get_next_set_bit_unchecked(i),
+                    LARGE_BIT_FIELD_BIT_SIZE - 1
+                );
+            }
+        }
+
+        //
+        // Set bits spread over groups should be found from anywhere between them.
+        //
 
-                //
-                // Calling set for an already set bit should result in no change.
-                //
+        large.clear_bit(LARGE_BIT_FIELD_BIT_SIZE - 1);
+        let stride = LARGE_BIT_FIELD_GROUP_COUNT + 3;
+        for i in (0..LARGE_BIT_FIELD_BIT_SIZE).step_by(stride) {
+            large.set_bit(i);
+        }
 
-                large_unsafe.set_bit_unchecked(i);
-                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            let expected = i.div_ceil(stride) * stride;
+            if expected < LARGE_BIT_FIELD_BIT_SIZE {
+                assert_eq!(large.get_next_set_bit(i), Some(expected));
+                unsafe {
+                    assert_eq!(large.get_next_set_bit_unchecked(i), expected);
                 }
+            } else {
+                assert_eq!(large.get_next_set_bit(i), None);
             }
         }
     }
 
     #[test]
-    fn validate_clear_bit() {
+    fn validate_get_previous_set_bit() {
         let mut large = LargeBitField::new();
-        let mut large_unsafe = LargeBitField::new();
-        let mut expected_toplayer = core::usize::MAX;
-        let mut expected_bitfield = [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT];
-
-        large.layer_cache = core::usize::MAX;
-        large.bitfield = [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT];
-        large_unsafe.layer_cache = core::usize::MAX;
-        large_unsafe.bitfield = [core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT];
-
-        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
-            //
-            // Out of bounds set should do nothing.
-            //
 
-            large.clear_bit(LARGE_BIT_FIELD_BIT_SIZE);
-            assert_eq!(large.layer_cache, expected_toplayer);
-            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                assert_eq!(large.bitfield[index], expected_bitfield[index]);
-            }
+        //
+        // Out of bounds and empty should result in None for checked variant
+        //
 
-            let active_group = i / LARGE_BIT_FIELD_GROUP_COUNT;
-            expected_bitfield[active_group] &= !(1 << (i % LARGE_BIT_FIELD_GROUP_COUNT));
-            if expected_bitfield[active_group] == 0 {
-                expected_toplayer &= !(1 << active_group);
-            }
+        assert_eq!(large.get_previous_set_bit(LARGE_BIT_FIELD_BIT_SIZE), None);
+        assert_eq!(
+            large.get_previous_set_bit(LARGE_BIT_FIELD_BIT_SIZE - 1),
+            None
+        );
 
-            large.clear_bit(i);
-            assert_eq!(large.layer_cache, expected_toplayer);
-            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        large.set_bit(0);
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            assert_eq!(large.get_previous_set_bit(i), Some(0));
+            unsafe {
+                assert_eq!(large.get_previous_set_bit_unchecked(i), 0);
             }
+        }
 
-            //
-            // Calling clear for an already cleared bit should result in no change.
-            //
+        //
+        // Set bits spread over groups should be found from anywhere between them.
+        //
 
-            large.clear_bit(i);
-            assert_eq!(large.layer_cache, expected_toplayer);
-            for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                assert_eq!(large.bitfield[index], expected_bitfield[index]);
-            }
+        large.clear_bit(0);
+        let stride = LARGE_BIT_FIELD_GROUP_COUNT + 3;
+        for i in (1..LARGE_BIT_FIELD_BIT_SIZE).step_by(stride) {
+            large.set_bit(i);
+        }
 
+        assert_eq!(large.get_previous_set_bit(0), None);
+        for i in 1..LARGE_BIT_FIELD_BIT_SIZE {
+            let expected = ((i - 1) / stride * stride) + 1;
+            assert_eq!(large.get_previous_set_bit(i), Some(expected));
             unsafe {
-                large_unsafe.clear_bit_unchecked(i);
-                assert_eq!(large.layer_cache, expected_toplayer);
-                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
-                }
-
-                //
-                // Calling clear for an already cleared bit should result in no change.
-                //
-
-                large_unsafe.clear_bit_unchecked(i);
-                for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-                    assert_eq!(large.bitfield[index], expected_bitfield[index]);
-                }
+                assert_eq!(large.get_previous_set_bit_unchecked(i), expected);
             }
         }
     }
 
     #[test]
-    fn validate_get_lowest_set_bit() {
+    fn validate_get_lowest_clear_bit() {
         let mut large = LargeBitField::new();
+        large.set_field(&[core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT]);
 
         //
-        // Empty should result in None for checked variant
+        // Full should result in None for checked variant
         //
 
-        assert_eq!(large.get_lowest_set_bit(), None);
+        assert_eq!(large.full_cache, core::usize::MAX);
+        assert!(large.is_full());
+        assert_eq!(large.get_lowest_clear_bit(), None);
 
         for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
-            large.set_bit(i);
-            assert_eq!(large.get_lowest_set_bit(), Some(0));
-            assert_eq!(large.get_lowest_set_bit_unchecked(), 0);
+            large.clear_bit(i);
+            assert!(!large.is_full());
+            assert_eq!(large.get_lowest_clear_bit(), Some(0));
+            assert_eq!(large.get_lowest_clear_bit_unchecked(), 0);
         }
 
+        assert_eq!(large.full_cache, 0);
+
         for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
-            assert_eq!(large.get_lowest_set_bit(), Some(i));
-            assert_eq!(large.get_lowest_set_bit_unchecked(), i);
-            large.clear_bit(i);
+            assert_eq!(large.get_lowest_clear_bit(), Some(i));
+            assert_eq!(large.get_lowest_clear_bit_unchecked(), i);
+            large.set_bit(i);
         }
+
+        assert_eq!(large.full_cache, core::usize::MAX);
+        assert!(large.is_full());
     }
 
     #[test]
-    fn validate_get_highest_set_bit() {
+    fn validate_get_highest_clear_bit() {
         let mut large = LargeBitField::new();
+        large.set_field(&[core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT]);
 
         //
-        // Empty should result in None for checked variant
+        // Full should result in None for checked variant
         //
 
-        assert_eq!(large.get_highest_set_bit(), None);
+        assert_eq!(large.get_highest_clear_bit(), None);
 
         for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
-            large.set_bit(i);
-            assert_eq!(large.get_highest_set_bit(), Some(i));
-            assert_eq!(large.get_highest_set_bit_unchecked(), i);
+            large.clear_bit(i);
+            assert_eq!(large.get_highest_clear_bit(), Some(i));
+            assert_eq!(large.get_highest_clear_bit_unchecked(), i);
         }
 
         for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
             assert_eq!(
-                large.get_highest_set_bit(),
+                large.get_highest_clear_bit(),
                 Some(LARGE_BIT_FIELD_BIT_SIZE - 1)
             );
             assert_eq!(
-                large.get_highest_set_bit_unchecked(),
+                large.get_highest_clear_bit_unchecked(),
                 LARGE_BIT_FIELD_BIT_SIZE - 1
             );
-            large.clear_bit(i);
+            large.set_bit(i);
         }
     }
 
     #[test]
-    fn validate_test_bit() {
-        let mut large = LargeBitField::new();
-
+    fn validate_clear_bit_unchecked_when_full() {
         //
-        // Out of bounds should return None for checked variant
+        // The result is undefined when every bit is set, but the queries must stay within the
+        // bitfield for every word type.
         //
 
-        assert_eq!(large.test_bit(LARGE_BIT_FIELD_BIT_SIZE), None);
+        let large = LargeBitField::full();
+        assert_eq!(large.get_lowest_clear_bit(), None);
+        assert_eq!(large.get_highest_clear_bit(), None);
+        large.get_lowest_clear_bit_unchecked();
+        large.get_highest_clear_bit_unchecked();
+
+        let large = LargeBitField::<u8>::from_groups([u8::MAX; 8]);
+        assert_eq!(large.get_lowest_clear_bit(), None);
+        assert_eq!(large.get_highest_clear_bit(), None);
+        large.get_lowest_clear_bit_unchecked();
+        large.get_highest_clear_bit_unchecked();
+    }
+
+    #[test]
+    fn validate_full_cache_tracks_groups() {
+        let mut large = LargeBitField::new();
+        let mut large_unsafe = LargeBitField::new();
+        let group = 3;
 
         //
-        // Set causes test to return true.
+        // A group is only marked full once every bit in it is set.
         //
 
-        large.set_bit(0);
-        assert_eq!(large.test_bit(0), Some(true));
-        unsafe {
-            assert_eq!(large.test_bit_unchecked(0), true);
-        }
-
-        //
-        // Clear causes test to return false.
-        //s
+        large.set_group(group, core::usize::MAX >> 1);
+        assert_eq!(large.full_cache, 0);
+        large.set_bit((group * LARGE_BIT_FIELD_GROUP_COUNT) + LARGE_BIT_FIELD_GROUP_COUNT - 1);
+        assert_eq!(large.full_cache, 1 << group);
 
-        large.clear_bit(0);
-        assert_eq!(large.test_bit(0), Some(false));
         unsafe {
-            assert_eq!(large.test_bit_unchecked(0), false);
-        }
+            large_unsafe.set_group_unchecked(group, core::usize::MAX);
+            assert_eq!(large_unsafe.full_cache, 1 << group);
 
-        //
-        // Changing another bit has no affect on the bit being tested.
-        //
+            large_unsafe.clear_bit_unchecked(group * LARGE_BIT_FIELD_GROUP_COUNT);
+            assert_eq!(large_unsafe.full_cache, 0);
 
-        large.set_bit(1);
-        assert_eq!(large.test_bit(0), Some(false));
-        unsafe {
-            assert_eq!(large.test_bit_unchecked(0), false);
+            large_unsafe.set_bit_unchecked(group * LARGE_BIT_FIELD_GROUP_COUNT);
+            assert_eq!(large_unsafe.full_cache, 1 << group);
         }
 
         //
-        // Clear causes test to return false.
+        // Clearing no bits leaves the group full, clearing any bit does not.
         //
 
-        large.set_bit(0);
-        large.clear_bit(1);
-        assert_eq!(large.test_bit(0), Some(true));
-        unsafe {
-            assert_eq!(large.test_bit_unchecked(0), true);
-        }
+        large.clear_group(group, 0);
+        assert_eq!(large.full_cache, 1 << group);
+        large.clear_group(group, 1);
+        assert_eq!(large.full_cache, 0);
     }
 
     //
-    // Method Tests
+    // Operator Tests
     //
 
-    #[test]
-    fn validate_set_and_clear_field() {
+    /// Checks that the caches of a bitfield agree with its groups.
+    fn assert_caches_consistent(large: &LargeBitField) {
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            let has_values = large.bitfield[index] != 0;
+            let is_full = large.bitfield[index] == core::usize::MAX;
+            assert_eq!((large.layer_cache & (1 << index)) != 0, has_values);
+            assert_eq!((large.full_cache & (1 << index)) != 0, is_full);
+        }
+    }
+
+    /// Creates a bitfield with a different pattern in each group, leaving some groups empty and
+    /// some groups full.
+    fn make_pattern(seed: usize) -> LargeBitField {
         let mut large = LargeBitField::new();
-        let mut expected_toplayer: usize = 0;
-        let mut expected_bitfield = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
+            let value = match (index + seed) % 4 {
+                0 => 0,
+                1 => core::usize::MAX,
+                _ => index.wrapping_mul(0x9E3779B9).rotate_left(seed as u32),
+            };
 
-        let zeros = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
-        let fives =
-            [(0x55555555_55555555 & core::usize::MAX) as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+            large.set_group(index, value);
+        }
 
-        let a_s = [(0xAAAAAAAA_AAAAAAAA & core::usize::MAX) as usize; LARGE_BIT_FIELD_GROUP_COUNT];
-        let f_s = [(0xFFFFFFFF_FFFFFFFF & core::usize::MAX) as usize; LARGE_BIT_FIELD_GROUP_COUNT];
+        large
+    }
+
+    #[test]
+    fn validate_set_operations() {
+        let left = make_pattern(0);
+        let right = make_pattern(1);
+
+        let union = make_pattern(0) | right;
+        let intersection = make_pattern(0) & right;
+        let symmetric_difference = make_pattern(0) ^ right;
+        let difference = make_pattern(0) - right;
+        let complement = !make_pattern(0);
 
         //
-        // Calling set with 0 results in no change.
+        // The operators taking the right operand by reference must agree with the by value ones.
         //
 
-        assert_eq!(large.layer_cache, 0);
+        assert_eq!(BitOr::bitor(make_pattern(0), &right), union);
+        assert_eq!(BitAnd::bitand(make_pattern(0), &right), intersection);
+        assert_eq!(
+            BitXor::bitxor(make_pattern(0), &right),
+            symmetric_difference
+        );
+        assert_eq!(Sub::sub(make_pattern(0), &right), difference);
+
         for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], zeros[index]);
+            let (l, r) = (left.bitfield[index], right.bitfield[index]);
+            assert_eq!(union.bitfield[index], l | r);
+            assert_eq!(intersection.bitfield[index], l & r);
+            assert_eq!(symmetric_difference.bitfield[index], l ^ r);
+            assert_eq!(difference.bitfield[index], l & !r);
+            assert_eq!(complement.bitfield[index], !l);
         }
 
-        large.set_field(&zeros);
+        assert_caches_consistent(&union);
+        assert_caches_consistent(&intersection);
+        assert_caches_consistent(&symmetric_difference);
+        assert_caches_consistent(&difference);
+        assert_caches_consistent(&complement);
+    }
 
-        assert_eq!(large.layer_cache, 0);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], zeros[index]);
-        }
+    #[test]
+    fn validate_set_assign_operations() {
+        let mut union = make_pattern(2);
+        let mut intersection = make_pattern(2);
+        let mut symmetric_difference = make_pattern(2);
+        let mut difference = make_pattern(2);
+
+        union |= make_pattern(3);
+        intersection &= make_pattern(3);
+        symmetric_difference ^= make_pattern(3);
+        difference -= make_pattern(3);
+
+        assert_eq!(union.bitfield, (make_pattern(2) | make_pattern(3)).bitfield);
+
+        assert_eq!(
+            intersection.bitfield,
+            (make_pattern(2) & make_pattern(3)).bitfield
+        );
+
+        assert_eq!(
+            symmetric_difference.bitfield,
+            (make_pattern(2) ^ make_pattern(3)).bitfield
+        );
+
+        assert_eq!(
+            difference.bitfield,
+            (make_pattern(2) - make_pattern(3)).bitfield
+        );
 
         //
-        // Setting only sets bits expected bits.
+        // Combining a bitfield with itself.
         //
 
-        expected_bitfield[1 / LARGE_BIT_FIELD_GROUP_COUNT] |=
-            1 << (1 % LARGE_BIT_FIELD_GROUP_COUNT);
+        let mut large = make_pattern(0);
+        large ^= make_pattern(0);
+        assert!(large.is_empty());
+        assert_caches_consistent(&large);
 
-        expected_toplayer |= 1 << (1 / LARGE_BIT_FIELD_GROUP_COUNT);
+        let mut large = make_pattern(0);
+        large -= make_pattern(0);
+        assert!(large.is_empty());
+        assert_caches_consistent(&large);
 
-        large.set_bit(1);
+        let large = make_pattern(0) | !make_pattern(0);
+        assert!(large.is_full());
+        assert_caches_consistent(&large);
+    }
 
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            expected_bitfield[index] |= fives[index];
-            if fives[index] != 0 {
-                expected_toplayer |= 1 << index;
-            }
+    #[test]
+    fn validate_count_rank_and_select() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Empty has no bits to count or select.
+        //
+
+        assert_eq!(large.count_set_bits(), 0);
+        assert_eq!(large.rank(LARGE_BIT_FIELD_BIT_SIZE), 0);
+        assert_eq!(large.select(0), None);
+
+        //
+        // Set every third bit so that groups hold differing counts.
+        //
+
+        for i in (0..LARGE_BIT_FIELD_BIT_SIZE).step_by(3) {
+            large.set_bit(i);
         }
 
-        large.set_field(&fives);
+        let count = LARGE_BIT_FIELD_BIT_SIZE.div_ceil(3);
+        assert_eq!(large.count_set_bits(), count);
+        assert_eq!(large.rank(LARGE_BIT_FIELD_BIT_SIZE), count);
 
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+        for i in 0..LARGE_BIT_FIELD_BIT_SIZE {
+            assert_eq!(large.rank(i), i.div_ceil(3));
+        }
+
+        for k in 0..count {
+            assert_eq!(large.select(k), Some(k * 3));
         }
 
+        assert_eq!(large.select(count), None);
+
         //
-        // Settings already set values should result in no change.
+        // Empty groups between set bits are skipped.
         //
 
-        large.set_field(&fives);
+        let mut large = LargeBitField::new();
+        large.set_bit(LARGE_BIT_FIELD_BIT_SIZE - 1);
+        large.set_bit(2);
+        assert_eq!(large.count_set_bits(), 2);
+        assert_eq!(large.rank(LARGE_BIT_FIELD_BIT_SIZE - 1), 1);
+        assert_eq!(large.select(1), Some(LARGE_BIT_FIELD_BIT_SIZE - 1));
+    }
 
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn validate_range_operations() {
+        let mut large = LargeBitField::new();
+
+        //
+        // Invalid ranges leave the field unchanged and cannot be queried.
+        //
+
+        large.set_range(0..LARGE_BIT_FIELD_BIT_SIZE + 1);
+        large.set_range(9..8);
+        assert!(large.is_empty());
+        assert_eq!(large.is_range_set(0..LARGE_BIT_FIELD_BIT_SIZE + 1), None);
+        assert_eq!(large.is_range_clear(9..8), None);
+        assert_eq!(large.count_in_range(9..8), None);
+
+        //
+        // Empty ranges are trivially both set and clear, even at the end of the field.
+        //
+
+        large.set_range(LARGE_BIT_FIELD_BIT_SIZE..LARGE_BIT_FIELD_BIT_SIZE);
+        assert!(large.is_empty());
+        assert_eq!(large.is_range_set(7..7), Some(true));
+        assert_eq!(large.is_range_clear(7..7), Some(true));
+        assert_eq!(large.count_in_range(7..7), Some(0));
+
+        large.set_range(0..LARGE_BIT_FIELD_BIT_SIZE);
+        assert!(large.is_full());
+        assert_caches_consistent(&large);
+
+        large.clear_range(0..LARGE_BIT_FIELD_BIT_SIZE);
+        assert!(large.is_empty());
+        assert_caches_consistent(&large);
+    }
+
+    #[test]
+    fn validate_range_operations_match_bit_operations() {
+        let ranges = [
+            (0, 1),
+            (5, 17),
+            (3, LARGE_BIT_FIELD_GROUP_COUNT),
+            (
+                LARGE_BIT_FIELD_GROUP_COUNT - 1,
+                LARGE_BIT_FIELD_GROUP_COUNT + 1,
+            ),
+            (LARGE_BIT_FIELD_GROUP_COUNT, LARGE_BIT_FIELD_GROUP_COUNT * 2),
+            (
+                LARGE_BIT_FIELD_GROUP_COUNT + 7,
+                (LARGE_BIT_FIELD_GROUP_COUNT * 5) + 3,
+            ),
+            (11, LARGE_BIT_FIELD_BIT_SIZE - 13),
+        ];
+
+        for seed in 0..4 {
+            for &(start, end) in ranges.iter() {
+                let original = make_pattern(seed);
+
+                let mut expected_count = 0;
+                let mut expected_set = true;
+                let mut expected_clear = true;
+                for index in start..end {
+                    let value = original.test_bit(index).unwrap();
+                    expected_count += value as usize;
+                    expected_set &= value;
+                    expected_clear &= !value;
+                }
+
+                assert_eq!(original.count_in_range(start..end), Some(expected_count));
+                assert_eq!(original.is_range_set(start..end), Some(expected_set));
+                assert_eq!(original.is_range_clear(start..end), Some(expected_clear));
+
+                let mut set = make_pattern(seed);
+                let mut cleared = make_pattern(seed);
+                let mut expected_set_field = make_pattern(seed);
+                let mut expected_cleared_field = make_pattern(seed);
+                for index in start..end {
+                    expected_set_field.set_bit(index);
+                    expected_cleared_field.clear_bit(index);
+                }
+
+                set.set_range(start..end);
+                cleared.clear_range(start..end);
+                assert_eq!(set.bitfield, expected_set_field.bitfield);
+                assert_eq!(cleared.bitfield, expected_cleared_field.bitfield);
+                assert_caches_consistent(&set);
+                assert_caches_consistent(&cleared);
+
+                assert_eq!(set.is_range_set(start..end), Some(true));
+                assert_eq!(cleared.is_range_clear(start..end), Some(true));
+                assert_eq!(set.count_in_range(start..end), Some(end - start));
+            }
         }
+    }
+
+    #[test]
+    fn validate_get_next_clear_bit() {
+        let mut large = LargeBitField::new();
+        large.set_range(0..LARGE_BIT_FIELD_BIT_SIZE);
+        assert_eq!(large.get_next_clear_bit(0), None);
+        assert_eq!(large.get_next_clear_bit(LARGE_BIT_FIELD_BIT_SIZE), None);
+
+        large.clear_bit(5);
+        large.clear_bit(LARGE_BIT_FIELD_BIT_SIZE - 1);
+        assert_eq!(large.get_next_clear_bit(0), Some(5));
+        assert_eq!(large.get_next_clear_bit(5), Some(5));
+        assert_eq!(
+            large.get_next_clear_bit(6),
+            Some(LARGE_BIT_FIELD_BIT_SIZE - 1)
+        );
+
+        for seed in 0..4 {
+            let large = make_pattern(seed);
+            let mut expected = None;
+            for index in (0..LARGE_BIT_FIELD_BIT_SIZE).rev() {
+                if large.test_bit(index) == Some(false) {
+                    expected = Some(index);
+                }
 
-        large.set_field(&a_s);
-        assert_eq!(large.layer_cache, core::usize::MAX);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], f_s[index]);
+                assert_eq!(large.get_next_clear_bit(index), expected);
+            }
         }
+    }
+
+    #[test]
+    fn validate_find_clear_run() {
+        let mut large = LargeBitField::new();
 
         //
-        // Clearing only clears expected bits.
+        // Degenerate requests never succeed.
         //
 
-        large.clear_field(&fives);
-        assert_eq!(large.layer_cache, core::usize::MAX);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], a_s[index]);
-        }
+        assert_eq!(large.find_clear_run(0, 1), None);
+        assert_eq!(large.find_clear_run(1, 0), None);
+        assert_eq!(large.find_clear_run(LARGE_BIT_FIELD_BIT_SIZE + 1, 1), None);
+
+        assert_eq!(large.find_clear_run(LARGE_BIT_FIELD_BIT_SIZE, 1), Some(0));
+        assert_eq!(large.find_clear_run(1, core::usize::MAX), Some(0));
 
         //
-        // Clearing already cleared values should result in no change.
+        // A run must skip over full groups and may span group boundaries.
         //
 
-        large.clear_field(&fives);
-        assert_eq!(large.layer_cache, core::usize::MAX);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], a_s[index]);
-        }
+        large.set_range(0..(LARGE_BIT_FIELD_GROUP_COUNT * 2) + 1);
+        large.set_bit((LARGE_BIT_FIELD_GROUP_COUNT * 3) - 2);
+        let group_two = LARGE_BIT_FIELD_GROUP_COUNT * 2;
+        assert_eq!(large.find_clear_run(1, 1), Some(group_two + 1));
+        assert_eq!(
+            large.find_clear_run(LARGE_BIT_FIELD_GROUP_COUNT - 3, 1),
+            Some(group_two + 1)
+        );
+
+        assert_eq!(
+            large.find_clear_run(LARGE_BIT_FIELD_GROUP_COUNT, 1),
+            Some((LARGE_BIT_FIELD_GROUP_COUNT * 3) - 1)
+        );
+
+        assert_eq!(
+            large.find_clear_run(2, LARGE_BIT_FIELD_GROUP_COUNT),
+            Some(LARGE_BIT_FIELD_GROUP_COUNT * 3)
+        );
 
         //
-        // Calling clear with 0 results in no change.
+        // A run cannot extend past the end of the field.
         //
 
-        large.clear_field(&zeros);
-        assert_eq!(large.layer_cache, core::usize::MAX);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], a_s[index]);
-        }
+        large.clear_range(0..LARGE_BIT_FIELD_BIT_SIZE);
+        large.set_range(0..LARGE_BIT_FIELD_BIT_SIZE - 4);
+        assert_eq!(
+            large.find_clear_run(4, 1),
+            Some(LARGE_BIT_FIELD_BIT_SIZE - 4)
+        );
+        assert_eq!(large.find_clear_run(5, 1), None);
+        assert_eq!(large.find_clear_run(4, 8), None);
     }
 
     #[test]
-    fn validate_set_and_clear_group() {
+    fn validate_fallible_operations() {
         let mut large = LargeBitField::new();
-        let mut large_unsafe = LargeBitField::new();
-        let mut expected_toplayer: usize = 0;
-        let mut expected_bitfield = [0 as usize; LARGE_BIT_FIELD_GROUP_COUNT];
-        let fives = (0x55555555_55555555 & core::usize::MAX) as usize;
-        let first_group = 0;
-        let second_group = 2;
-        let third_group = 5;
 
         //
-        // Verify Set Group
+        // Invalid indices and groups are reported rather than ignored.
         //
 
-        expected_toplayer |= 1 << first_group;
-        expected_bitfield[first_group] |= fives;
+        assert_eq!(
+            large.try_set_bit(LARGE_BIT_FIELD_BIT_SIZE),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(
+            large.try_clear_bit(LARGE_BIT_FIELD_BIT_SIZE),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(
+            large.try_test_bit(LARGE_BIT_FIELD_BIT_SIZE),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(
+            large.try_set_group(LARGE_BIT_FIELD_GROUP_COUNT, 1),
+            Err(BitFieldError::GroupOutOfRange)
+        );
+        assert_eq!(
+            large.try_clear_group(LARGE_BIT_FIELD_GROUP_COUNT, 1),
+            Err(BitFieldError::GroupOutOfRange)
+        );
+        assert_eq!(
+            large.try_test_group(LARGE_BIT_FIELD_GROUP_COUNT),
+            Err(BitFieldError::GroupOutOfRange)
+        );
+        assert!(large.is_empty());
 
-        expected_toplayer |= 1 << second_group;
-        expected_bitfield[second_group] |= fives;
+        let last_bit = LARGE_BIT_FIELD_BIT_SIZE - 1;
+        assert_eq!(large.try_set_bit(last_bit), Ok(()));
+        assert_eq!(large.try_test_bit(last_bit), Ok(true));
+        assert_eq!(large.try_clear_bit(last_bit), Ok(()));
+        assert_eq!(large.try_test_bit(last_bit), Ok(false));
+
+        let last_group = LARGE_BIT_FIELD_GROUP_COUNT - 1;
+        assert_eq!(large.try_set_group(last_group, core::usize::MAX), Ok(()));
+        assert_eq!(large.try_test_group(last_group), Ok(true));
+        assert!(large.full_cache & (1 << last_group) != 0);
+        assert_eq!(large.try_clear_group(last_group, core::usize::MAX), Ok(()));
+        assert_eq!(large.try_test_group(last_group), Ok(false));
+        assert_caches_consistent(&large);
+    }
 
-        large.set_group(first_group, fives);
-        large.set_group(second_group, fives);
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(expected = "set_bit_unchecked called with index")]
+    fn checked_rejects_out_of_range_index() {
+        let mut large = LargeBitField::new();
+        unsafe {
+            large.set_bit_unchecked(LARGE_BIT_FIELD_BIT_SIZE);
         }
+    }
 
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(expected = "clear_group_unchecked called with index")]
+    fn checked_rejects_out_of_range_group() {
+        let mut large = LargeBitField::new();
         unsafe {
-            large_unsafe.set_group_unchecked(first_group, fives);
-            large_unsafe.set_group_unchecked(second_group, fives);
+            large.clear_group_unchecked(LARGE_BIT_FIELD_GROUP_COUNT, 1);
         }
+    }
 
-        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
-        }
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(expected = "caches are out of sync after set_bit: group 3")]
+    fn checked_detects_cache_corruption() {
+        let mut large = LargeBitField::new();
+        large.bitfield[3] = 1;
+        large.set_bit(0);
+    }
+
+    #[test]
+    fn validate_verify_invariants_and_rebuild_summary() {
+        let mut large = LargeBitField::new();
+        large.set_group(2, 1);
+        large.set_group(5, core::usize::MAX);
+        assert_eq!(large.verify_invariants(), Ok(()));
 
         //
-        // Calling set out of bounds results in no change
+        // Change groups behind the caches' back and make the full cache claim an empty group is
+        // full.
         //
 
-        large.set_group(LARGE_BIT_FIELD_GROUP_COUNT, fives);
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
-        }
+        large.bitfield[2] = 0;
+        large.bitfield[5] = 0x10;
+        large.full_cache |= 1 << 9;
+        assert_eq!(
+            large.verify_invariants(),
+            Err((1 << 2) | (1 << 5) | (1 << 9))
+        );
+
+        large.rebuild_summary();
+        assert_eq!(large.verify_invariants(), Ok(()));
+        assert_caches_consistent(&large);
 
         //
-        // Calling set with 0, will result in no change
+        // A field with no set groups should rebuild to empty caches.
         //
 
-        large.set_group(third_group, 0);
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
-        }
-
-        unsafe {
-            large_unsafe.set_group_unchecked(third_group, 0);
-        }
+        large.bitfield = [0; LARGE_BIT_FIELD_GROUP_COUNT];
+        large.rebuild_summary();
+        assert_eq!(large.layer_cache, 0);
+        assert_eq!(large.full_cache, 0);
+        assert_eq!(large.get_lowest_set_bit(), None);
+    }
 
-        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
-        }
+    #[test]
+    fn validate_standard_traits() {
+        let mut large = LargeBitField::default();
+        assert_eq!(large, LargeBitField::new());
+        assert_eq!(format!("{:?}", large), "LargeBitField {}");
+
+        large.set_bit(LARGE_BIT_FIELD_GROUP_COUNT);
+        let copy = large;
+        large.clear_bit(LARGE_BIT_FIELD_GROUP_COUNT);
+        assert_ne!(large, copy);
+        assert_eq!(large, LargeBitField::new());
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(large);
+        set.insert(copy);
+        set.insert(make_pattern(0));
+        set.insert(make_pattern(0));
+        assert_eq!(set.len(), 3);
 
         //
-        // Verify Clear Group
+        // Runs of set bits that span groups collapse into a single range.
         //
 
-        expected_toplayer &= !(1 << first_group);
-        expected_bitfield[first_group] &= !fives;
+        large.set_range(3..(LARGE_BIT_FIELD_GROUP_COUNT * 2) + 1);
+        large.set_bit(LARGE_BIT_FIELD_BIT_SIZE - 1);
+        assert_eq!(
+            format!("{:?}", large),
+            format!(
+                "LargeBitField {{3..={}, {}}}",
+                LARGE_BIT_FIELD_GROUP_COUNT * 2,
+                LARGE_BIT_FIELD_BIT_SIZE - 1
+            )
+        );
 
-        large.clear_group(first_group, fives);
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
-        }
+        large.set_range(0..LARGE_BIT_FIELD_BIT_SIZE);
+        assert_eq!(
+            format!("{:?}", large),
+            format!("LargeBitField {{0..={}}}", LARGE_BIT_FIELD_BIT_SIZE - 1)
+        );
+    }
 
-        unsafe {
-            large_unsafe.clear_group_unchecked(first_group, fives);
+    #[test]
+    fn validate_word_import_and_export() {
+        for seed in 0..4 {
+            let pattern = make_pattern(seed);
+            let large = LargeBitField::from_words(pattern.into_words());
+            assert_caches_consistent(&large);
+            assert_eq!(large, pattern);
+            assert_eq!(large.as_words(), &pattern.bitfield);
         }
 
-        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
-        }
+        let empty = LargeBitField::from_words([0; LARGE_BIT_FIELD_GROUP_COUNT]);
+        assert_eq!(empty, LargeBitField::new());
 
-        //
-        // Calling clear out of bounds results in no change
-        //
+        let full = LargeBitField::from_words([core::usize::MAX; LARGE_BIT_FIELD_GROUP_COUNT]);
+        assert!(full.is_full());
+        assert_caches_consistent(&full);
+    }
 
-        large.clear_group(LARGE_BIT_FIELD_GROUP_COUNT, fives);
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
-        }
+    #[test]
+    fn validate_byte_serialization() {
+        let word_count = LARGE_BIT_FIELD_BIT_SIZE / 64;
+        assert_eq!(LargeBitField::SERIALIZED_SIZE, 8 + (word_count * 8));
 
-        //
-        // Calling clear with 0, will result in no change
-        //
+        for seed in 0..4 {
+            let large = make_pattern(seed);
+            let mut buffer = [0; LargeBitField::SERIALIZED_SIZE];
+            assert_eq!(
+                large.to_bytes(&mut buffer),
+                Ok(LargeBitField::SERIALIZED_SIZE)
+            );
 
-        large.clear_group(second_group, 0);
-        assert_eq!(large.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large.bitfield[index], expected_bitfield[index]);
-        }
+            //
+            // Every bit must land in the 64-bit word and position given by its index.
+            //
 
-        unsafe {
-            large_unsafe.clear_group_unchecked(second_group, 0);
-        }
+            for index in 0..LARGE_BIT_FIELD_BIT_SIZE {
+                let byte = buffer[8 + (index / 8)];
+                assert_eq!(
+                    (byte >> (index % 8)) & 1 == 1,
+                    large.test_bit(index).unwrap()
+                );
+            }
 
-        assert_eq!(large_unsafe.layer_cache, expected_toplayer);
-        for index in 0..LARGE_BIT_FIELD_GROUP_COUNT {
-            assert_eq!(large_unsafe.bitfield[index], expected_bitfield[index]);
+            let restored = LargeBitField::from_bytes(&buffer).unwrap();
+            assert_eq!(restored, large);
+            assert_caches_consistent(&restored);
         }
-    }
 
-    #[test]
-    fn validate_test_group() {
-        let mut large = LargeBitField::new();
-        let bit = 20;
-        let different_group_bit = bit + LARGE_BIT_FIELD_GROUP_COUNT;
+        let mut small_buffer = [0; LargeBitField::SERIALIZED_SIZE - 1];
+        assert_eq!(
+            LargeBitField::new().to_bytes(&mut small_buffer),
+            Err(BitFieldError::BufferTooSmall)
+        );
 
         //
-        // Out of bounds should return None for checked variant
+        // A bitfield serialized by a machine with a different number of bits is rejected.
         //
 
-        assert_eq!(large.test_group(LARGE_BIT_FIELD_GROUP_COUNT), None);
+        let mut buffer = [0; LargeBitField::SERIALIZED_SIZE];
+        buffer[..8].copy_from_slice(&((LARGE_BIT_FIELD_BIT_SIZE as u64) * 4).to_le_bytes());
+        assert_eq!(
+            LargeBitField::from_bytes(&buffer),
+            Err(BitFieldError::SizeMismatch)
+        );
+    }
+
+    #[test]
+    fn validate_sparse_serialization() {
+        let mut buffer = [0; LargeBitField::MAX_SPARSE_SERIALIZED_SIZE];
+        let empty = LargeBitField::new();
+        assert_eq!(empty.get_sparse_serialized_size(), 16);
+        assert_eq!(empty.to_sparse_bytes(&mut buffer), Ok(16));
+        assert_eq!(LargeBitField::from_sparse_bytes(&buffer[..16]), Ok(empty));
 
         //
-        // Set causes test to return true.
+        // Only the groups with bits set should be written, in group order.
         //
 
-        large.set_bit(bit);
+        let mut large = LargeBitField::new();
+        large.set_bit(LARGE_BIT_FIELD_GROUP_COUNT * 9 + 4);
+        large.set_bit(1);
+        large.set_bit(LARGE_BIT_FIELD_BIT_SIZE - 1);
+        assert_eq!(large.get_sparse_serialized_size(), 40);
+        assert_eq!(large.to_sparse_bytes(&mut buffer), Ok(40));
         assert_eq!(
-            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-            Some(true)
+            &buffer[8..16],
+            &(((1usize << (LARGE_BIT_FIELD_GROUP_COUNT - 1)) | (1 << 9) | 1) as u64).to_le_bytes()
         );
-        unsafe {
-            assert_eq!(
-                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-                true
-            );
+        assert_eq!(&buffer[16..24], &2u64.to_le_bytes());
+        assert_eq!(&buffer[24..32], &16u64.to_le_bytes());
+
+        let restored = LargeBitField::from_sparse_bytes(&buffer).unwrap();
+        assert_eq!(restored, large);
+        assert_eq!(restored.get_sparse_serialized_size(), 40);
+
+        for seed in 0..4 {
+            let large = make_pattern(seed);
+            let size = large.to_sparse_bytes(&mut buffer).unwrap();
+            let restored = LargeBitField::from_sparse_bytes(&buffer[..size]).unwrap();
+            assert_eq!(restored, large);
+            assert_caches_consistent(&restored);
         }
 
-        //
-        // Clear causes test to return false.
-        //
+        let mut full = LargeBitField::new();
+        full.set_range(0..LARGE_BIT_FIELD_BIT_SIZE);
+        assert_eq!(
+            full.to_sparse_bytes(&mut buffer),
+            Ok(LargeBitField::MAX_SPARSE_SERIALIZED_SIZE)
+        );
 
-        large.clear_bit(bit);
         assert_eq!(
-            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-            Some(false)
+            large.to_sparse_bytes(&mut buffer[..16]),
+            Err(BitFieldError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn validate_sparse_decoding_rejects_invalid_input() {
+        let mut buffer = [0; LargeBitField::MAX_SPARSE_SERIALIZED_SIZE];
+        let mut large = LargeBitField::new();
+        large.set_bit(LARGE_BIT_FIELD_GROUP_COUNT * 3);
+        let size = large.to_sparse_bytes(&mut buffer).unwrap();
+
+        assert_eq!(
+            LargeBitField::from_sparse_bytes(&buffer[..15]),
+            Err(BitFieldError::BufferTooSmall)
+        );
+        assert_eq!(
+            LargeBitField::from_sparse_bytes(&buffer[..size - 1]),
+            Err(BitFieldError::BufferTooSmall)
         );
-        unsafe {
-            assert_eq!(
-                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-                false
-            );
-        }
 
         //
-        // Changing another group has no affect on the bit being tested.
+        // The summary naming a group that was written as empty is malformed.
         //
 
-        large.set_bit(different_group_bit);
+        let mut invalid = buffer;
+        invalid[16..24].copy_from_slice(&0u64.to_le_bytes());
         assert_eq!(
-            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-            Some(false)
+            LargeBitField::from_sparse_bytes(&invalid[..size]),
+            Err(BitFieldError::InvalidFormat)
         );
-        unsafe {
-            assert_eq!(
-                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-                false
-            );
+
+        let mut invalid = buffer;
+        invalid[..8].copy_from_slice(&((LARGE_BIT_FIELD_BIT_SIZE as u64) / 4).to_le_bytes());
+        assert_eq!(
+            LargeBitField::from_sparse_bytes(&invalid[..size]),
+            Err(BitFieldError::SizeMismatch)
+        );
+    }
+
+    /// Checks a LargeBitField of any word type against a reference set of bits.
+    fn validate_word_type<W: BitWord>() {
+        let bits = W::BITS * W::BITS;
+        let mut large = LargeBitField::<W>::new();
+        assert_eq!(large.capacity(), bits);
+        assert_eq!(LargeBitField::<W>::BITS, bits);
+
+        let indices = [0, 3, W::BITS, (W::BITS * 2) + 1, bits - W::BITS, bits - 1];
+        for index in indices.iter() {
+            large.set_bit(*index);
         }
 
-        //
-        // Clear causes test to return false.
-        //
+        large.set_bit(bits);
+        assert_eq!(large.test_bit(bits), None);
+        assert_eq!(large.verify_invariants(), Ok(()));
+        assert_eq!(large.count_set_bits(), indices.len());
+        assert_eq!(large.get_lowest_set_bit(), Some(0));
+        assert_eq!(large.get_highest_set_bit(), Some(bits - 1));
+        assert_eq!(large.get_next_set_bit(4), Some(W::BITS));
+        assert_eq!(large.get_previous_set_bit(bits - 2), Some(bits - W::BITS));
+        assert_eq!(large.get_lowest_clear_bit(), Some(1));
+        assert_eq!(large.rank(W::BITS + 1), 3);
+        assert_eq!(large.select(3), Some((W::BITS * 2) + 1));
+        assert!(large.iter_set_bits().eq(indices.iter().cloned()));
+        assert!(large
+            .iter_set_bits()
+            .rev()
+            .eq(indices.iter().rev().cloned()));
+
+        large.set_range(W::BITS..(W::BITS * 3));
+        assert_eq!(large.test_group(1), Some(true));
+        assert_eq!(large.as_groups().as_ref()[2], W::MAX);
+        assert_eq!(large.is_range_set(W::BITS..(W::BITS * 3)), Some(true));
+        assert_eq!(large.get_next_clear_bit(W::BITS), Some(W::BITS * 3));
+        assert_eq!(large.verify_invariants(), Ok(()));
+
+        let mut other = LargeBitField::<W>::new();
+        other.set_group(1, W::MAX);
+        let difference = large - other;
+        assert_eq!(difference.test_group(1), Some(false));
+        assert_eq!(difference.get_next_set_bit(4), Some(W::BITS * 2));
+
+        let complement = !large;
+        assert_eq!(complement.get_lowest_set_bit(), Some(1));
+        assert_eq!(complement.get_highest_set_bit(), Some(bits - 2));
+        assert_eq!(complement.verify_invariants(), Ok(()));
+
+        large.clear_range(0..bits);
+        assert!(large.is_empty());
+        assert_eq!(large, LargeBitField::<W>::new());
+    }
 
-        large.set_bit(bit);
-        large.clear_bit(different_group_bit);
+    #[test]
+    fn validate_word_types() {
+        validate_word_type::<u8>();
+        validate_word_type::<u16>();
+        validate_word_type::<u32>();
+        validate_word_type::<u64>();
+        validate_word_type::<u128>();
+        validate_word_type::<usize>();
+    }
+
+    /// Holds a bitfield built at compile time, including a full group.
+    static CONST_LARGE: LargeBitField = {
+        let mut words = [0; LARGE_BIT_FIELD_GROUP_COUNT];
+        words[1] = core::usize::MAX;
+        LargeBitField::from_words(words).with_bit(LARGE_BIT_FIELD_BIT_SIZE - 1)
+    };
+
+    #[test]
+    fn validate_const_construction() {
+        const BITS: LargeBitField =
+            LargeBitField::with_bits(&[0, 70, 4000, LARGE_BIT_FIELD_BIT_SIZE]);
+        const BIT: Option<bool> = BITS.test_bit(4000);
+        assert_eq!(BIT, Some(true));
+        assert_eq!(BITS.count_set_bits(), 3);
+        assert_caches_consistent(&BITS);
+
+        let mut expected = LargeBitField::new();
+        expected.set_bit(0);
+        expected.set_bit(70);
+        expected.set_bit(4000);
+        assert_eq!(BITS, expected);
+
+        assert_caches_consistent(&CONST_LARGE);
         assert_eq!(
-            large.test_group(bit / LARGE_BIT_FIELD_GROUP_COUNT),
+            CONST_LARGE.count_set_bits(),
+            LARGE_BIT_FIELD_GROUP_COUNT + 1
+        );
+        assert_eq!(CONST_LARGE.get_lowest_clear_bit(), Some(0));
+        assert_eq!(
+            CONST_LARGE.get_next_clear_bit(LARGE_BIT_FIELD_GROUP_COUNT),
+            Some(LARGE_BIT_FIELD_GROUP_COUNT * 2)
+        );
+        assert_eq!(
+            CONST_LARGE.test_bit(LARGE_BIT_FIELD_BIT_SIZE - 1),
             Some(true)
         );
-        unsafe {
-            assert_eq!(
-                large.test_group_unchecked(bit / LARGE_BIT_FIELD_GROUP_COUNT),
-                true
-            );
-        }
+        assert_eq!(CONST_LARGE.test_bit(LARGE_BIT_FIELD_BIT_SIZE), None);
+
+        let full = LargeBitField::full();
+        assert!(full.is_full());
+        assert_caches_consistent(&full);
+        assert_eq!(full.count_set_bits(), LARGE_BIT_FIELD_BIT_SIZE);
     }
 }
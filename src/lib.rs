@@ -3,9 +3,36 @@
 //! Fast bitfields are bitfields that can evaluate the lowest and highest set bits, set and clear
 //! bits, and check for empty quickly and in a constant time invariant (or nearly invariant) of the
 //! contents of the bitfield.
+//!
+//! ## Migrating from `FastBitField::get_number_of_bits()`
+//! `FastBitField` describes the size of a bitfield with the instance method `capacity()`, so
+//! bitfields sized at runtime can implement it. The associated `get_number_of_bits()` moved to the
+//! `FixedBitField` trait along with a `BITS` constant, and `SmallBitField`, `LargeBitField` and
+//! `HierarchicalBitField` keep it as an inherent `const fn`, so calls on those types still compile.
+//! Generic code bounded by `B: FastBitField` that calls `B::get_number_of_bits()` no longer
+//! compiles. Either call `capacity()` on a bitfield, or bound `B` by `FixedBitField` and use
+//! `B::BITS` or `B::get_number_of_bits()`.
+//!
+//! ```
+//! use fast_bitfield::{FastBitField, FixedBitField, LargeBitField};
+//!
+//! fn get_capacity<B: FastBitField>(bitfield: &B) -> usize {
+//!     bitfield.capacity()
+//! }
+//!
+//! fn get_type_capacity<B: FixedBitField>() -> usize {
+//!     B::get_number_of_bits()
+//! }
+//!
+//! assert_eq!(get_capacity(&LargeBitField::new()), get_type_capacity::<LargeBitField>());
+//! assert_eq!(LargeBitField::get_number_of_bits(), LargeBitField::BITS);
+//! ```
 
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use cpu_features;
 use debruijin;
 
@@ -17,11 +44,28 @@ pub trait FastBitField {
     /// A FastBitField.
     fn new() -> Self;
 
-    /// Gets the number of bits available in the bitfield type.
+    /// Initializes a new, empty bitfield in caller provided memory. Unlike `new()`, which builds
+    /// the bitfield on the stack and returns it by value, bitfields that nest other bitfields
+    /// initialize each level directly in the slot, so large bitfields can be built on the heap or
+    /// in a static without a stack copy.
+    ///
+    /// # Arguments
+    /// slot - Provides the memory to initialize.
+    ///
+    /// # Returns
+    /// A reference to the initialized bitfield.
+    fn init_in_place(slot: &mut core::mem::MaybeUninit<Self>) -> &mut Self
+    where
+        Self: Sized,
+    {
+        slot.write(Self::new())
+    }
+
+    /// Gets the number of bits available in the bitfield.
     ///
     /// # Returns
     /// The number of bits available.
-    fn get_number_of_bits() -> usize;
+    fn capacity(&self) -> usize;
 
     /// Sets a bit in the bit field
     ///
@@ -47,6 +91,26 @@ pub trait FastBitField {
     /// The highest set bit index or `None` if no bits are set.
     fn get_highest_set_bit(&self) -> Option<usize>;
 
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn get_next_set_bit(&self, index: usize) -> Option<usize>;
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn get_previous_set_bit(&self, index: usize) -> Option<usize>;
+
     /// Gets the value of a specific bit in the bit field.
     ///
     /// # Arguments
@@ -58,12 +122,141 @@ pub trait FastBitField {
     /// `None` if index is invalid.
     fn test_bit(&self, index: usize) -> Option<bool>;
 
+    /// Sets a bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set.
+    ///
+    /// # Returns
+    /// `Ok(())` if the bit was set or `BitFieldError::IndexOutOfRange` if index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{BitFieldError, FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    ///
+    /// assert_eq!(small.try_set_bit(3), Ok(()));
+    /// assert_eq!(small.try_test_bit(3), Ok(true));
+    /// assert_eq!(
+    ///     small.try_set_bit(core::usize::MAX),
+    ///     Err(BitFieldError::IndexOutOfRange)
+    /// );
+    /// ```
+    fn try_set_bit(&mut self, index: usize) -> Result<(), BitFieldError> {
+        if index >= self.capacity() {
+            return Err(BitFieldError::IndexOutOfRange);
+        }
+
+        //
+        // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+        // calling it.
+        //
+
+        unsafe {
+            self.set_bit_unchecked(index);
+        }
+
+        Ok(())
+    }
+
+    /// Clears a bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to clear.
+    ///
+    /// # Returns
+    /// `Ok(())` if the bit was cleared or `BitFieldError::IndexOutOfRange` if index is invalid.
+    fn try_clear_bit(&mut self, index: usize) -> Result<(), BitFieldError> {
+        if index >= self.capacity() {
+            return Err(BitFieldError::IndexOutOfRange);
+        }
+
+        //
+        // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
+        // calling it.
+        //
+
+        unsafe {
+            self.clear_bit_unchecked(index);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the value of a specific bit in the bit field, reporting invalid indices.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Ok(true)` if bit is set.
+    /// `Ok(false)` if bit is cleared.
+    /// `BitFieldError::IndexOutOfRange` if index is invalid.
+    fn try_test_bit(&self, index: usize) -> Result<bool, BitFieldError> {
+        self.test_bit(index).ok_or(BitFieldError::IndexOutOfRange)
+    }
+
     /// Determines whether or not the bitfield is empty.
     ///
     /// # Returns
     /// `true` if empty, `false` otherwise.
     fn is_empty(&self) -> bool;
 
+    /// Determines whether or not every bit in the bitfield is set.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    fn is_full(&self) -> bool;
+
+    /// Gets the number of set bits in the bitfield.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    fn count_set_bits(&self) -> usize;
+
+    /// Gets the number of set bits below a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    fn rank(&self, index: usize) -> usize;
+
+    /// Gets the set bit with a given rank.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    fn select(&self, rank: usize) -> Option<usize>;
+
+    /// Gets the lowest clear bit.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    fn get_lowest_clear_bit(&self) -> Option<usize>;
+
+    /// Gets the highest clear bit.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    fn get_highest_clear_bit(&self) -> Option<usize>;
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    fn get_next_clear_bit(&self, index: usize) -> Option<usize>;
+
     /// Gets the lowest set bit, guaranteed to have no branches and be in constant time, completely
     /// invariant of the state of the bit field. If no bits are set, the result is undefined.
     ///
@@ -84,6 +277,57 @@ pub trait FastBitField {
     /// The highest set bit index or `UNDEFINED` if no bits are set.
     fn get_highest_set_bit_unchecked(&self) -> usize;
 
+    /// Gets the lowest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_lowest_clear_bit_unchecked(&self) -> usize;
+
+    /// Gets the highest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_highest_clear_bit_unchecked(&self) -> usize;
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `UNDEFINED` if no such bit is
+    /// set.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or after the index. The caller must guarantee that the index is less
+    /// than `capacity()` and that a bit at or after the index is set.
+    unsafe fn get_next_set_bit_unchecked(&self, index: usize) -> usize;
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `UNDEFINED` if no such bit is set.
+    ///
+    /// # Safety
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or before the index. The caller must guarantee that the index is
+    /// less than `capacity()` and that a bit at or before the index is set.
+    unsafe fn get_previous_set_bit_unchecked(&self, index: usize) -> usize;
+
     /// Sets a bit in the bit field.
     ///
     /// # Arguments
@@ -91,7 +335,7 @@ pub trait FastBitField {
     ///
     /// # Unsafe
     /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
     unsafe fn set_bit_unchecked(&mut self, index: usize);
 
     /// Clears a bit in the bit field
@@ -101,7 +345,7 @@ pub trait FastBitField {
     ///
     /// # Unsafe
     /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
     unsafe fn clear_bit_unchecked(&mut self, index: usize);
 
     /// Gets the value of a specific bit in the bit field.
@@ -115,17 +359,355 @@ pub trait FastBitField {
     ///
     /// # Unsafe
     /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
     unsafe fn test_bit_unchecked(&self, index: usize) -> bool;
 }
 
-/// Defines a fast bitfield that can hold `sizeof(usize) * 8` bits.
+/// Defines the functionality of fast bitfields whose number of bits is fixed by their type.
+/// The `capacity()` of a fixed bitfield is always `BITS`. Generic code that already holds a bitfield
+/// should prefer `FastBitField::capacity()`, which runtime sized bitfields implement as well.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::{FastBitField, FixedBitField, LargeBitField, SmallBitField};
+///
+/// const SLOTS: usize = LargeBitField::BITS;
+///
+/// fn get_sizes<B: FixedBitField>(bitfield: &B) -> (usize, usize) {
+///     (B::BITS, bitfield.capacity())
+/// }
+///
+/// assert_eq!(get_sizes(&LargeBitField::new()), (SLOTS, SLOTS));
+/// assert_eq!(
+///     get_sizes(&SmallBitField::new()),
+///     (SmallBitField::get_number_of_bits(), SmallBitField::get_number_of_bits())
+/// );
+/// ```
+pub trait FixedBitField: FastBitField {
+    /// Defines the number of bits available in the bitfield type.
+    const BITS: usize;
+
+    /// Gets the number of bits available in the bitfield type.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    fn get_number_of_bits() -> usize {
+        Self::BITS
+    }
+}
+
+/// Defines the errors reported by the fallible bitfield operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitFieldError {
+    /// The bit index is not less than the number of bits in the bitfield.
+    IndexOutOfRange,
+
+    /// The group index is not less than the number of groups in the bitfield.
+    GroupOutOfRange,
+
+    /// The buffer is too small to hold the serialized bitfield.
+    BufferTooSmall,
+
+    /// The serialized bitfield holds a different number of bits than the bitfield.
+    SizeMismatch,
+
+    /// The serialized bitfield is malformed.
+    InvalidFormat,
+}
+
+/// Defines how BitFieldError is displayed.
+impl core::fmt::Display for BitFieldError {
+    /// Formats the error as a message.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the message.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BitFieldError::IndexOutOfRange => f.write_str("bit index out of range"),
+            BitFieldError::GroupOutOfRange => f.write_str("group index out of range"),
+            BitFieldError::BufferTooSmall => f.write_str("buffer too small"),
+            BitFieldError::SizeMismatch => f.write_str("serialized bit count does not match"),
+            BitFieldError::InvalidFormat => f.write_str("invalid serialized bitfield"),
+        }
+    }
+}
+
+/// Defines the searches the shared bitfield helpers perform. Every FastBitField supports them, and
+/// bitfields that cannot implement FastBitField implement them directly so they can use the same
+/// helpers.
+trait SearchBitField {
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    fn get_bit_count(&self) -> usize;
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn find_next_set_bit(&self, index: usize) -> Option<usize>;
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    fn find_next_clear_bit(&self, index: usize) -> Option<usize>;
+}
+
+/// Defines the searches of every FastBitField in terms of its queries.
+impl<B: FastBitField> SearchBitField for B {
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    fn get_bit_count(&self) -> usize {
+        self.capacity()
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    fn find_next_set_bit(&self, index: usize) -> Option<usize> {
+        self.get_next_set_bit(index)
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    fn find_next_clear_bit(&self, index: usize) -> Option<usize> {
+        self.get_next_clear_bit(index)
+    }
+}
+
+/// Defines the primitive word types SmallBitField and LargeBitField can be built from.
+mod bit_word;
+pub use bit_word::BitWord;
+
+/// Defines a fast bitfield that can hold the bits of one word, `sizeof(usize) * 8` by default.
 mod small_bitfield;
-pub use small_bitfield::SmallBitField;
+pub use small_bitfield::{SmallBitField, SmallBitFieldIter};
 
-/// Defines a fast bitfield that can hold `sizeof(usize) * sizeof(usize) * 8` bits.
+/// Defines a fast bitfield that can hold `sizeof(usize) * sizeof(usize) * 8` bits, or the square of
+/// the number of bits in another word type.
 mod large_bitfield;
-pub use large_bitfield::LargeBitField;
+pub use large_bitfield::{LargeBitField, LargeBitFieldIter};
+
+/// Defines a fast bitfield that nests `sizeof(usize) * 8` sub bitfields under a summary layer.
+mod hierarchical_bitfield;
+pub use hierarchical_bitfield::HierarchicalBitField;
+
+/// Defines a heap allocated fast bitfield whose number of bits is chosen at runtime and can grow.
+#[cfg(feature = "alloc")]
+mod dynamic_bitfield;
+#[cfg(feature = "alloc")]
+pub use dynamic_bitfield::DynamicBitField;
+
+/// Defines bitfield views over memory provided by the caller.
+mod bitfield_view;
+pub use bitfield_view::{BitFieldMut, BitFieldRef};
+
+/// Defines a lock-free fast bitfield that can hold `sizeof(usize) * 8` bits.
+mod atomic_small_bitfield;
+pub use atomic_small_bitfield::AtomicSmallBitField;
+
+/// Defines a lock-free fast bitfield that can hold `sizeof(usize) * sizeof(usize) * 8` bits.
+mod atomic_large_bitfield;
+pub use atomic_large_bitfield::AtomicLargeBitField;
+
+/// Defines an allocator of unique ids backed by a large bitfield.
+mod id_allocator;
+pub use id_allocator::{IdAllocator, IdAllocatorError};
+
+/// Defines a queue of items with fixed capacity priority levels tracked by a fast bitfield.
+mod priority_queue;
+pub use priority_queue::PriorityQueue;
+
+/// Defines a hierarchical timer wheel that tracks occupied slots with large bitfields.
+mod timer_wheel;
+pub use timer_wheel::{TimerId, TimerWheel};
+
+/// Defines the serde implementations for the bitfields.
+#[cfg(feature = "serde")]
+mod serde_support;
+
+/// Panics if an index passed to an unchecked operation is out of range. The check is only performed
+/// when the `checked` feature is enabled.
+///
+/// # Arguments
+/// operation - The name of the unchecked operation.
+/// index - The index passed to the operation.
+/// limit - The number of valid indices.
+#[inline(always)]
+fn check_unchecked_index(operation: &str, index: usize, limit: usize) {
+    #[cfg(feature = "checked")]
+    assert!(
+        index < limit,
+        "{} called with index {} but only indices below {} are valid",
+        operation,
+        index,
+        limit
+    );
+
+    #[cfg(not(feature = "checked"))]
+    let _ = (operation, index, limit);
+}
+
+/// Formats the set bits of a bitfield as a set of indices, collapsing runs of consecutive set bits
+/// into inclusive ranges.
+///
+/// # Arguments
+/// bitfield - The bitfield to format.
+/// name - The name of the bitfield type.
+/// f - The formatter to write to.
+///
+/// # Returns
+/// The result of writing the bitfield.
+fn format_set_bits<B: SearchBitField>(
+    bitfield: &B,
+    name: &str,
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    write!(f, "{} ", name)?;
+    let mut set = f.debug_set();
+    let mut index = 0;
+    while let Some(start) = bitfield.find_next_set_bit(index) {
+        let end = bitfield
+            .find_next_clear_bit(start)
+            .unwrap_or_else(|| bitfield.get_bit_count());
+
+        if end - start == 1 {
+            set.entry(&start);
+        } else {
+            set.entry(&(start..=(end - 1)));
+        }
+
+        index = end;
+    }
+
+    set.finish()
+}
+
+/// Gets the number of bytes a bitfield takes up in the portable byte format.
+///
+/// The format is the number of bits as a little-endian `u64` followed by the bits packed into
+/// little-endian `u64` words, lowest bit first. Bits past the end of the bitfield in the last word
+/// are zero. The format is identical regardless of the size of `usize`.
+///
+/// # Arguments
+/// bits - The number of bits in the bitfield.
+///
+/// # Returns
+/// The number of bytes in the serialized bitfield.
+const fn get_serialized_size(bits: usize) -> usize {
+    8 + (bits.div_ceil(64) * 8)
+}
+
+/// Writes words holding a bitfield into the portable byte format.
+///
+/// # Arguments
+/// words - The words holding the bitfield.
+/// buffer - The buffer to write to.
+///
+/// # Returns
+/// The number of bytes written or `BitFieldError::BufferTooSmall` if buffer cannot hold them.
+fn write_portable_bytes(words: &[usize], buffer: &mut [u8]) -> Result<usize, BitFieldError> {
+    let word_bits = core::mem::size_of::<usize>() * 8;
+    let bits = words.len() * word_bits;
+    let size = get_serialized_size(bits);
+    if buffer.len() < size {
+        return Err(BitFieldError::BufferTooSmall);
+    }
+
+    buffer[..8].copy_from_slice(&(bits as u64).to_le_bytes());
+
+    //
+    // Pack as many usize words as fit into each u64, lowest word first.
+    //
+
+    let chunks = words.chunks(64 / word_bits);
+    let outputs = buffer[8..size].chunks_mut(8);
+    for (chunk, output) in chunks.zip(outputs) {
+        let mut value: u64 = 0;
+        for (index, word) in chunk.iter().enumerate() {
+            value |= (*word as u64) << (index * word_bits);
+        }
+
+        output.copy_from_slice(&value.to_le_bytes());
+    }
+
+    Ok(size)
+}
+
+/// Reads words holding a bitfield from the portable byte format.
+///
+/// # Arguments
+/// bytes - The serialized bitfield.
+/// words - The words to fill. The number of words determines the number of bits expected.
+///
+/// # Returns
+/// `Ok(())` if the words were filled.
+/// `BitFieldError::BufferTooSmall` if bytes is too short.
+/// `BitFieldError::SizeMismatch` if the serialized bit count does not match words.
+/// `BitFieldError::InvalidFormat` if bits past the end of the bitfield are set.
+fn read_portable_bytes(bytes: &[u8], words: &mut [usize]) -> Result<(), BitFieldError> {
+    let word_bits = core::mem::size_of::<usize>() * 8;
+    let bits = words.len() * word_bits;
+    let size = get_serialized_size(bits);
+    if bytes.len() < size {
+        return Err(BitFieldError::BufferTooSmall);
+    }
+
+    let mut header = [0; 8];
+    header.copy_from_slice(&bytes[..8]);
+    if u64::from_le_bytes(header) != bits as u64 {
+        return Err(BitFieldError::SizeMismatch);
+    }
+
+    let chunks = words.chunks_mut(64 / word_bits);
+    let inputs = bytes[8..size].chunks(8);
+    for (chunk, input) in chunks.zip(inputs) {
+        let mut value = [0; 8];
+        value.copy_from_slice(input);
+        let value = u64::from_le_bytes(value);
+
+        //
+        // Only the last u64 can cover fewer bits than it holds and the extra bits must be clear.
+        //
+
+        let covered_bits = chunk.len() * word_bits;
+        if covered_bits < 64 && (value >> covered_bits) != 0 {
+            return Err(BitFieldError::InvalidFormat);
+        }
+
+        for (index, word) in chunk.iter_mut().enumerate() {
+            *word = (value >> (index * word_bits)) as usize;
+        }
+    }
+
+    Ok(())
+}
 
 /// Gets the lowest set bit of a usize value.
 ///
@@ -156,3 +738,87 @@ fn find_highest_set_bit(value: usize) -> usize {
         debruijin::get_highest_set_bit(value)
     }
 }
+
+/// Finds the lowest run of consecutive clear bits in a bitfield.
+///
+/// # Arguments
+/// bitfield - The bitfield to search.
+/// len - The number of consecutive clear bits required.
+/// align - The alignment the first bit of the run must have.
+///
+/// # Returns
+/// The index of the first bit of the lowest aligned run or `None` if no such run exists or len or
+/// align is zero.
+fn search_clear_run<B: SearchBitField>(bitfield: &B, len: usize, align: usize) -> Option<usize> {
+    if len == 0 || align == 0 || len > bitfield.get_bit_count() {
+        return None;
+    }
+
+    let mut candidate = 0;
+    loop {
+        let misalignment = candidate % align;
+        if misalignment != 0 {
+            candidate = candidate.checked_add(align - misalignment)?;
+        }
+
+        if candidate > bitfield.get_bit_count() - len {
+            return None;
+        }
+
+        //
+        // Skip over set bits to the next clear bit, realigning if it moved the candidate.
+        //
+
+        let start = bitfield.find_next_clear_bit(candidate)?;
+        if start != candidate {
+            candidate = start;
+            continue;
+        }
+
+        //
+        // The run is only broken by a set bit before its end, in which case the search resumes
+        // after that bit.
+        //
+
+        match bitfield.find_next_set_bit(candidate) {
+            Some(set_bit) if set_bit < candidate + len => candidate = set_bit + 1,
+            _ => return Some(candidate),
+        }
+    }
+}
+
+/// Gets the set bit of a usize value with a given rank.
+///
+/// # Arguments
+/// value - The value to search.
+/// rank - The number of set bits that precede the bit to find.
+///
+/// # Returns
+/// The index of the set bit preceded by exactly rank set bits or `UNDEFINED` if rank is not less
+/// than the number of set bits in value.
+fn find_set_bit_with_rank(value: usize, rank: usize) -> usize {
+    let mut value = value;
+    let mut rank = rank;
+    let mut index = 0;
+    let mut width = core::mem::size_of::<usize>() * 8;
+
+    //
+    // Narrow down to the half that holds the bit, skipping the set bits of the lower half when
+    // moving to the upper half.
+    //
+
+    while width > 1 {
+        width /= 2;
+        let lower_half = value & ((1 << width) - 1);
+        let lower_count = lower_half.count_ones() as usize;
+        if rank >= lower_count {
+            rank -= lower_count;
+            value >>= width;
+            index += width;
+        } else {
+            value = lower_half;
+        }
+    }
+
+    index
+}
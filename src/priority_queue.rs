@@ -0,0 +1,400 @@
+use crate::FastBitField;
+
+/// Defines the structure for a single priority level of a priority queue.
+/// A level is a fixed capacity ring buffer holding its items in the order they were pushed.
+struct PriorityLevel<T, const DEPTH: usize> {
+    /// Holds the items, with empty slots set to `None`.
+    items: [Option<T>; DEPTH],
+
+    /// Holds the slot of the oldest item.
+    head: usize,
+
+    /// Holds the number of items in the level.
+    len: usize,
+}
+
+/// Defines functionality for PriorityLevel.
+impl<T, const DEPTH: usize> PriorityLevel<T, DEPTH> {
+    /// Creates a new, empty PriorityLevel
+    ///
+    /// # Returns
+    /// A PriorityLevel.
+    fn new() -> Self {
+        PriorityLevel {
+            items: core::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Gets the slot holding the item at a given position from the front of the level.
+    ///
+    /// # Arguments
+    /// position - Provides the number of items ahead of the item.
+    ///
+    /// # Returns
+    /// The slot index. DEPTH must be non-zero.
+    fn slot(&self, position: usize) -> usize {
+        (self.head + position) % DEPTH
+    }
+
+    /// Adds an item to the back of the level.
+    ///
+    /// # Arguments
+    /// item - Provides the item to add.
+    ///
+    /// # Returns
+    /// `Ok(())` if the item was added or `Err(item)` if the level is full.
+    fn push_back(&mut self, item: T) -> Result<(), T> {
+        if self.len == DEPTH {
+            return Err(item);
+        }
+
+        let slot = self.slot(self.len);
+        self.items[slot] = Some(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the item at a given position from the front of the level, preserving the order of
+    /// the remaining items.
+    ///
+    /// # Arguments
+    /// position - Provides the number of items ahead of the item to remove. Must be less than the
+    /// number of items in the level.
+    ///
+    /// # Returns
+    /// The removed item.
+    fn remove_at(&mut self, position: usize) -> Option<T> {
+        let item = self.items[self.slot(position)].take();
+
+        //
+        // Removing the oldest item only requires moving the head. Anything else requires closing
+        // the gap by moving the newer items forward.
+        //
+
+        if position == 0 {
+            self.head = self.slot(1);
+        } else {
+            for index in position..(self.len - 1) {
+                let next = self.items[self.slot(index + 1)].take();
+                let slot = self.slot(index);
+                self.items[slot] = next;
+            }
+        }
+
+        self.len -= 1;
+        item
+    }
+}
+
+/// Defines the structure for Priority Queues.
+/// A Priority Queue holds up to DEPTH items at each of LEVELS priority levels, handing out items of
+/// the same priority in the order they were pushed. A fast bitfield tracks which levels hold any
+/// items, so finding the highest or lowest priority item takes the same time regardless of how
+/// many levels are empty.
+///
+/// Larger priority values are higher priorities. LEVELS must be no more than the number of bits
+/// in B.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::{PriorityQueue, SmallBitField};
+///
+/// let mut queue = PriorityQueue::<&str, SmallBitField, 8, 4>::new();
+/// queue.push(2, "idle").unwrap();
+/// queue.push(6, "interrupt").unwrap();
+/// queue.push(6, "timer").unwrap();
+///
+/// assert_eq!(queue.pop_highest(), Some((6, "interrupt")));
+/// assert_eq!(queue.pop_highest(), Some((6, "timer")));
+/// assert_eq!(queue.pop_highest(), Some((2, "idle")));
+/// assert_eq!(queue.pop_highest(), None);
+/// ```
+pub struct PriorityQueue<T, B: FastBitField, const LEVELS: usize, const DEPTH: usize> {
+    /// Holds a bitfield describing which priority levels currently hold any items.
+    occupied: B,
+
+    /// Holds the items of each priority level.
+    levels: [PriorityLevel<T, DEPTH>; LEVELS],
+}
+
+/// Defines functionality for PriorityQueue.
+impl<T, B: FastBitField, const LEVELS: usize, const DEPTH: usize>
+    PriorityQueue<T, B, LEVELS, DEPTH>
+{
+    /// Creates a new, empty PriorityQueue
+    ///
+    /// # Returns
+    /// A PriorityQueue.
+    ///
+    /// # Panics
+    /// Panics if LEVELS is larger than the number of bits in B.
+    pub fn new() -> Self {
+        let occupied = B::new();
+        assert!(
+            LEVELS <= occupied.capacity(),
+            "PriorityQueue has more levels than its bitfield has bits"
+        );
+
+        PriorityQueue {
+            occupied,
+            levels: core::array::from_fn(|_| PriorityLevel::new()),
+        }
+    }
+
+    /// Gets the number of items in the queue.
+    ///
+    /// # Returns
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        self.levels.iter().map(|level| level.len).sum()
+    }
+
+    /// Gets the number of items at a priority level.
+    ///
+    /// # Arguments
+    /// priority - Provides the priority level to inspect.
+    ///
+    /// # Returns
+    /// The number of items or `None` if priority is invalid.
+    pub fn len_at(&self, priority: usize) -> Option<usize> {
+        self.levels.get(priority).map(|level| level.len)
+    }
+
+    /// Determines whether or not the queue is empty.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.occupied.is_empty()
+    }
+
+    /// Adds an item to the back of a priority level.
+    ///
+    /// # Arguments
+    /// priority - Provides the priority level to add the item to.
+    /// item - Provides the item to add.
+    ///
+    /// # Returns
+    /// `Ok(())` if the item was added or `Err(item)` if priority is invalid or the level is full.
+    pub fn push(&mut self, priority: usize, item: T) -> Result<(), T> {
+        let level = match self.levels.get_mut(priority) {
+            Some(level) => level,
+            None => return Err(item),
+        };
+
+        level.push_back(item)?;
+
+        //
+        // UNSAFE: priority is a valid level and LEVELS is no larger than the bitfield.
+        //
+
+        unsafe {
+            self.occupied.set_bit_unchecked(priority);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the oldest item of the highest non-empty priority level.
+    ///
+    /// # Returns
+    /// The priority and item or `None` if the queue is empty.
+    pub fn pop_highest(&mut self) -> Option<(usize, T)> {
+        let priority = self.occupied.get_highest_set_bit()?;
+        self.remove_at(priority, 0).map(|item| (priority, item))
+    }
+
+    /// Removes the oldest item of the lowest non-empty priority level.
+    ///
+    /// # Returns
+    /// The priority and item or `None` if the queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{LargeBitField, PriorityQueue};
+    ///
+    /// let mut queue = PriorityQueue::<u32, LargeBitField, 256, 2>::new();
+    /// queue.push(200, 1).unwrap();
+    /// queue.push(17, 2).unwrap();
+    ///
+    /// assert_eq!(queue.pop_lowest(), Some((17, 2)));
+    /// assert_eq!(queue.pop_lowest(), Some((200, 1)));
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn pop_lowest(&mut self) -> Option<(usize, T)> {
+        let priority = self.occupied.get_lowest_set_bit()?;
+        self.remove_at(priority, 0).map(|item| (priority, item))
+    }
+
+    /// Removes the oldest item at a priority level that is equal to a given item.
+    ///
+    /// # Arguments
+    /// priority - Provides the priority level to search.
+    /// item - Provides the item to compare against.
+    ///
+    /// # Returns
+    /// The removed item or `None` if priority is invalid or no item at that level is equal.
+    pub fn remove(&mut self, priority: usize, item: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let level = self.levels.get(priority)?;
+        let position = (0..level.len)
+            .position(|position| level.items[level.slot(position)].as_ref() == Some(item))?;
+
+        self.remove_at(priority, position)
+    }
+
+    /// Removes an item from a priority level, updating the occupied bitfield if the level becomes
+    /// empty.
+    ///
+    /// # Arguments
+    /// priority - Provides the priority level to remove from. Must be a non-empty level.
+    /// position - Provides the number of items ahead of the item to remove.
+    ///
+    /// # Returns
+    /// The removed item.
+    fn remove_at(&mut self, priority: usize, position: usize) -> Option<T> {
+        let level = &mut self.levels[priority];
+        let item = level.remove_at(position);
+        if level.len == 0 {
+            //
+            // UNSAFE: priority is a valid level and LEVELS is no larger than the bitfield.
+            //
+
+            unsafe {
+                self.occupied.clear_bit_unchecked(priority);
+            }
+        }
+
+        item
+    }
+}
+
+/// Defines the default PriorityQueue.
+impl<T, B: FastBitField, const LEVELS: usize, const DEPTH: usize> Default
+    for PriorityQueue<T, B, LEVELS, DEPTH>
+{
+    /// Creates a new, empty PriorityQueue
+    ///
+    /// # Returns
+    /// A PriorityQueue.
+    ///
+    /// # Panics
+    /// Panics if LEVELS is larger than the number of bits in B.
+    fn default() -> Self {
+        PriorityQueue::new()
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HierarchicalBitField, LargeBitField, SmallBitField};
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let queue = PriorityQueue::<usize, SmallBitField, 16, 4>::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.len_at(15), Some(0));
+        assert_eq!(queue.len_at(16), None);
+        assert!(PriorityQueue::<usize, SmallBitField, 16, 4>::default().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_levels_panics() {
+        PriorityQueue::<usize, SmallBitField, 1024, 1>::new();
+    }
+
+    #[test]
+    fn validate_push_and_pop() {
+        let mut queue = PriorityQueue::<usize, LargeBitField, 512, 3>::new();
+
+        //
+        // Invalid priorities and full levels hand the item back.
+        //
+
+        assert_eq!(queue.push(512, 1), Err(1));
+        assert_eq!(queue.push(7, 1), Ok(()));
+        assert_eq!(queue.push(7, 2), Ok(()));
+        assert_eq!(queue.push(7, 3), Ok(()));
+        assert_eq!(queue.push(7, 4), Err(4));
+
+        assert_eq!(queue.push(300, 5), Ok(()));
+        assert_eq!(queue.push(0, 6), Ok(()));
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.len_at(7), Some(3));
+
+        assert_eq!(queue.pop_highest(), Some((300, 5)));
+        assert_eq!(queue.pop_lowest(), Some((0, 6)));
+        assert_eq!(queue.pop_lowest(), Some((7, 1)));
+
+        //
+        // Freed space in a level should be reusable after the ring buffer wraps.
+        //
+
+        assert_eq!(queue.push(7, 4), Ok(()));
+        assert_eq!(queue.push(7, 5), Err(5));
+        assert_eq!(queue.pop_highest(), Some((7, 2)));
+        assert_eq!(queue.pop_highest(), Some((7, 3)));
+        assert_eq!(queue.pop_highest(), Some((7, 4)));
+        assert_eq!(queue.pop_highest(), None);
+        assert_eq!(queue.pop_lowest(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn validate_remove() {
+        let mut queue = PriorityQueue::<usize, SmallBitField, 8, 4>::new();
+        assert_eq!(queue.remove(8, &1), None);
+        assert_eq!(queue.remove(3, &1), None);
+
+        //
+        // Wrap the ring buffer so that removal has to close a gap across the end of it.
+        //
+
+        queue.push(3, 0).unwrap();
+        queue.push(3, 0).unwrap();
+        queue.pop_highest();
+        queue.pop_highest();
+        for item in 1..5 {
+            queue.push(3, item).unwrap();
+        }
+
+        assert_eq!(queue.remove(3, &2), Some(2));
+        assert_eq!(queue.remove(3, &2), None);
+        assert_eq!(queue.len_at(3), Some(3));
+        assert_eq!(queue.pop_highest(), Some((3, 1)));
+        assert_eq!(queue.remove(3, &4), Some(4));
+        assert_eq!(queue.remove(3, &3), Some(3));
+
+        //
+        // Removing the last item of a level should mark the level as empty.
+        //
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_lowest(), None);
+    }
+
+    #[test]
+    fn validate_hierarchical_levels() {
+        type Levels = HierarchicalBitField<SmallBitField>;
+
+        let mut queue = PriorityQueue::<usize, Levels, 1024, 1>::new();
+        for priority in (0..1024).step_by(100) {
+            queue.push(priority, priority).unwrap();
+        }
+
+        assert_eq!(queue.pop_highest(), Some((1000, 1000)));
+        assert_eq!(queue.pop_lowest(), Some((0, 0)));
+        assert_eq!(queue.pop_highest(), Some((900, 900)));
+    }
+}
@@ -0,0 +1,623 @@
+use crate::{BitFieldError, FastBitField, FixedBitField, LargeBitField, SmallBitField};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{self, Serialize, SerializeSeq, SerializeTuple, Serializer};
+
+/// Defines the operations the serde implementations need from a bitfield.
+trait SerdeBitField: FixedBitField + Sized {
+    /// Holds the number of bytes the bitfield takes up in the portable byte format.
+    const SERIALIZED_SIZE: usize;
+
+    /// Writes the bitfield into the portable byte format.
+    ///
+    /// # Arguments
+    /// buffer - Provides the buffer to write to.
+    ///
+    /// # Returns
+    /// The number of bytes written or an error if buffer is too small.
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, BitFieldError>;
+
+    /// Reads a bitfield from the portable byte format, rebuilding any caches.
+    ///
+    /// # Arguments
+    /// bytes - Provides the serialized bitfield.
+    ///
+    /// # Returns
+    /// The bitfield or an error if bytes do not hold a valid bitfield of this type.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, BitFieldError>;
+
+    /// Sets a range of bits in the bitfield.
+    ///
+    /// # Arguments
+    /// range - Provides the range of bits to set. Must be within the bitfield.
+    fn set_range(&mut self, range: Range<usize>);
+}
+
+/// Defines the serde operations for SmallBitField.
+impl SerdeBitField for SmallBitField {
+    const SERIALIZED_SIZE: usize = SmallBitField::SERIALIZED_SIZE;
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, BitFieldError> {
+        SmallBitField::to_bytes(self, buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, BitFieldError> {
+        SmallBitField::from_bytes(bytes)
+    }
+
+    fn set_range(&mut self, range: Range<usize>) {
+        SmallBitField::set_range(self, range)
+    }
+}
+
+/// Defines the serde operations for LargeBitField.
+impl SerdeBitField for LargeBitField {
+    const SERIALIZED_SIZE: usize = LargeBitField::SERIALIZED_SIZE;
+
+    fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, BitFieldError> {
+        LargeBitField::to_bytes(self, buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, BitFieldError> {
+        LargeBitField::from_bytes(bytes)
+    }
+
+    fn set_range(&mut self, range: Range<usize>) {
+        LargeBitField::set_range(self, range)
+    }
+}
+
+/// Holds enough bytes for the portable byte format of any of the serializable bitfields.
+const MAX_SERIALIZED_SIZE: usize = LargeBitField::SERIALIZED_SIZE;
+
+/// Defines an entry of the human-readable form: either a single set bit or an inclusive range of
+/// consecutive set bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SetBits {
+    /// A single set bit.
+    Single(usize),
+
+    /// An inclusive range of set bits.
+    Range(usize, usize),
+}
+
+/// Defines the serialization of human-readable entries.
+/// Single bits are serialized as integers and ranges as two element tuples.
+impl Serialize for SetBits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            SetBits::Single(index) => serializer.serialize_u64(index as u64),
+            SetBits::Range(start, end) => {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&(start as u64))?;
+                tuple.serialize_element(&(end as u64))?;
+                tuple.end()
+            }
+        }
+    }
+}
+
+/// Defines the deserialization of human-readable entries.
+impl<'de> Deserialize<'de> for SetBits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SetBitsVisitor)
+    }
+}
+
+/// Defines the visitor for human-readable entries.
+struct SetBitsVisitor;
+
+/// Defines how human-readable entries are visited.
+impl<'de> Visitor<'de> for SetBitsVisitor {
+    type Value = SetBits;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a bit index or an inclusive [start, end] range of bit indices")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<SetBits, E>
+    where
+        E: de::Error,
+    {
+        Ok(SetBits::Single(get_index(value)?))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<SetBits, E>
+    where
+        E: de::Error,
+    {
+        if value < 0 {
+            return Err(E::invalid_value(Unexpected::Signed(value), &self));
+        }
+
+        self.visit_u64(value as u64)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<SetBits, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let start: u64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+        let end: u64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+
+        if start > end {
+            return Err(de::Error::invalid_value(
+                Unexpected::Other("a descending range"),
+                &self,
+            ));
+        }
+
+        Ok(SetBits::Range(get_index(start)?, get_index(end)?))
+    }
+}
+
+/// Converts a deserialized bit index to a usize.
+///
+/// # Arguments
+/// value - Provides the deserialized index.
+///
+/// # Returns
+/// The index or an error if it does not fit in a usize.
+fn get_index<E: de::Error>(value: u64) -> Result<usize, E> {
+    if value > core::usize::MAX as u64 {
+        return Err(E::custom(BitFieldError::IndexOutOfRange));
+    }
+
+    Ok(value as usize)
+}
+
+/// Gets the next entry of the human-readable form.
+///
+/// # Arguments
+/// bitfield - Provides the bitfield to inspect.
+/// index - Provides the bit index to start searching from.
+///
+/// # Returns
+/// The entry and the index following it or `None` if no bits at or above index are set.
+fn get_next_set_bits<B: FastBitField>(bitfield: &B, index: usize) -> Option<(SetBits, usize)> {
+    let start = bitfield.get_next_set_bit(index)?;
+    let end = bitfield
+        .get_next_clear_bit(start)
+        .unwrap_or_else(|| bitfield.capacity());
+
+    if end - start == 1 {
+        Some((SetBits::Single(start), end))
+    } else {
+        Some((SetBits::Range(start, end - 1), end))
+    }
+}
+
+/// Serializes a bitfield. Human-readable formats get a list of set bit indices with runs of
+/// consecutive set bits collapsed into inclusive ranges, and other formats get the portable byte
+/// format.
+///
+/// # Arguments
+/// bitfield - Provides the bitfield to serialize.
+/// serializer - Provides the serializer to write to.
+///
+/// # Returns
+/// The result of the serializer.
+fn serialize_bitfield<B: SerdeBitField, S: Serializer>(
+    bitfield: &B,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if !serializer.is_human_readable() {
+        let mut buffer = [0; MAX_SERIALIZED_SIZE];
+        let size = bitfield.to_bytes(&mut buffer).map_err(ser::Error::custom)?;
+        return serializer.serialize_bytes(&buffer[..size]);
+    }
+
+    //
+    // Count the entries first so that formats requiring a length up front are supported.
+    //
+
+    let mut count = 0;
+    let mut index = 0;
+    while let Some((_, next)) = get_next_set_bits(bitfield, index) {
+        count += 1;
+        index = next;
+    }
+
+    let mut seq = serializer.serialize_seq(Some(count))?;
+    let mut index = 0;
+    while let Some((entry, next)) = get_next_set_bits(bitfield, index) {
+        seq.serialize_element(&entry)?;
+        index = next;
+    }
+
+    seq.end()
+}
+
+/// Deserializes a bitfield from either of the forms written by serialize_bitfield.
+///
+/// # Arguments
+/// deserializer - Provides the deserializer to read from.
+///
+/// # Returns
+/// The bitfield, with its caches rebuilt, or an error if the input does not describe a valid
+/// bitfield of type B.
+fn deserialize_bitfield<'de, B: SerdeBitField, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<B, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_seq(SetBitsListVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(PortableBytesVisitor(PhantomData))
+    }
+}
+
+/// Defines the visitor for the human-readable form of a bitfield.
+struct SetBitsListVisitor<B>(PhantomData<B>);
+
+/// Defines how the human-readable form of a bitfield is visited.
+impl<'de, B: SerdeBitField> Visitor<'de> for SetBitsListVisitor<B> {
+    type Value = B;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a list of bit indices and inclusive ranges below {}",
+            B::get_number_of_bits()
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<B, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bitfield = B::new();
+        while let Some(entry) = seq.next_element()? {
+            let (start, end) = match entry {
+                SetBits::Single(index) => (index, index),
+                SetBits::Range(start, end) => (start, end),
+            };
+
+            if end >= B::get_number_of_bits() {
+                return Err(de::Error::custom(BitFieldError::IndexOutOfRange));
+            }
+
+            bitfield.set_range(start..(end + 1));
+        }
+
+        Ok(bitfield)
+    }
+}
+
+/// Defines the visitor for the compact form of a bitfield.
+struct PortableBytesVisitor<B>(PhantomData<B>);
+
+/// Defines how the compact form of a bitfield is visited.
+impl<'de, B: SerdeBitField> Visitor<'de> for PortableBytesVisitor<B> {
+    type Value = B;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes in the portable byte format",
+            B::SERIALIZED_SIZE
+        )
+    }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<B, E>
+    where
+        E: de::Error,
+    {
+        if bytes.len() != B::SERIALIZED_SIZE {
+            return Err(E::invalid_length(bytes.len(), &self));
+        }
+
+        B::from_bytes(bytes).map_err(E::custom)
+    }
+
+    //
+    // Some binary formats hand bytes back as a sequence.
+    //
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<B, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut buffer = [0; MAX_SERIALIZED_SIZE];
+        let mut length = 0;
+        while let Some(byte) = seq.next_element()? {
+            if length == B::SERIALIZED_SIZE {
+                return Err(de::Error::invalid_length(length + 1, &self));
+            }
+
+            buffer[length] = byte;
+            length += 1;
+        }
+
+        self.visit_bytes(&buffer[..length])
+    }
+}
+
+/// Defines the serialization of SmallBitField.
+///
+/// # Examples
+/// Human-readable formats list the set bits, collapsing runs into inclusive ranges:
+/// `[1, [4, 6]]` for a bitfield with bits 1, 4, 5 and 6 set. Other formats use the portable byte
+/// format written by `SmallBitField::to_bytes`.
+impl Serialize for SmallBitField {
+    /// Serializes the bitfield.
+    ///
+    /// # Arguments
+    /// serializer - Provides the serializer to write to.
+    ///
+    /// # Returns
+    /// The result of the serializer.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bitfield(self, serializer)
+    }
+}
+
+/// Defines the deserialization of SmallBitField from either serialized form. Indices out of range,
+/// descending ranges and malformed bytes are rejected.
+impl<'de> Deserialize<'de> for SmallBitField {
+    /// Deserializes a bitfield.
+    ///
+    /// # Arguments
+    /// deserializer - Provides the deserializer to read from.
+    ///
+    /// # Returns
+    /// The bitfield or an error if the input does not describe a valid bitfield.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bitfield(deserializer)
+    }
+}
+
+/// Defines the serialization of LargeBitField.
+///
+/// # Examples
+/// Human-readable formats list the set bits, collapsing runs into inclusive ranges:
+/// `[1, [4, 300]]` for a bitfield with bit 1 and bits 4 through 300 set. Other formats use the
+/// portable byte format written by `LargeBitField::to_bytes`. The caches are not serialized.
+impl Serialize for LargeBitField {
+    /// Serializes the bitfield.
+    ///
+    /// # Arguments
+    /// serializer - Provides the serializer to write to.
+    ///
+    /// # Returns
+    /// The result of the serializer.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bitfield(self, serializer)
+    }
+}
+
+/// Defines the deserialization of LargeBitField from either serialized form. Indices out of range,
+/// descending ranges and malformed bytes are rejected, and the caches are rebuilt from the
+/// deserialized bits.
+impl<'de> Deserialize<'de> for LargeBitField {
+    /// Deserializes a bitfield.
+    ///
+    /// # Arguments
+    /// deserializer - Provides the deserializer to read from.
+    ///
+    /// # Returns
+    /// The bitfield or an error if the input does not describe a valid bitfield.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bitfield(deserializer)
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    /// Gets the portable byte format of a bitfield as a static slice for use in tokens.
+    fn get_bytes<B: SerdeBitField>(bitfield: &B) -> &'static [u8] {
+        let mut bytes = vec![0; B::SERIALIZED_SIZE];
+        bitfield.to_bytes(&mut bytes).unwrap();
+        Box::leak(bytes.into_boxed_slice())
+    }
+
+    #[test]
+    fn validate_small_readable_form() {
+        let mut small = SmallBitField::new();
+        assert_tokens(
+            &small.readable(),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+
+        small.set_bit(1);
+        small.set_range(4..7);
+        small.set_bit(SmallBitField::get_number_of_bits() - 1);
+        let last = (SmallBitField::get_number_of_bits() - 1) as u64;
+        assert_tokens(
+            &small.readable(),
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U64(1),
+                Token::Tuple { len: 2 },
+                Token::U64(4),
+                Token::U64(6),
+                Token::TupleEnd,
+                Token::U64(last),
+                Token::SeqEnd,
+            ],
+        );
+
+        //
+        // Overlapping, unordered and signed entries are accepted on load.
+        //
+
+        assert_de_tokens(
+            &small.readable(),
+            &[
+                Token::Seq { len: None },
+                Token::I64(last as i64),
+                Token::Seq { len: Some(2) },
+                Token::U8(5),
+                Token::U8(6),
+                Token::SeqEnd,
+                Token::U64(1),
+                Token::Tuple { len: 2 },
+                Token::U64(4),
+                Token::U64(5),
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn validate_large_readable_form() {
+        let mut large = LargeBitField::new();
+        large.set_range(3..300);
+        large.set_bit(1000);
+
+        assert_tokens(
+            &large.readable(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Tuple { len: 2 },
+                Token::U64(3),
+                Token::U64(299),
+                Token::TupleEnd,
+                Token::U64(1000),
+                Token::SeqEnd,
+            ],
+        );
+
+        //
+        // The caches must be rebuilt from the deserialized bits. Equality compares the caches, so
+        // the expected bitfield is built through the regular operations.
+        //
+
+        let mut large = LargeBitField::new();
+        large.set_range(70..81);
+        large.set_bit(2000);
+        assert_de_tokens(
+            &large.readable(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U64(2000),
+                Token::Tuple { len: 2 },
+                Token::U64(70),
+                Token::U64(80),
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn validate_compact_form() {
+        let mut small = SmallBitField::new();
+        small.set_bit(0);
+        small.set_bit(9);
+        assert_tokens(&small.compact(), &[Token::Bytes(get_bytes(&small))]);
+
+        let mut large = LargeBitField::new();
+        large.set_bit(0);
+        large.set_range(500..700);
+        assert_tokens(&large.compact(), &[Token::Bytes(get_bytes(&large))]);
+
+        //
+        // Byte sequences must be accepted as well.
+        //
+
+        let mut tokens = vec![Token::Seq {
+            len: Some(SmallBitField::SERIALIZED_SIZE),
+        }];
+
+        tokens.extend(get_bytes(&small).iter().map(|byte| Token::U8(*byte)));
+        tokens.push(Token::SeqEnd);
+        assert_de_tokens(&small.compact(), &tokens);
+    }
+
+    #[test]
+    fn validate_invalid_input_is_rejected() {
+        let bits = LargeBitField::get_number_of_bits() as u64;
+        assert_de_tokens_error::<serde_test::Readable<LargeBitField>>(
+            &[Token::Seq { len: Some(1) }, Token::U64(bits)],
+            "bit index out of range",
+        );
+
+        assert_de_tokens_error::<serde_test::Readable<LargeBitField>>(
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Tuple { len: 2 },
+                Token::U64(0),
+                Token::U64(bits),
+                Token::TupleEnd,
+            ],
+            "bit index out of range",
+        );
+
+        assert_de_tokens_error::<serde_test::Readable<SmallBitField>>(
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Tuple { len: 2 },
+                Token::U64(5),
+                Token::U64(4),
+                Token::TupleEnd,
+            ],
+            "invalid value: a descending range, expected a bit index or an inclusive [start, end] \
+             range of bit indices",
+        );
+
+        assert_de_tokens_error::<serde_test::Readable<SmallBitField>>(
+            &[Token::Seq { len: Some(1) }, Token::I64(-1)],
+            "invalid value: integer `-1`, expected a bit index or an inclusive [start, end] range \
+             of bit indices",
+        );
+
+        //
+        // Compact input must be exactly the serialized size with a matching header.
+        //
+
+        let small = SmallBitField::new();
+        let bytes = get_bytes(&small);
+        assert_de_tokens_error::<serde_test::Compact<SmallBitField>>(
+            &[Token::Bytes(&bytes[..bytes.len() - 1])],
+            &format!(
+                "invalid length {}, expected {} bytes in the portable byte format",
+                bytes.len() - 1,
+                SmallBitField::SERIALIZED_SIZE
+            ),
+        );
+
+        let large = LargeBitField::new();
+        let bytes = get_bytes(&large);
+        assert_de_tokens_error::<serde_test::Compact<SmallBitField>>(
+            &[Token::Bytes(&bytes[..SmallBitField::SERIALIZED_SIZE])],
+            "serialized bit count does not match",
+        );
+    }
+}
@@ -1,22 +1,266 @@
-use crate::{find_highest_set_bit, find_lowest_set_bit, FastBitField};
-
-/// Defines the maximum number of bits in a small bitfield.
+use crate::{
+    check_unchecked_index, format_set_bits, get_serialized_size, read_portable_bytes,
+    write_portable_bytes, BitFieldError, BitWord, FastBitField, FixedBitField,
+};
+use core::fmt;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range, Sub, SubAssign,
+};
+
+/// Defines the maximum number of bits in a small bitfield of `usize` words.
 const SMALL_BIT_FIELD_BIT_SIZE: usize = core::mem::size_of::<usize>() * 8;
 
 /// Defines the structure and fast_bitfield interface for Small Bitfieds.
-/// A Small Bitfield is a wrapper type that holds a `usize` bitfield.
-pub struct SmallBitField {
+/// A Small Bitfield is a wrapper type that holds a single word bitfield. The word is a `usize`
+/// unless another BitWord is chosen, such as `SmallBitField<u8>` for an 8 bit bitfield.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SmallBitField<W: BitWord = usize> {
     /// Holds the bitfield state.
-    bitfield: usize,
+    bitfield: W,
 }
 
-/// Defines functionality unique to SmallBitField.
+/// Defines functionality unique to SmallBitFields of `usize` words, including the word and portable
+/// byte format conversions.
 impl SmallBitField {
+    /// Defines the number of bits in a SmallBitField. This is the same as `FixedBitField::BITS` but
+    /// names the `usize` word type for type inference.
+    pub const BITS: usize = SMALL_BIT_FIELD_BIT_SIZE;
+
+    /// Defines the number of bytes a SmallBitField takes up in the portable byte format.
+    pub const SERIALIZED_SIZE: usize = get_serialized_size(SMALL_BIT_FIELD_BIT_SIZE);
+
+    /// Gets the number of bits available in the bitfield type. This is the same as
+    /// `FixedBitField::get_number_of_bits()` but does not require the trait to be in scope.
+    ///
+    /// # Returns
+    /// The number of bits available.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// assert_eq!(SmallBitField::get_number_of_bits(), core::mem::size_of::<usize>() * 8);
+    /// ```
+    pub const fn get_number_of_bits() -> usize {
+        SMALL_BIT_FIELD_BIT_SIZE
+    }
+
+    /// Creates a new, empty SmallBitField. This is the same as `FastBitField::new()` but does not
+    /// require the trait to be in scope, names the `usize` word type for type inference and can be
+    /// used to initialize a `const` or `static`.
+    ///
+    /// # Returns
+    /// A SmallBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::SmallBitField;
+    ///
+    /// static EMPTY: SmallBitField = SmallBitField::new();
+    ///
+    /// assert_eq!(EMPTY.test_bit(0), Some(false));
+    /// ```
+    pub const fn new() -> Self {
+        SmallBitField { bitfield: 0 }
+    }
+
+    /// Creates a SmallBitField with every bit set.
+    ///
+    /// # Returns
+    /// A full SmallBitField.
+    pub const fn full() -> Self {
+        SmallBitField {
+            bitfield: core::usize::MAX,
+        }
+    }
+
+    /// Creates a SmallBitField with a set of bits set. Invalid indices are ignored.
+    ///
+    /// # Arguments
+    /// bits - Provides the indices of the bits to set.
+    ///
+    /// # Returns
+    /// A SmallBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// const RESERVED: SmallBitField = SmallBitField::with_bits(&[0, 3, 5, core::usize::MAX]);
+    ///
+    /// assert_eq!(RESERVED.count_set_bits(), 3);
+    /// assert_eq!(RESERVED.get_highest_set_bit(), Some(5));
+    /// ```
+    pub const fn with_bits(bits: &[usize]) -> Self {
+        let mut small = SmallBitField::new();
+        let mut index = 0;
+        while index < bits.len() {
+            small = small.with_bit(bits[index]);
+            index += 1;
+        }
+
+        small
+    }
+
+    /// Creates a SmallBitField from its raw value.
+    ///
+    /// # Arguments
+    /// bitfield - Provides the bits to set.
+    ///
+    /// # Returns
+    /// A SmallBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let small = SmallBitField::from_raw(0b1010);
+    ///
+    /// assert_eq!(small.get_lowest_set_bit(), Some(1));
+    /// assert_eq!(small.into_words(), [0b1010]);
+    /// ```
+    pub const fn from_raw(bitfield: usize) -> Self {
+        SmallBitField { bitfield }
+    }
+
+    /// Creates a SmallBitField from its words.
+    ///
+    /// # Arguments
+    /// words - Provides the bits to set.
+    ///
+    /// # Returns
+    /// A SmallBitField.
+    pub const fn from_words(words: [usize; 1]) -> Self {
+        SmallBitField { bitfield: words[0] }
+    }
+
+    /// Sets a bit in the bit field, returning the bit field so that bits can be set at compile
+    /// time. This is the same as `FastBitField::set_bit()` but can be evaluated in a `const fn`.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to set. Invalid indices are ignored.
+    ///
+    /// # Returns
+    /// The SmallBitField with the bit set.
+    pub const fn with_bit(mut self, index: usize) -> Self {
+        if index < SMALL_BIT_FIELD_BIT_SIZE {
+            self.bitfield |= 1 << index;
+        }
+
+        self
+    }
+
+    /// Gets the value of a specific bit in the bit field. This is the same as
+    /// `FastBitField::test_bit()` but can be evaluated at compile time.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to test.
+    ///
+    /// # Returns
+    /// `Some(true)` if bit is set.
+    /// `Some(false)` if bit is cleared.
+    /// `None` if index is invalid.
+    pub const fn test_bit(&self, index: usize) -> Option<bool> {
+        if index < SMALL_BIT_FIELD_BIT_SIZE {
+            return Some((self.bitfield & (1 << index)) != 0);
+        }
+
+        None
+    }
+
+    /// Gets the words of the bit field.
+    ///
+    /// # Returns
+    /// A reference to the words of the bit field.
+    pub fn as_words(&self) -> &[usize; 1] {
+        core::array::from_ref(&self.bitfield)
+    }
+
+    /// Converts the bit field into its words.
+    ///
+    /// # Returns
+    /// The words of the bit field.
+    pub fn into_words(self) -> [usize; 1] {
+        [self.bitfield]
+    }
+
+    /// Writes the bit field into the portable byte format.
+    ///
+    /// The format is the number of bits as a little-endian `u64` followed by the bits packed into
+    /// little-endian `u64` words, so it can be exchanged between machines with different `usize`
+    /// sizes as long as the number of bits matches.
+    ///
+    /// # Arguments
+    /// buffer - Provides the buffer to write to. Must be at least `SERIALIZED_SIZE` bytes.
+    ///
+    /// # Returns
+    /// The number of bytes written or `BitFieldError::BufferTooSmall` if buffer is too small.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut buffer = [0; SmallBitField::SERIALIZED_SIZE];
+    /// let small = SmallBitField::from_raw(0x8001);
+    ///
+    /// assert_eq!(small.to_bytes(&mut buffer), Ok(SmallBitField::SERIALIZED_SIZE));
+    /// assert_eq!(SmallBitField::from_bytes(&buffer), Ok(small));
+    /// ```
+    pub fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, BitFieldError> {
+        write_portable_bytes(self.as_words(), buffer)
+    }
+
+    /// Reads a bit field from the portable byte format.
+    ///
+    /// # Arguments
+    /// bytes - Provides the serialized bit field. Bytes past `SERIALIZED_SIZE` are ignored.
+    ///
+    /// # Returns
+    /// The bit field or an error if bytes is too short, holds a different number of bits or is
+    /// malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BitFieldError> {
+        let mut words = [0; 1];
+        read_portable_bytes(bytes, &mut words)?;
+        Ok(SmallBitField::from_words(words))
+    }
+}
+
+/// Defines functionality unique to SmallBitField.
+impl<W: BitWord> SmallBitField<W> {
+    /// Creates a SmallBitField from its word.
+    ///
+    /// # Arguments
+    /// word - Provides the bits to set.
+    ///
+    /// # Returns
+    /// A SmallBitField.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let small = SmallBitField::from_word(0b1010_0000u8);
+    ///
+    /// assert_eq!(small.get_lowest_set_bit(), Some(5));
+    /// assert_eq!(small.get_word(), 0b1010_0000);
+    /// ```
+    pub fn from_word(word: W) -> Self {
+        SmallBitField { bitfield: word }
+    }
+
+    /// Gets the word of the bit field.
+    ///
+    /// # Returns
+    /// The word holding the bits of the bit field.
+    pub fn get_word(&self) -> W {
+        self.bitfield
+    }
+
     /// Sets bits in the bit field.
     ///
     /// # Arguments
     /// field - Provides the bits to be set.
-    pub fn set_field(&mut self, field: usize) {
+    pub fn set_field(&mut self, field: W) {
         self.bitfield |= field;
     }
 
@@ -24,34 +268,171 @@ impl SmallBitField {
     ///
     /// # Arguments
     /// field - Provides the bits to be cleared.
-    pub fn clear_field(&mut self, field: usize) {
+    pub fn clear_field(&mut self, field: W) {
         self.bitfield &= !field;
     }
-}
 
-/// Defines the FastBitField interface for SmallBitField.
-impl FastBitField for SmallBitField {
-    /// Creates a new, empty SmallBitField
+    /// Sets a range of bits in the bit field.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to set.
+    ///
+    /// # Note
+    /// If the range extends past the end of the bit field or starts after it ends, the field will
+    /// remain unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_range(2..5);
+    ///
+    /// assert_eq!(small.is_range_set(2..5), Some(true));
+    /// assert_eq!(small.count_set_bits(), 3);
+    /// ```
+    pub fn set_range(&mut self, range: Range<usize>) {
+        if let Some(mask) = Self::get_mask_for_range(&range) {
+            self.bitfield |= mask;
+        }
+    }
+
+    /// Clears a range of bits in the bit field.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to clear.
+    ///
+    /// # Note
+    /// If the range extends past the end of the bit field or starts after it ends, the field will
+    /// remain unchanged.
+    pub fn clear_range(&mut self, range: Range<usize>) {
+        if let Some(mask) = Self::get_mask_for_range(&range) {
+            self.bitfield &= !mask;
+        }
+    }
+
+    /// Determines whether or not every bit in a range is set.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to test.
     ///
     /// # Returns
-    /// A SmallBitField.
-    fn new() -> Self {
-        SmallBitField { bitfield: 0 }
+    /// `Some(true)` if every bit in the range is set.
+    /// `Some(false)` if any bit in the range is cleared.
+    /// `None` if the range is invalid.
+    pub fn is_range_set(&self, range: Range<usize>) -> Option<bool> {
+        let mask = Self::get_mask_for_range(&range)?;
+        Some((self.bitfield & mask) == mask)
     }
 
-    /// Gets the number of bits available in the bitfield type.
+    /// Determines whether or not every bit in a range is cleared.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to test.
     ///
     /// # Returns
-    /// The number of bits available.
+    /// `Some(true)` if every bit in the range is cleared.
+    /// `Some(false)` if any bit in the range is set.
+    /// `None` if the range is invalid.
     ///
     /// # Examples
     /// ```
     /// use fast_bitfield::{FastBitField, SmallBitField};
     ///
-    /// assert_eq!(SmallBitField::get_number_of_bits(), core::mem::size_of::<usize>() * 8);
+    /// let mut small = SmallBitField::new();
+    /// small.set_bit(5);
+    ///
+    /// assert_eq!(small.is_range_clear(0..5), Some(true));
+    /// assert_eq!(small.is_range_clear(0..6), Some(false));
+    /// assert_eq!(small.is_range_clear(0..core::usize::MAX), None);
     /// ```
-    fn get_number_of_bits() -> usize {
-        SMALL_BIT_FIELD_BIT_SIZE
+    pub fn is_range_clear(&self, range: Range<usize>) -> Option<bool> {
+        let mask = Self::get_mask_for_range(&range)?;
+        Some((self.bitfield & mask) == W::ZERO)
+    }
+
+    /// Gets the number of set bits in a range.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to count.
+    ///
+    /// # Returns
+    /// The number of set bits in the range or `None` if the range is invalid.
+    pub fn count_in_range(&self, range: Range<usize>) -> Option<usize> {
+        let mask = Self::get_mask_for_range(&range)?;
+        Some((self.bitfield & mask).count_set_bits())
+    }
+
+    /// Gets the mask covering a range of bits.
+    ///
+    /// # Arguments
+    /// range - Provides the bits to cover.
+    ///
+    /// # Returns
+    /// The mask with every bit in the range set or `None` if the range is invalid. An empty range
+    /// results in an empty mask.
+    fn get_mask_for_range(range: &Range<usize>) -> Option<W> {
+        if range.start > range.end || range.end > W::BITS {
+            return None;
+        }
+
+        if range.start == range.end {
+            return Some(W::ZERO);
+        }
+
+        Some(W::get_range_mask(range.start, range.end))
+    }
+
+    /// Gets an iterator over the set bits in the bit field.
+    ///
+    /// # Returns
+    /// An iterator yielding the index of each set bit, from lowest to highest. The iterator may
+    /// also be consumed from highest to lowest.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_bit(3);
+    /// small.set_bit(17);
+    /// small.set_bit(30);
+    ///
+    /// let mut iter = small.iter_set_bits();
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next_back(), Some(30));
+    /// assert_eq!(iter.next(), Some(17));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_set_bits(&self) -> SmallBitFieldIter<W> {
+        SmallBitFieldIter {
+            remaining: self.bitfield,
+        }
+    }
+}
+
+/// Defines the FixedBitField interface for SmallBitField.
+impl<W: BitWord> FixedBitField for SmallBitField<W> {
+    /// Defines the number of bits in a SmallBitField.
+    const BITS: usize = W::BITS;
+}
+
+/// Defines the FastBitField interface for SmallBitField.
+impl<W: BitWord> FastBitField for SmallBitField<W> {
+    /// Creates a new, empty SmallBitField
+    ///
+    /// # Returns
+    /// A SmallBitField.
+    fn new() -> Self {
+        SmallBitField { bitfield: W::ZERO }
+    }
+
+    /// Gets the number of bits available in the bitfield.
+    ///
+    /// # Returns
+    /// The number of bits available, which is always `get_number_of_bits()`.
+    fn capacity(&self) -> usize {
+        W::BITS
     }
 
     /// Sets a bit in the bit field
@@ -59,8 +440,8 @@ impl FastBitField for SmallBitField {
     /// # Arguments
     /// index - Provides the bit to set.
     fn set_bit(&mut self, index: usize) {
-        if index < SMALL_BIT_FIELD_BIT_SIZE {
-            self.bitfield |= 1 << index;
+        if index < W::BITS {
+            self.bitfield |= W::ONE << index;
         }
     }
 
@@ -69,8 +450,8 @@ impl FastBitField for SmallBitField {
     /// # Arguments
     /// index - Provides the bit to clear.
     fn clear_bit(&mut self, index: usize) {
-        if index < SMALL_BIT_FIELD_BIT_SIZE {
-            self.bitfield &= !(1 << index);
+        if index < W::BITS {
+            self.bitfield &= !(W::ONE << index);
         }
     }
 
@@ -130,6 +511,78 @@ impl FastBitField for SmallBitField {
         Some(self.get_highest_set_bit_unchecked())
     }
 
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_bit(4);
+    /// small.set_bit(9);
+    ///
+    /// assert_eq!(small.get_next_set_bit(0), Some(4));
+    /// assert_eq!(small.get_next_set_bit(4), Some(4));
+    /// assert_eq!(small.get_next_set_bit(5), Some(9));
+    /// assert_eq!(small.get_next_set_bit(10), None);
+    /// assert_eq!(small.get_next_set_bit(core::usize::MAX), None);
+    /// ```
+    fn get_next_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= W::BITS {
+            return None;
+        }
+
+        let remaining = self.bitfield & (W::MAX << index);
+        if remaining == W::ZERO {
+            return None;
+        }
+
+        Some(remaining.find_lowest_set_bit())
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `None` if no such bit is set or
+    /// index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_bit(4);
+    /// small.set_bit(9);
+    ///
+    /// assert_eq!(small.get_previous_set_bit(20), Some(9));
+    /// assert_eq!(small.get_previous_set_bit(9), Some(9));
+    /// assert_eq!(small.get_previous_set_bit(8), Some(4));
+    /// assert_eq!(small.get_previous_set_bit(3), None);
+    /// assert_eq!(small.get_previous_set_bit(core::usize::MAX), None);
+    /// ```
+    fn get_previous_set_bit(&self, index: usize) -> Option<usize> {
+        if index >= W::BITS {
+            return None;
+        }
+
+        let remaining = self.bitfield & (W::MAX >> (W::BITS - 1 - index));
+        if remaining == W::ZERO {
+            return None;
+        }
+
+        Some(remaining.find_highest_set_bit())
+    }
+
     /// Gets the value of a specific bit in the bit field.
     ///
     /// # Arguments
@@ -154,7 +607,7 @@ impl FastBitField for SmallBitField {
     /// assert_eq!(small.test_bit(5), Some(true));
     /// ```
     fn test_bit(&self, index: usize) -> Option<bool> {
-        if index < SMALL_BIT_FIELD_BIT_SIZE {
+        if index < W::BITS {
             //
             // UNSAFE: The index check that makes the unsafe variant unsafe is performed before
             // calling it.
@@ -185,7 +638,193 @@ impl FastBitField for SmallBitField {
     /// assert!(!small.is_empty());
     /// ```
     fn is_empty(&self) -> bool {
-        self.bitfield == 0
+        self.bitfield == W::ZERO
+    }
+
+    /// Determines whether or not every bit in the bitfield is set.
+    ///
+    /// # Returns
+    /// `true` if full, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(core::usize::MAX);
+    /// assert!(small.is_full());
+    ///
+    /// small.clear_bit(0);
+    /// assert!(!small.is_full());
+    /// ```
+    fn is_full(&self) -> bool {
+        self.bitfield == W::MAX
+    }
+
+    /// Gets the number of set bits in the bitfield.
+    ///
+    /// # Returns
+    /// The number of set bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// assert_eq!(small.count_set_bits(), 0);
+    ///
+    /// small.set_field(0b1011);
+    /// assert_eq!(small.count_set_bits(), 3);
+    /// ```
+    fn count_set_bits(&self) -> usize {
+        self.bitfield.count_set_bits()
+    }
+
+    /// Gets the number of set bits below a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to count up to, exclusive.
+    ///
+    /// # Returns
+    /// The number of set bits with an index less than index. If index is greater than or equal to
+    /// `capacity()`, every set bit is counted.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(0b1011);
+    ///
+    /// assert_eq!(small.rank(0), 0);
+    /// assert_eq!(small.rank(1), 1);
+    /// assert_eq!(small.rank(3), 2);
+    /// assert_eq!(small.rank(core::usize::MAX), 3);
+    /// ```
+    fn rank(&self, index: usize) -> usize {
+        if index >= W::BITS {
+            return self.count_set_bits();
+        }
+
+        (self.bitfield & !(W::MAX << index)).count_set_bits()
+    }
+
+    /// Gets the set bit with a given rank.
+    ///
+    /// # Arguments
+    /// rank - Provides the number of set bits that precede the bit to find.
+    ///
+    /// # Returns
+    /// The index of the set bit preceded by exactly rank set bits or `None` if fewer than
+    /// `rank + 1` bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(0b1011);
+    ///
+    /// assert_eq!(small.select(0), Some(0));
+    /// assert_eq!(small.select(1), Some(1));
+    /// assert_eq!(small.select(2), Some(3));
+    /// assert_eq!(small.select(3), None);
+    /// ```
+    fn select(&self, rank: usize) -> Option<usize> {
+        if rank >= self.count_set_bits() {
+            return None;
+        }
+
+        Some(self.bitfield.find_set_bit_with_rank(rank))
+    }
+
+    /// Gets the lowest clear bit.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `None` if every bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(core::usize::MAX);
+    ///
+    /// assert_eq!(small.get_lowest_clear_bit(), None);
+    ///
+    /// small.clear_bit(5);
+    /// assert_eq!(small.get_lowest_clear_bit(), Some(5));
+    ///
+    /// small.clear_bit(9);
+    /// assert_eq!(small.get_lowest_clear_bit(), Some(5));
+    /// ```
+    fn get_lowest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_lowest_clear_bit_unchecked())
+    }
+
+    /// Gets the highest clear bit.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `None` if every bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(core::usize::MAX);
+    ///
+    /// assert_eq!(small.get_highest_clear_bit(), None);
+    ///
+    /// small.clear_bit(5);
+    /// assert_eq!(small.get_highest_clear_bit(), Some(5));
+    ///
+    /// small.clear_bit(9);
+    /// assert_eq!(small.get_highest_clear_bit(), Some(9));
+    /// ```
+    fn get_highest_clear_bit(&self) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        Some(self.get_highest_clear_bit_unchecked())
+    }
+
+    /// Gets the lowest clear bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest clear bit index greater than or equal to index or `None` if no such bit is clear
+    /// or index is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(0b1110);
+    ///
+    /// assert_eq!(small.get_next_clear_bit(0), Some(0));
+    /// assert_eq!(small.get_next_clear_bit(1), Some(4));
+    /// assert_eq!(small.get_next_clear_bit(core::usize::MAX), None);
+    /// ```
+    fn get_next_clear_bit(&self, index: usize) -> Option<usize> {
+        if index >= W::BITS {
+            return None;
+        }
+
+        let remaining = !self.bitfield & (W::MAX << index);
+        if remaining == W::ZERO {
+            return None;
+        }
+
+        Some(remaining.find_lowest_set_bit())
     }
 
     /// Gets the lowest set bit, guaranteed to have no branches and be in constant time, completely
@@ -211,7 +850,7 @@ impl FastBitField for SmallBitField {
     /// assert_eq!(small.get_lowest_set_bit_unchecked(), 0);
     /// ```
     fn get_lowest_set_bit_unchecked(&self) -> usize {
-        find_lowest_set_bit(self.bitfield)
+        self.bitfield.find_lowest_set_bit()
     }
 
     /// Gets the highest set bit, guaranteed to have no branches and be in constant time, completely
@@ -237,7 +876,70 @@ impl FastBitField for SmallBitField {
     /// assert_eq!(small.get_highest_set_bit_unchecked(), 1);
     /// ```
     fn get_highest_set_bit_unchecked(&self) -> usize {
-        find_highest_set_bit(self.bitfield)
+        self.bitfield.find_highest_set_bit()
+    }
+
+    /// Gets the lowest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The lowest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_lowest_clear_bit_unchecked(&self) -> usize {
+        (!self.bitfield).find_lowest_set_bit()
+    }
+
+    /// Gets the highest clear bit, guaranteed to have no branches and be in constant time,
+    /// completely invariant of the state of the bit field. If every bit is set, the result is
+    /// undefined.
+    ///
+    /// This function should only be used if the caller can guarantee the bitfield will always
+    /// have at least one bit clear.
+    ///
+    /// # Returns
+    /// The highest clear bit index or `UNDEFINED` if every bit is set.
+    fn get_highest_clear_bit_unchecked(&self) -> usize {
+        (!self.bitfield).find_highest_set_bit()
+    }
+
+    /// Gets the lowest set bit at or after a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The lowest set bit index greater than or equal to index or `UNDEFINED` if no such bit is
+    /// set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or after the index. The caller must guarantee that the index is less
+    /// than `capacity()` and that a bit at or after the index is set.
+    unsafe fn get_next_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index("get_next_set_bit_unchecked", index, W::BITS);
+
+        (self.bitfield & (W::MAX << index)).find_lowest_set_bit()
+    }
+
+    /// Gets the highest set bit at or before a given index.
+    ///
+    /// # Arguments
+    /// index - Provides the bit to start searching from.
+    ///
+    /// # Returns
+    /// The highest set bit index less than or equal to index or `UNDEFINED` if no such bit is set.
+    ///
+    /// # Unsafe
+    /// This unsafe variant does not check if the index is valid for the size of the bit field or
+    /// if a set bit exists at or before the index. The caller must guarantee that the index is
+    /// less than `capacity()` and that a bit at or before the index is set.
+    unsafe fn get_previous_set_bit_unchecked(&self, index: usize) -> usize {
+        check_unchecked_index("get_previous_set_bit_unchecked", index, W::BITS);
+
+        (self.bitfield & (W::MAX >> (W::BITS - 1 - index))).find_highest_set_bit()
     }
 
     /// Sets a bit in the bit field.
@@ -247,9 +949,11 @@ impl FastBitField for SmallBitField {
     ///
     /// # Unsafe
     /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
     unsafe fn set_bit_unchecked(&mut self, index: usize) {
-        self.bitfield |= 1 << index;
+        check_unchecked_index("set_bit_unchecked", index, W::BITS);
+
+        self.bitfield |= W::ONE << index;
     }
 
     /// Clears a bit in the bit field
@@ -259,9 +963,11 @@ impl FastBitField for SmallBitField {
     ///
     /// # Unsafe
     /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
     unsafe fn clear_bit_unchecked(&mut self, index: usize) {
-        self.bitfield &= !(1 << index);
+        check_unchecked_index("clear_bit_unchecked", index, W::BITS);
+
+        self.bitfield &= !(W::ONE << index);
     }
 
     /// Gets the value of a specific bit in the bit field.
@@ -275,7 +981,7 @@ impl FastBitField for SmallBitField {
     ///
     /// # Unsafe
     /// This unsafe variant does not check if the index is valid for the size of
-    /// the bit field. The caller must guarantee that the index is less than `get_number_of_bits()`.
+    /// the bit field. The caller must guarantee that the index is less than `capacity()`.
     ///
     /// # Examples
     /// ```
@@ -292,10 +998,221 @@ impl FastBitField for SmallBitField {
     /// }
     /// ```
     unsafe fn test_bit_unchecked(&self, index: usize) -> bool {
-        (self.bitfield & (1 << index)) != 0
+        check_unchecked_index("test_bit_unchecked", index, W::BITS);
+
+        (self.bitfield & (W::ONE << index)) != W::ZERO
+    }
+}
+
+/// Defines the union of two SmallBitFields.
+impl<W: BitWord> BitOr for SmallBitField<W> {
+    type Output = SmallBitField<W>;
+
+    /// Gets the bits set in either bitfield.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A SmallBitField holding the union.
+    fn bitor(self, rhs: SmallBitField<W>) -> SmallBitField<W> {
+        SmallBitField {
+            bitfield: self.bitfield | rhs.bitfield,
+        }
+    }
+}
+
+/// Defines the in place union of two SmallBitFields.
+impl<W: BitWord> BitOrAssign for SmallBitField<W> {
+    /// Sets every bit that is set in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitor_assign(&mut self, rhs: SmallBitField<W>) {
+        self.bitfield |= rhs.bitfield;
+    }
+}
+
+/// Defines the intersection of two SmallBitFields.
+impl<W: BitWord> BitAnd for SmallBitField<W> {
+    type Output = SmallBitField<W>;
+
+    /// Gets the bits set in both bitfields.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A SmallBitField holding the intersection.
+    fn bitand(self, rhs: SmallBitField<W>) -> SmallBitField<W> {
+        SmallBitField {
+            bitfield: self.bitfield & rhs.bitfield,
+        }
+    }
+}
+
+/// Defines the in place intersection of two SmallBitFields.
+impl<W: BitWord> BitAndAssign for SmallBitField<W> {
+    /// Clears every bit that is not set in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitand_assign(&mut self, rhs: SmallBitField<W>) {
+        self.bitfield &= rhs.bitfield;
+    }
+}
+
+/// Defines the symmetric difference of two SmallBitFields.
+impl<W: BitWord> BitXor for SmallBitField<W> {
+    type Output = SmallBitField<W>;
+
+    /// Gets the bits set in exactly one of the bitfields.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A SmallBitField holding the symmetric difference.
+    fn bitxor(self, rhs: SmallBitField<W>) -> SmallBitField<W> {
+        SmallBitField {
+            bitfield: self.bitfield ^ rhs.bitfield,
+        }
+    }
+}
+
+/// Defines the in place symmetric difference of two SmallBitFields.
+impl<W: BitWord> BitXorAssign for SmallBitField<W> {
+    /// Toggles every bit that is set in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn bitxor_assign(&mut self, rhs: SmallBitField<W>) {
+        self.bitfield ^= rhs.bitfield;
+    }
+}
+
+/// Defines the difference of two SmallBitFields.
+impl<W: BitWord> Sub for SmallBitField<W> {
+    type Output = SmallBitField<W>;
+
+    /// Gets the bits set in this bitfield but not in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    ///
+    /// # Returns
+    /// A SmallBitField holding the difference.
+    fn sub(self, rhs: SmallBitField<W>) -> SmallBitField<W> {
+        SmallBitField {
+            bitfield: self.bitfield & !rhs.bitfield,
+        }
+    }
+}
+
+/// Defines the in place difference of two SmallBitFields.
+impl<W: BitWord> SubAssign for SmallBitField<W> {
+    /// Clears every bit that is set in rhs.
+    ///
+    /// # Arguments
+    /// rhs - Provides the bitfield to combine with.
+    fn sub_assign(&mut self, rhs: SmallBitField<W>) {
+        self.bitfield &= !rhs.bitfield;
+    }
+}
+
+/// Defines the complement of a SmallBitField.
+impl<W: BitWord> Not for SmallBitField<W> {
+    type Output = SmallBitField<W>;
+
+    /// Gets the bits that are not set in the bitfield.
+    ///
+    /// # Returns
+    /// A SmallBitField holding the complement.
+    fn not(self) -> SmallBitField<W> {
+        SmallBitField {
+            bitfield: !self.bitfield,
+        }
+    }
+}
+
+/// Defines how a SmallBitField is displayed for debugging.
+impl<W: BitWord> fmt::Debug for SmallBitField<W> {
+    /// Formats the set bits of the bitfield, collapsing runs of set bits into ranges.
+    ///
+    /// # Arguments
+    /// f - Provides the formatter to write to.
+    ///
+    /// # Returns
+    /// The result of writing the bitfield.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::{FastBitField, SmallBitField};
+    ///
+    /// let mut small = SmallBitField::new();
+    /// small.set_field(0b1000_1111);
+    ///
+    /// assert_eq!(format!("{:?}", small), "SmallBitField {0..=3, 7}");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_set_bits(self, "SmallBitField", f)
+    }
+}
+
+/// Defines an iterator over the set bits of a SmallBitField.
+pub struct SmallBitFieldIter<W: BitWord = usize> {
+    /// Holds the set bits that have not been yielded yet.
+    remaining: W,
+}
+
+/// Defines the Iterator interface for SmallBitFieldIter.
+impl<W: BitWord> Iterator for SmallBitFieldIter<W> {
+    type Item = usize;
+
+    /// Gets the lowest set bit that has not been yielded yet.
+    ///
+    /// # Returns
+    /// The lowest remaining set bit index or `None` if all set bits have been yielded.
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == W::ZERO {
+            return None;
+        }
+
+        let index = self.remaining.find_lowest_set_bit();
+        self.remaining = self.remaining.clear_lowest_set_bit();
+        Some(index)
+    }
+
+    /// Gets the exact number of set bits that have not been yielded yet.
+    ///
+    /// # Returns
+    /// The remaining number of set bits as both the lower and upper bound.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.remaining.count_set_bits();
+        (count, Some(count))
+    }
+}
+
+/// Defines the DoubleEndedIterator interface for SmallBitFieldIter.
+impl<W: BitWord> DoubleEndedIterator for SmallBitFieldIter<W> {
+    /// Gets the highest set bit that has not been yielded yet.
+    ///
+    /// # Returns
+    /// The highest remaining set bit index or `None` if all set bits have been yielded.
+    fn next_back(&mut self) -> Option<usize> {
+        if self.remaining == W::ZERO {
+            return None;
+        }
+
+        let index = self.remaining.find_highest_set_bit();
+        self.remaining &= !(W::ONE << index);
+        Some(index)
     }
 }
 
+/// Defines the ExactSizeIterator interface for SmallBitFieldIter.
+impl<W: BitWord> ExactSizeIterator for SmallBitFieldIter<W> {}
+
 //
 // Unit Tests
 //
@@ -325,6 +1242,13 @@ mod tests {
             SmallBitField::get_number_of_bits(),
             SMALL_BIT_FIELD_BIT_SIZE
         );
+
+        assert_eq!(
+            <SmallBitField as FixedBitField>::get_number_of_bits(),
+            SMALL_BIT_FIELD_BIT_SIZE
+        );
+        assert_eq!(SmallBitField::BITS, SMALL_BIT_FIELD_BIT_SIZE);
+        assert_eq!(SmallBitField::new().capacity(), SMALL_BIT_FIELD_BIT_SIZE);
     }
 
     #[test]
@@ -575,4 +1499,541 @@ mod tests {
         small.clear_field(0);
         assert_eq!(small.bitfield, a_s);
     }
+
+    #[test]
+    fn validate_iter_set_bits() {
+        let mut small = SmallBitField::new();
+
+        //
+        // Empty bitfield yields nothing.
+        //
+
+        assert_eq!(small.iter_set_bits().next(), None);
+        assert_eq!(small.iter_set_bits().next_back(), None);
+
+        let fives = 0x55555555_55555555 & core::usize::MAX;
+        small.set_field(fives);
+
+        let mut expected = 0;
+        let mut iter = small.iter_set_bits();
+        assert_eq!(iter.len(), SMALL_BIT_FIELD_BIT_SIZE / 2);
+        for index in &mut iter {
+            assert_eq!(index, expected);
+            expected += 2;
+        }
+
+        assert_eq!(expected, SMALL_BIT_FIELD_BIT_SIZE);
+        assert_eq!(iter.len(), 0);
+
+        expected = SMALL_BIT_FIELD_BIT_SIZE;
+        for index in small.iter_set_bits().rev() {
+            expected -= 2;
+            assert_eq!(index, expected);
+        }
+
+        assert_eq!(expected, 0);
+    }
+
+    #[test]
+    fn validate_iter_set_bits_from_both_ends() {
+        let mut small = SmallBitField::new();
+        small.set_bit(0);
+        small.set_bit(1);
+        small.set_bit(SMALL_BIT_FIELD_BIT_SIZE - 1);
+
+        let mut iter = small.iter_set_bits();
+        assert_eq!(iter.next_back(), Some(SMALL_BIT_FIELD_BIT_SIZE - 1));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn validate_get_next_set_bit() {
+        let mut small = SmallBitField::new();
+
+        //
+        // Out of bounds and empty should result in None for checked variant
+        //
+
+        assert_eq!(small.get_next_set_bit(SMALL_BIT_FIELD_BIT_SIZE), None);
+        assert_eq!(small.get_next_set_bit(0), None);
+
+        small.set_bit(SMALL_BIT_FIELD_BIT_SIZE - 1);
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(
+                small.get_next_set_bit(i),
+                Some(SMALL_BIT_FIELD_BIT_SIZE - 1)
+            );
+            unsafe {
+                assert_eq!(
+                    small.get_next_set_bit_unchecked(i),
+                    SMALL_BIT_FIELD_BIT_SIZE - 1
+                );
+            }
+        }
+
+        //
+        // Every set bit should be found from itself and the bit following the previous set bit.
+        //
+
+        let fives = 0x55555555_55555555 & core::usize::MAX;
+        small.clear_field(core::usize::MAX);
+        small.set_field(fives);
+        for i in (0..SMALL_BIT_FIELD_BIT_SIZE).step_by(2) {
+            assert_eq!(small.get_next_set_bit(i), Some(i));
+            unsafe {
+                assert_eq!(small.get_next_set_bit_unchecked(i), i);
+            }
+
+            if i > 0 {
+                assert_eq!(small.get_next_set_bit(i - 1), Some(i));
+            }
+        }
+
+        assert_eq!(small.get_next_set_bit(SMALL_BIT_FIELD_BIT_SIZE - 1), None);
+    }
+
+    #[test]
+    fn validate_get_previous_set_bit() {
+        let mut small = SmallBitField::new();
+
+        //
+        // Out of bounds and empty should result in None for checked variant
+        //
+
+        assert_eq!(small.get_previous_set_bit(SMALL_BIT_FIELD_BIT_SIZE), None);
+        assert_eq!(
+            small.get_previous_set_bit(SMALL_BIT_FIELD_BIT_SIZE - 1),
+            None
+        );
+
+        small.set_bit(0);
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(small.get_previous_set_bit(i), Some(0));
+            unsafe {
+                assert_eq!(small.get_previous_set_bit_unchecked(i), 0);
+            }
+        }
+
+        //
+        // Every set bit should be found from itself and the bit preceding the next set bit.
+        //
+
+        let a_s = 0xAAAAAAAA_AAAAAAAA & core::usize::MAX;
+        small.clear_field(core::usize::MAX);
+        small.set_field(a_s);
+        for i in (1..SMALL_BIT_FIELD_BIT_SIZE).step_by(2) {
+            assert_eq!(small.get_previous_set_bit(i), Some(i));
+            unsafe {
+                assert_eq!(small.get_previous_set_bit_unchecked(i), i);
+            }
+
+            if i + 1 < SMALL_BIT_FIELD_BIT_SIZE {
+                assert_eq!(small.get_previous_set_bit(i + 1), Some(i));
+            }
+        }
+
+        assert_eq!(small.get_previous_set_bit(0), None);
+    }
+
+    #[test]
+    fn validate_get_lowest_clear_bit() {
+        let mut small = SmallBitField::new();
+        small.set_field(core::usize::MAX);
+
+        //
+        // Full should result in None for checked variant
+        //
+
+        assert!(small.is_full());
+        assert_eq!(small.get_lowest_clear_bit(), None);
+
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            small.clear_bit(i);
+            assert!(!small.is_full());
+            assert_eq!(small.get_lowest_clear_bit(), Some(0));
+            assert_eq!(small.get_lowest_clear_bit_unchecked(), 0);
+        }
+
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(small.get_lowest_clear_bit(), Some(i));
+            assert_eq!(small.get_lowest_clear_bit_unchecked(), i);
+            small.set_bit(i);
+        }
+
+        assert!(small.is_full());
+    }
+
+    #[test]
+    fn validate_get_highest_clear_bit() {
+        let mut small = SmallBitField::new();
+        small.set_field(core::usize::MAX);
+
+        //
+        // Full should result in None for checked variant
+        //
+
+        assert_eq!(small.get_highest_clear_bit(), None);
+
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            small.clear_bit(i);
+            assert_eq!(small.get_highest_clear_bit(), Some(i));
+            assert_eq!(small.get_highest_clear_bit_unchecked(), i);
+        }
+
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(
+                small.get_highest_clear_bit(),
+                Some(SMALL_BIT_FIELD_BIT_SIZE - 1)
+            );
+            assert_eq!(
+                small.get_highest_clear_bit_unchecked(),
+                SMALL_BIT_FIELD_BIT_SIZE - 1
+            );
+            small.set_bit(i);
+        }
+    }
+
+    //
+    // Operator Tests
+    //
+
+    #[test]
+    fn validate_set_operations() {
+        let fives = 0x55555555_55555555 & core::usize::MAX;
+        let threes = 0x33333333_33333333 & core::usize::MAX;
+        let make = |value: usize| {
+            let mut small = SmallBitField::new();
+            small.set_field(value);
+            small
+        };
+
+        assert_eq!((make(fives) | make(threes)).bitfield, fives | threes);
+        assert_eq!((make(fives) & make(threes)).bitfield, fives & threes);
+        assert_eq!((make(fives) ^ make(threes)).bitfield, fives ^ threes);
+        assert_eq!((make(fives) - make(threes)).bitfield, fives & !threes);
+        assert_eq!((!make(fives)).bitfield, !fives);
+
+        let mut small = make(fives);
+        small |= make(threes);
+        assert_eq!(small.bitfield, fives | threes);
+
+        let mut small = make(fives);
+        small &= make(threes);
+        assert_eq!(small.bitfield, fives & threes);
+
+        let mut small = make(fives);
+        small ^= make(threes);
+        assert_eq!(small.bitfield, fives ^ threes);
+
+        let mut small = make(fives);
+        small -= make(threes);
+        assert_eq!(small.bitfield, fives & !threes);
+    }
+
+    #[test]
+    fn validate_count_rank_and_select() {
+        let mut small = SmallBitField::new();
+
+        //
+        // Empty has no bits to count or select.
+        //
+
+        assert_eq!(small.count_set_bits(), 0);
+        assert_eq!(small.rank(SMALL_BIT_FIELD_BIT_SIZE), 0);
+        assert_eq!(small.select(0), None);
+
+        let fives = 0x55555555_55555555 & core::usize::MAX;
+        small.set_field(fives);
+        assert_eq!(small.count_set_bits(), SMALL_BIT_FIELD_BIT_SIZE / 2);
+
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(small.rank(i), i.div_ceil(2));
+        }
+
+        for k in 0..SMALL_BIT_FIELD_BIT_SIZE / 2 {
+            assert_eq!(small.select(k), Some(k * 2));
+            assert_eq!(small.rank(k * 2), k);
+        }
+
+        assert_eq!(small.select(SMALL_BIT_FIELD_BIT_SIZE / 2), None);
+
+        //
+        // Every bit set.
+        //
+
+        small.set_field(core::usize::MAX);
+        assert_eq!(small.count_set_bits(), SMALL_BIT_FIELD_BIT_SIZE);
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(small.rank(i), i);
+            assert_eq!(small.select(i), Some(i));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn validate_range_operations() {
+        let mut small = SmallBitField::new();
+
+        //
+        // Invalid ranges leave the field unchanged and cannot be queried.
+        //
+
+        small.set_range(0..SMALL_BIT_FIELD_BIT_SIZE + 1);
+        small.set_range(5..4);
+        assert_eq!(small.bitfield, 0);
+        assert_eq!(small.is_range_set(0..SMALL_BIT_FIELD_BIT_SIZE + 1), None);
+        assert_eq!(small.is_range_clear(5..4), None);
+        assert_eq!(small.count_in_range(5..4), None);
+
+        //
+        // Empty ranges are trivially both set and clear.
+        //
+
+        small.set_range(3..3);
+        assert_eq!(small.bitfield, 0);
+        assert_eq!(small.is_range_set(3..3), Some(true));
+        assert_eq!(small.is_range_clear(3..3), Some(true));
+        assert_eq!(small.count_in_range(3..3), Some(0));
+
+        small.set_range(0..SMALL_BIT_FIELD_BIT_SIZE);
+        assert_eq!(small.bitfield, core::usize::MAX);
+
+        small.clear_range(1..SMALL_BIT_FIELD_BIT_SIZE - 1);
+        assert_eq!(small.bitfield, 1 | (1 << (SMALL_BIT_FIELD_BIT_SIZE - 1)));
+        assert_eq!(small.count_in_range(0..SMALL_BIT_FIELD_BIT_SIZE), Some(2));
+        assert_eq!(
+            small.is_range_clear(1..SMALL_BIT_FIELD_BIT_SIZE - 1),
+            Some(true)
+        );
+        assert_eq!(
+            small.is_range_clear(0..SMALL_BIT_FIELD_BIT_SIZE - 1),
+            Some(false)
+        );
+
+        small.set_range(4..9);
+        assert_eq!(small.is_range_set(4..9), Some(true));
+        assert_eq!(small.is_range_set(3..9), Some(false));
+        assert_eq!(small.is_range_set(4..10), Some(false));
+        assert_eq!(small.count_in_range(0..9), Some(6));
+    }
+
+    #[test]
+    fn validate_get_next_clear_bit() {
+        let mut small = SmallBitField::new();
+        small.set_field(core::usize::MAX);
+        assert_eq!(small.get_next_clear_bit(0), None);
+        assert_eq!(small.get_next_clear_bit(SMALL_BIT_FIELD_BIT_SIZE), None);
+
+        small.clear_bit(0);
+        small.clear_bit(SMALL_BIT_FIELD_BIT_SIZE - 1);
+        assert_eq!(small.get_next_clear_bit(0), Some(0));
+        assert_eq!(
+            small.get_next_clear_bit(1),
+            Some(SMALL_BIT_FIELD_BIT_SIZE - 1)
+        );
+        assert_eq!(
+            small.get_next_clear_bit(SMALL_BIT_FIELD_BIT_SIZE - 1),
+            Some(SMALL_BIT_FIELD_BIT_SIZE - 1)
+        );
+    }
+
+    #[test]
+    fn validate_fallible_operations() {
+        let mut small = SmallBitField::new();
+        assert_eq!(
+            small.try_set_bit(SMALL_BIT_FIELD_BIT_SIZE),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(
+            small.try_clear_bit(SMALL_BIT_FIELD_BIT_SIZE),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(
+            small.try_test_bit(SMALL_BIT_FIELD_BIT_SIZE),
+            Err(BitFieldError::IndexOutOfRange)
+        );
+        assert_eq!(small.bitfield, 0);
+
+        for i in 0..SMALL_BIT_FIELD_BIT_SIZE {
+            assert_eq!(small.try_set_bit(i), Ok(()));
+            assert_eq!(small.try_test_bit(i), Ok(true));
+        }
+
+        assert!(small.is_full());
+        assert_eq!(small.try_clear_bit(4), Ok(()));
+        assert_eq!(small.try_test_bit(4), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(expected = "test_bit_unchecked called with index")]
+    fn checked_rejects_out_of_range_index() {
+        let small = SmallBitField::new();
+        unsafe {
+            small.test_bit_unchecked(SMALL_BIT_FIELD_BIT_SIZE);
+        }
+    }
+
+    #[test]
+    fn validate_standard_traits() {
+        let mut small = SmallBitField::default();
+        assert_eq!(small, SmallBitField::new());
+        assert_eq!(format!("{:?}", small), "SmallBitField {}");
+
+        small.set_bit(1);
+        let copy = small;
+        small.set_bit(2);
+        assert_ne!(small, copy);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(small);
+        set.insert(copy);
+        set.insert(small);
+        assert_eq!(set.len(), 2);
+
+        small.set_bit(SMALL_BIT_FIELD_BIT_SIZE - 1);
+        assert_eq!(
+            format!("{:?}", small),
+            format!("SmallBitField {{1..=2, {}}}", SMALL_BIT_FIELD_BIT_SIZE - 1)
+        );
+
+        small.set_field(core::usize::MAX);
+        assert_eq!(
+            format!("{:?}", small),
+            format!("SmallBitField {{0..={}}}", SMALL_BIT_FIELD_BIT_SIZE - 1)
+        );
+    }
+
+    #[test]
+    fn validate_word_import_and_export() {
+        let small = SmallBitField::from_raw(0xF0F0);
+        assert_eq!(small.bitfield, 0xF0F0);
+        assert_eq!(small.as_words(), &[0xF0F0]);
+        assert_eq!(small.into_words(), [0xF0F0]);
+        assert_eq!(SmallBitField::from_words([0xF0F0]), small);
+        assert_eq!(SmallBitField::from_raw(0), SmallBitField::new());
+        assert!(SmallBitField::from_raw(core::usize::MAX).is_full());
+    }
+
+    #[test]
+    fn validate_byte_serialization() {
+        let small = SmallBitField::from_raw(0x1234 | (1 << (SMALL_BIT_FIELD_BIT_SIZE - 1)));
+        let mut buffer = [0xAA; SmallBitField::SERIALIZED_SIZE + 1];
+        assert_eq!(SmallBitField::SERIALIZED_SIZE, 16);
+        assert_eq!(small.to_bytes(&mut buffer), Ok(16));
+
+        //
+        // The layout is a little-endian bit count followed by little-endian 64-bit words.
+        //
+
+        let mut expected = [0; 17];
+        expected[..8].copy_from_slice(&(SMALL_BIT_FIELD_BIT_SIZE as u64).to_le_bytes());
+        expected[8..16].copy_from_slice(&(small.bitfield as u64).to_le_bytes());
+        expected[16] = 0xAA;
+        assert_eq!(buffer, expected);
+        assert_eq!(SmallBitField::from_bytes(&buffer), Ok(small));
+
+        assert_eq!(
+            small.to_bytes(&mut buffer[..15]),
+            Err(BitFieldError::BufferTooSmall)
+        );
+        assert_eq!(
+            SmallBitField::from_bytes(&buffer[..15]),
+            Err(BitFieldError::BufferTooSmall)
+        );
+
+        buffer[0] ^= 1;
+        assert_eq!(
+            SmallBitField::from_bytes(&buffer),
+            Err(BitFieldError::SizeMismatch)
+        );
+    }
+
+    /// Checks a SmallBitField of any word type against a reference set of bits.
+    fn validate_word_type<W: BitWord>() {
+        let mut small = SmallBitField::<W>::new();
+        assert_eq!(small.capacity(), W::BITS);
+        assert_eq!(SmallBitField::<W>::BITS, W::BITS);
+
+        let indices = [0, 1, W::BITS / 2, W::BITS - 2, W::BITS - 1];
+        for index in indices.iter() {
+            small.set_bit(*index);
+        }
+
+        small.set_bit(W::BITS);
+        assert_eq!(small.test_bit(W::BITS), None);
+        assert_eq!(small.count_set_bits(), indices.len());
+        assert_eq!(small.get_lowest_set_bit(), Some(0));
+        assert_eq!(small.get_highest_set_bit(), Some(W::BITS - 1));
+        assert_eq!(small.get_next_set_bit(2), Some(W::BITS / 2));
+        assert_eq!(small.get_previous_set_bit(W::BITS - 3), Some(W::BITS / 2));
+        assert_eq!(small.get_lowest_clear_bit(), Some(2));
+        assert_eq!(small.get_highest_clear_bit(), Some(W::BITS - 3));
+        assert_eq!(small.rank(W::BITS - 1), indices.len() - 1);
+        assert_eq!(small.select(2), Some(W::BITS / 2));
+        assert_eq!(small.select(4), Some(W::BITS - 1));
+        assert!(small.iter_set_bits().eq(indices.iter().cloned()));
+        assert!(small
+            .iter_set_bits()
+            .rev()
+            .eq(indices.iter().rev().cloned()));
+
+        small.clear_bit(0);
+        small.clear_bit(W::BITS - 1);
+        assert_eq!(small.get_lowest_set_bit(), Some(1));
+        assert_eq!(small.get_highest_set_bit(), Some(W::BITS - 2));
+
+        small.set_range(0..W::BITS);
+        assert!(small.is_full());
+        assert_eq!(small.get_word(), W::MAX);
+        assert_eq!(small.get_lowest_clear_bit(), None);
+
+        small.clear_range(1..W::BITS);
+        assert_eq!(small.get_word(), W::ONE);
+        assert_eq!(!small, SmallBitField::from_word(!W::ONE));
+        assert_eq!(
+            format!("{:?}", !small),
+            format!("SmallBitField {{1..={}}}", W::BITS - 1)
+        );
+    }
+
+    #[test]
+    fn validate_word_types() {
+        validate_word_type::<u8>();
+        validate_word_type::<u16>();
+        validate_word_type::<u32>();
+        validate_word_type::<u64>();
+        validate_word_type::<u128>();
+        validate_word_type::<usize>();
+    }
+
+    /// Holds a bitfield built at compile time.
+    const CONST_SMALL: SmallBitField = SmallBitField::with_bits(&[1, 4, SMALL_BIT_FIELD_BIT_SIZE])
+        .with_bit(SMALL_BIT_FIELD_BIT_SIZE - 1);
+
+    /// Holds the result of testing a bit at compile time.
+    const CONST_SMALL_BIT: Option<bool> = CONST_SMALL.test_bit(4);
+
+    #[test]
+    fn validate_const_construction() {
+        assert_eq!(CONST_SMALL_BIT, Some(true));
+        assert_eq!(CONST_SMALL.count_set_bits(), 3);
+        assert_eq!(
+            CONST_SMALL,
+            SmallBitField::from_raw((1 << 1) | (1 << 4) | (1 << (SMALL_BIT_FIELD_BIT_SIZE - 1)))
+        );
+
+        assert_eq!(CONST_SMALL.test_bit(SMALL_BIT_FIELD_BIT_SIZE), None);
+        assert_eq!(CONST_SMALL.test_bit(0), Some(false));
+
+        let full = SmallBitField::full();
+        assert!(full.is_full());
+        assert_eq!(SmallBitField::with_bits(&[]), SmallBitField::new());
+
+        const FROM_WORDS: SmallBitField = SmallBitField::from_words([0b110]);
+        assert_eq!(FROM_WORDS.get_lowest_set_bit(), Some(1));
+    }
 }
@@ -0,0 +1,688 @@
+use crate::large_bitfield::LARGE_BIT_FIELD_BIT_SIZE;
+use crate::{FastBitField, IdAllocator, LargeBitField};
+
+/// Defines the number of slots in each level of a timer wheel.
+const TIMER_WHEEL_SLOTS: usize = LARGE_BIT_FIELD_BIT_SIZE;
+
+/// Defines the number of bits of a deadline consumed by each level of a timer wheel.
+const TIMER_WHEEL_SLOT_BITS: usize = TIMER_WHEEL_SLOTS.trailing_zeros() as usize;
+
+/// Defines a handle to a timer in a timer wheel.
+/// A handle is only valid until its timer expires or is cancelled. Handles of timers that are no
+/// longer in the wheel are never mistaken for newer timers occupying the same entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerId {
+    /// Holds the entry holding the timer.
+    index: usize,
+
+    /// Holds the insertion sequence number of the timer.
+    sequence: u64,
+}
+
+/// Defines the structure for a timer held in a timer wheel.
+struct TimerEntry<T> {
+    /// Holds the tick at which the timer expires.
+    deadline: u64,
+
+    /// Holds the insertion sequence number of the timer.
+    sequence: u64,
+
+    /// Holds the level of the slot holding the timer.
+    level: usize,
+
+    /// Holds the slot holding the timer.
+    slot: usize,
+
+    /// Holds the previous timer in the same slot.
+    previous: Option<usize>,
+
+    /// Holds the next timer in the same slot.
+    next: Option<usize>,
+
+    /// Holds the item handed back when the timer expires.
+    item: T,
+}
+
+/// Defines the structure for Timer Wheels.
+/// A Timer Wheel holds up to CAPACITY timers across LEVELS levels of slots. Each level has one slot
+/// per bit of a `LargeBitField` and each slot of a level spans as many ticks as the entire level
+/// below it. A timer is placed in the lowest level whose slots can reach its deadline and is
+/// cascaded down to lower levels as the wheel advances towards it.
+///
+/// The occupied slots of each level are tracked in a `LargeBitField`, so the next slot to expire is
+/// found with a find-next-set-bit search from the current slot that wraps around to the start of
+/// the level, regardless of how many slots are empty.
+///
+/// LEVELS must be at least one, the levels together must span no more than a `u64` worth of ticks
+/// and CAPACITY must be no more than the number of bits in a `LargeBitField`.
+///
+/// # Examples
+/// ```
+/// use fast_bitfield::TimerWheel;
+///
+/// let mut wheel = TimerWheel::<&str, 2, 16>::new();
+/// wheel.insert(30, "retransmit").unwrap();
+/// wheel.insert(10, "heartbeat").unwrap();
+///
+/// assert_eq!(wheel.next_expiration(), Some(10));
+///
+/// let mut expired = Vec::new();
+/// wheel.advance(30, |item| expired.push(item));
+/// assert_eq!(expired, ["heartbeat", "retransmit"]);
+/// ```
+pub struct TimerWheel<T, const LEVELS: usize, const CAPACITY: usize> {
+    /// Holds the current tick.
+    now: u64,
+
+    /// Holds the sequence number to give the next inserted timer.
+    next_sequence: u64,
+
+    /// Holds a bitfield per level describing which slots currently hold any timers.
+    occupied: [LargeBitField; LEVELS],
+
+    /// Holds the first timer in each slot of each level.
+    heads: [[Option<usize>; TIMER_WHEEL_SLOTS]; LEVELS],
+
+    /// Holds the last timer in each slot of each level.
+    tails: [[Option<usize>; TIMER_WHEEL_SLOTS]; LEVELS],
+
+    /// Holds which timer entries are in use.
+    allocator: IdAllocator,
+
+    /// Holds the timers.
+    entries: [Option<TimerEntry<T>>; CAPACITY],
+}
+
+/// Defines functionality for TimerWheel.
+impl<T, const LEVELS: usize, const CAPACITY: usize> TimerWheel<T, LEVELS, CAPACITY> {
+    /// Creates a new, empty TimerWheel at tick zero.
+    ///
+    /// # Returns
+    /// A TimerWheel.
+    ///
+    /// # Panics
+    /// Panics if LEVELS or CAPACITY is out of range.
+    pub fn new() -> Self {
+        assert!(LEVELS > 0, "TimerWheel must have at least one level");
+        assert!(
+            TIMER_WHEEL_SLOT_BITS * LEVELS <= 64,
+            "TimerWheel levels span more ticks than a u64 can hold"
+        );
+
+        assert!(
+            CAPACITY <= LargeBitField::get_number_of_bits(),
+            "TimerWheel capacity is larger than its entry allocator"
+        );
+
+        TimerWheel {
+            now: 0,
+            next_sequence: 0,
+            occupied: core::array::from_fn(|_| LargeBitField::new()),
+            heads: [[None; TIMER_WHEEL_SLOTS]; LEVELS],
+            tails: [[None; TIMER_WHEEL_SLOTS]; LEVELS],
+            allocator: IdAllocator::new(),
+            entries: core::array::from_fn(|_| None),
+        }
+    }
+
+    /// Gets the current tick.
+    ///
+    /// # Returns
+    /// The tick the wheel has advanced to.
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Gets the number of timers in the wheel.
+    ///
+    /// # Returns
+    /// The number of timers.
+    pub fn len(&self) -> usize {
+        self.allocator.allocated_count()
+    }
+
+    /// Determines whether or not the wheel holds any timers.
+    ///
+    /// # Returns
+    /// `true` if empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.allocator.allocated_count() == 0
+    }
+
+    /// Adds a timer to the wheel.
+    ///
+    /// # Arguments
+    /// deadline - Provides the tick at which the timer expires. Deadlines that have already passed
+    /// expire on the next advance.
+    /// item - Provides the item to hand back when the timer expires.
+    ///
+    /// # Returns
+    /// The handle of the timer or `Err(item)` if the wheel is full or the deadline is too far in the
+    /// future for the levels of the wheel.
+    pub fn insert(&mut self, deadline: u64, item: T) -> Result<TimerId, T> {
+        let deadline = deadline.max(self.now);
+        let (level, slot) = match self.get_slot(deadline) {
+            Some(position) => position,
+            None => return Err(item),
+        };
+
+        let index = match self.allocator.alloc() {
+            Ok(index) if index < CAPACITY => index,
+            Ok(index) => {
+                self.allocator.free(index).unwrap();
+                return Err(item);
+            }
+
+            Err(_) => return Err(item),
+        };
+
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.entries[index] = Some(TimerEntry {
+            deadline,
+            sequence,
+            level,
+            slot,
+            previous: None,
+            next: None,
+            item,
+        });
+
+        self.link(index);
+        Ok(TimerId { index, sequence })
+    }
+
+    /// Removes a timer from the wheel before it expires.
+    ///
+    /// # Arguments
+    /// id - Provides the handle of the timer to remove.
+    ///
+    /// # Returns
+    /// The item of the timer or `None` if the timer has already expired or been cancelled.
+    ///
+    /// # Examples
+    /// ```
+    /// use fast_bitfield::TimerWheel;
+    ///
+    /// let mut wheel = TimerWheel::<u32, 1, 4>::new();
+    /// let id = wheel.insert(5, 7).unwrap();
+    ///
+    /// assert_eq!(wheel.cancel(id), Some(7));
+    /// assert_eq!(wheel.cancel(id), None);
+    /// assert_eq!(wheel.next_expiration(), None);
+    /// ```
+    pub fn cancel(&mut self, id: TimerId) -> Option<T> {
+        match self.entries.get(id.index) {
+            Some(Some(entry)) if entry.sequence == id.sequence => (),
+            _ => return None,
+        }
+
+        self.unlink(id.index);
+        self.allocator.free(id.index).unwrap();
+        self.entries[id.index].take().map(|entry| entry.item)
+    }
+
+    /// Gets the earliest tick at which the wheel has work to do.
+    ///
+    /// # Returns
+    /// The earliest tick at which a timer may expire or `None` if the wheel is empty. For timers
+    /// that have not yet cascaded to the lowest level this is the start of their slot, which may
+    /// be earlier than their deadline.
+    pub fn next_expiration(&self) -> Option<u64> {
+        self.get_next_slot().map(|(tick, _, _)| tick)
+    }
+
+    /// Advances the wheel, expiring every timer with a deadline at or before a given tick.
+    ///
+    /// # Arguments
+    /// now - Provides the tick to advance to. Ticks before the current tick are ignored.
+    /// expire - Provides the function called with the item of each expired timer, in deadline
+    /// order. Timers with the same deadline expire in the order they were inserted.
+    ///
+    /// # Returns
+    /// The number of timers that expired.
+    pub fn advance<F: FnMut(T)>(&mut self, now: u64, mut expire: F) -> usize {
+        let mut expired = 0;
+        while let Some((tick, level, slot)) = self.get_next_slot() {
+            if tick > now {
+                break;
+            }
+
+            self.now = self.now.max(tick);
+
+            //
+            // Detach the whole slot before walking it, as cascading timers are linked into other
+            // slots.
+            //
+
+            let mut next = self.heads[level][slot].take();
+            self.tails[level][slot] = None;
+            self.occupied[level].clear_bit(slot);
+            while let Some(index) = next {
+                next = self.entries[index].as_ref().and_then(|entry| entry.next);
+                if level == 0 {
+                    self.allocator.free(index).unwrap();
+                    if let Some(entry) = self.entries[index].take() {
+                        expire(entry.item);
+                        expired += 1;
+                    }
+                } else {
+                    self.cascade(index);
+                }
+            }
+        }
+
+        self.now = self.now.max(now);
+        expired
+    }
+
+    /// Gets the slot a deadline belongs in given the current tick.
+    ///
+    /// # Arguments
+    /// deadline - Provides the deadline to place. Must be no earlier than the current tick.
+    ///
+    /// # Returns
+    /// The level and slot or `None` if the deadline is beyond the reach of every level.
+    fn get_slot(&self, deadline: u64) -> Option<(usize, usize)> {
+        for level in 0..LEVELS {
+            //
+            // A level can hold the deadline if it is less than a full rotation of the level ahead
+            // of the current slot, so the slot it lands in is never ambiguous.
+            //
+
+            let deadline_units = Self::get_slot_units(deadline, level);
+            if deadline_units - Self::get_slot_units(self.now, level) < TIMER_WHEEL_SLOTS as u64 {
+                return Some((level, (deadline_units % TIMER_WHEEL_SLOTS as u64) as usize));
+            }
+        }
+
+        None
+    }
+
+    /// Gets the next occupied slot of the wheel.
+    ///
+    /// # Returns
+    /// The tick at which the slot is due, its level and the slot or `None` if the wheel is empty.
+    /// Slots due at the same tick are reported from the highest level first, so timers cascade
+    /// before the lowest level expires.
+    fn get_next_slot(&self) -> Option<(u64, usize, usize)> {
+        let mut next: Option<(u64, usize, usize)> = None;
+        for level in (0..LEVELS).rev() {
+            let units = Self::get_slot_units(self.now, level);
+            let current = (units % TIMER_WHEEL_SLOTS as u64) as usize;
+
+            //
+            // Search forwards from the current slot, wrapping around to the start of the level.
+            //
+
+            let occupied = &self.occupied[level];
+            let slot = match occupied
+                .get_next_set_bit(current)
+                .or_else(|| occupied.get_lowest_set_bit())
+            {
+                Some(slot) => slot,
+                None => continue,
+            };
+
+            //
+            // Saturate rather than wrap for slots starting past the last tick, so a slot is never
+            // reported as due before the current tick.
+            //
+
+            let distance = ((slot + TIMER_WHEEL_SLOTS - current) % TIMER_WHEEL_SLOTS) as u64;
+            let tick = match distance {
+                0 => self.now,
+                _ => units
+                    .checked_add(distance)
+                    .and_then(|start| start.checked_mul(1 << (TIMER_WHEEL_SLOT_BITS * level)))
+                    .unwrap_or(core::u64::MAX),
+            };
+
+            let is_earlier = match next {
+                Some((next_tick, _, _)) => tick < next_tick,
+                None => true,
+            };
+
+            if is_earlier {
+                next = Some((tick, level, slot));
+            }
+        }
+
+        next
+    }
+
+    /// Gets a tick in units of the slots of a level.
+    ///
+    /// # Arguments
+    /// tick - Provides the tick to convert.
+    /// level - Provides the level whose slot size to use.
+    ///
+    /// # Returns
+    /// The number of whole slots of the level before the tick.
+    fn get_slot_units(tick: u64, level: usize) -> u64 {
+        tick.checked_shr((TIMER_WHEEL_SLOT_BITS * level) as u32)
+            .unwrap_or(0)
+    }
+
+    /// Moves a detached timer to the slot its deadline belongs in given the current tick.
+    ///
+    /// # Arguments
+    /// index - Provides the entry holding the timer.
+    fn cascade(&mut self, index: usize) {
+        let deadline = match &self.entries[index] {
+            Some(entry) => entry.deadline,
+            None => return,
+        };
+
+        //
+        // A timer can only move closer to the current tick, so it always fits in a lower level.
+        //
+
+        let (level, slot) = self.get_slot(deadline.max(self.now)).unwrap();
+        if let Some(entry) = self.entries[index].as_mut() {
+            entry.level = level;
+            entry.slot = slot;
+        }
+
+        self.link(index);
+    }
+
+    /// Adds a timer to the slot recorded in its entry, after every timer in the slot that was
+    /// inserted before it.
+    ///
+    /// # Arguments
+    /// index - Provides the entry holding the timer.
+    fn link(&mut self, index: usize) {
+        let (level, slot, sequence) = match &self.entries[index] {
+            Some(entry) => (entry.level, entry.slot, entry.sequence),
+            None => return,
+        };
+
+        //
+        // A newly inserted timer is always the latest in its slot and is appended at the tail. A
+        // cascading timer may land in a slot already holding timers inserted after it, so walk
+        // back past those to keep timers with the same deadline in insertion order.
+        //
+
+        let mut previous = self.tails[level][slot];
+        while let Some(entry) = previous.and_then(|previous| self.entries[previous].as_ref()) {
+            if entry.sequence < sequence {
+                break;
+            }
+
+            previous = entry.previous;
+        }
+
+        let next = match previous {
+            Some(previous) => self.entries[previous]
+                .as_mut()
+                .and_then(|entry| entry.next.replace(index)),
+
+            None => self.heads[level][slot].replace(index),
+        };
+
+        match next {
+            Some(next) => {
+                if let Some(entry) = self.entries[next].as_mut() {
+                    entry.previous = Some(index);
+                }
+            }
+
+            None => self.tails[level][slot] = Some(index),
+        }
+
+        if let Some(entry) = self.entries[index].as_mut() {
+            entry.previous = previous;
+            entry.next = next;
+        }
+
+        self.occupied[level].set_bit(slot);
+    }
+
+    /// Removes a timer from the slot recorded in its entry.
+    ///
+    /// # Arguments
+    /// index - Provides the entry holding the timer.
+    fn unlink(&mut self, index: usize) {
+        let (level, slot, previous, next) = match &self.entries[index] {
+            Some(entry) => (entry.level, entry.slot, entry.previous, entry.next),
+            None => return,
+        };
+
+        match previous {
+            Some(previous) => {
+                if let Some(entry) = self.entries[previous].as_mut() {
+                    entry.next = next;
+                }
+            }
+
+            None => self.heads[level][slot] = next,
+        }
+
+        match next {
+            Some(next) => {
+                if let Some(entry) = self.entries[next].as_mut() {
+                    entry.previous = previous;
+                }
+            }
+
+            None => self.tails[level][slot] = previous,
+        }
+
+        if self.heads[level][slot].is_none() {
+            self.occupied[level].clear_bit(slot);
+        }
+    }
+}
+
+/// Defines the default TimerWheel.
+impl<T, const LEVELS: usize, const CAPACITY: usize> Default for TimerWheel<T, LEVELS, CAPACITY> {
+    /// Creates a new, empty TimerWheel at tick zero.
+    ///
+    /// # Returns
+    /// A TimerWheel.
+    ///
+    /// # Panics
+    /// Panics if LEVELS or CAPACITY is out of range.
+    fn default() -> Self {
+        TimerWheel::new()
+    }
+}
+
+//
+// Unit Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Advances a wheel and collects the expired items.
+    fn advance_and_collect<const LEVELS: usize, const CAPACITY: usize>(
+        wheel: &mut TimerWheel<u64, LEVELS, CAPACITY>,
+        now: u64,
+    ) -> Vec<u64> {
+        let mut expired = Vec::new();
+        wheel.advance(now, |item| expired.push(item));
+        expired
+    }
+
+    #[test]
+    fn create_defaults_to_empty() {
+        let wheel = TimerWheel::<u64, 1, 8>::new();
+        assert_eq!(wheel.now(), 0);
+        assert_eq!(wheel.len(), 0);
+        assert!(wheel.is_empty());
+        assert_eq!(wheel.next_expiration(), None);
+        assert!(TimerWheel::<u64, 1, 8>::default().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_large_capacity_panics() {
+        TimerWheel::<u64, 1, { LARGE_BIT_FIELD_BIT_SIZE + 1 }>::new();
+    }
+
+    #[test]
+    fn validate_insert_limits() {
+        let mut wheel = TimerWheel::<u64, 1, 2>::new();
+        let slots = TIMER_WHEEL_SLOTS as u64;
+
+        //
+        // A single level reaches exactly one rotation ahead.
+        //
+
+        assert_eq!(wheel.insert(slots, 0), Err(0));
+        assert!(wheel.insert(slots - 1, 1).is_ok());
+        assert!(wheel.insert(0, 2).is_ok());
+        assert_eq!(wheel.insert(1, 3), Err(3));
+        assert_eq!(wheel.len(), 2);
+
+        assert_eq!(advance_and_collect(&mut wheel, 0), [2]);
+        assert_eq!(advance_and_collect(&mut wheel, slots - 1), [1]);
+        assert!(wheel.is_empty());
+    }
+
+    #[test]
+    fn validate_expiration_order() {
+        let mut wheel = TimerWheel::<u64, 2, 16>::new();
+        for &deadline in [40, 5, 17, 5, 1000].iter() {
+            wheel.insert(deadline, deadline).unwrap();
+        }
+
+        assert_eq!(wheel.next_expiration(), Some(5));
+        assert_eq!(advance_and_collect(&mut wheel, 4), []);
+        assert_eq!(wheel.now(), 4);
+        assert_eq!(advance_and_collect(&mut wheel, 17), [5, 5, 17]);
+        assert_eq!(wheel.next_expiration(), Some(40));
+        assert_eq!(advance_and_collect(&mut wheel, 2000), [40, 1000]);
+        assert_eq!(wheel.now(), 2000);
+
+        //
+        // Time never moves backwards and past deadlines expire on the next advance.
+        //
+
+        assert_eq!(advance_and_collect(&mut wheel, 10), []);
+        assert_eq!(wheel.now(), 2000);
+        wheel.insert(3, 3).unwrap();
+        assert_eq!(wheel.next_expiration(), Some(2000));
+        assert_eq!(advance_and_collect(&mut wheel, 2000), [3]);
+    }
+
+    #[test]
+    fn validate_wraparound() {
+        let mut wheel = TimerWheel::<u64, 1, 4>::new();
+        let slots = TIMER_WHEEL_SLOTS as u64;
+
+        //
+        // Deadlines past the end of the level land in slots before the current one.
+        //
+
+        advance_and_collect(&mut wheel, slots - 2);
+        wheel.insert(slots + 3, 1).unwrap();
+        wheel.insert(slots - 1, 2).unwrap();
+        assert_eq!(wheel.next_expiration(), Some(slots - 1));
+        assert_eq!(advance_and_collect(&mut wheel, slots), [2]);
+        assert_eq!(wheel.next_expiration(), Some(slots + 3));
+        assert_eq!(advance_and_collect(&mut wheel, slots + 3), [1]);
+    }
+
+    #[test]
+    fn validate_cascade() {
+        let mut wheel = TimerWheel::<u64, 3, 8>::new();
+        let slots = TIMER_WHEEL_SLOTS as u64;
+        let far = (slots * slots * 2) + (slots * 3) + 7;
+        let near = (slots * 2) + 5;
+
+        wheel.insert(far, far).unwrap();
+        wheel.insert(near, near).unwrap();
+
+        //
+        // Higher levels report the start of their slot before cascading.
+        //
+
+        assert_eq!(wheel.next_expiration(), Some(slots * 2));
+        assert_eq!(advance_and_collect(&mut wheel, near - 1), []);
+        assert_eq!(wheel.next_expiration(), Some(near));
+        assert_eq!(advance_and_collect(&mut wheel, near), [near]);
+
+        assert_eq!(advance_and_collect(&mut wheel, far - 1), []);
+        assert_eq!(wheel.next_expiration(), Some(far));
+        assert_eq!(advance_and_collect(&mut wheel, far), [far]);
+        assert!(wheel.is_empty());
+    }
+
+    #[test]
+    fn validate_same_deadline_order() {
+        let mut wheel = TimerWheel::<u64, 2, 8>::new();
+        let slots = TIMER_WHEEL_SLOTS as u64;
+        let deadline = slots + 100;
+
+        //
+        // Timers with the same deadline expire in insertion order, including timers that cascade
+        // into a slot after later timers were inserted into it directly.
+        //
+
+        wheel.insert(deadline, 1).unwrap();
+        wheel.insert(deadline, 2).unwrap();
+        advance_and_collect(&mut wheel, 200);
+        wheel.insert(deadline, 3).unwrap();
+        wheel.insert(deadline, 4).unwrap();
+        let fifth = wheel.insert(deadline, 5).unwrap();
+        wheel.insert(deadline, 6).unwrap();
+        assert_eq!(wheel.cancel(fifth), Some(5));
+        wheel.insert(deadline, 7).unwrap();
+
+        assert_eq!(
+            advance_and_collect(&mut wheel, deadline),
+            [1, 2, 3, 4, 6, 7]
+        );
+        assert!(wheel.is_empty());
+    }
+
+    #[test]
+    fn validate_last_tick() {
+        let mut wheel = TimerWheel::<u64, 2, 4>::new();
+        let slots = TIMER_WHEEL_SLOTS as u64;
+        let start = core::u64::MAX - (slots * 3);
+
+        //
+        // Slots near the end of time must not wrap around to tick zero.
+        //
+
+        advance_and_collect(&mut wheel, start);
+        wheel.insert(core::u64::MAX, 1).unwrap();
+        wheel.insert(core::u64::MAX - 1, 2).unwrap();
+        assert!(wheel.next_expiration().unwrap() > start);
+        assert_eq!(advance_and_collect(&mut wheel, start + 1), []);
+        assert_eq!(advance_and_collect(&mut wheel, core::u64::MAX - 1), [2]);
+        assert_eq!(advance_and_collect(&mut wheel, core::u64::MAX), [1]);
+        assert!(wheel.is_empty());
+    }
+
+    #[test]
+    fn validate_cancel() {
+        let mut wheel = TimerWheel::<u64, 2, 3>::new();
+        let first = wheel.insert(9, 1).unwrap();
+        let second = wheel.insert(9, 2).unwrap();
+        let third = wheel.insert(9, 3).unwrap();
+        assert_eq!(wheel.insert(9, 4), Err(4));
+
+        assert_eq!(wheel.cancel(second), Some(2));
+        assert_eq!(wheel.cancel(second), None);
+
+        //
+        // A stale handle must not cancel a newer timer reusing its entry.
+        //
+
+        let fourth = wheel.insert(12, 4).unwrap();
+        assert_eq!(wheel.cancel(second), None);
+
+        assert_eq!(wheel.cancel(first), Some(1));
+        assert_eq!(wheel.cancel(third), Some(3));
+        assert_eq!(wheel.next_expiration(), Some(12));
+        assert_eq!(wheel.cancel(fourth), Some(4));
+        assert_eq!(wheel.next_expiration(), None);
+        assert_eq!(advance_and_collect(&mut wheel, 100), []);
+    }
+}